@@ -1,9 +1,6 @@
-mod import_finder;
-mod tracer;
-
 use camino::Utf8PathBuf;
 use clap::Parser;
-use tracer::Tracer;
+use turbo_trace::Tracer;
 use turbopath::{AbsoluteSystemPathBuf, PathError};
 
 #[derive(Parser, Debug)]
@@ -1,4 +1,8 @@
-use std::{collections::HashSet, fs, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    rc::Rc,
+};
 
 use camino::Utf8PathBuf;
 use miette::{Diagnostic, NamedSource, SourceSpan};
@@ -41,6 +45,8 @@ pub enum TraceError {
 pub struct TraceResult {
     pub errors: Vec<TraceError>,
     pub files: HashSet<AbsoluteSystemPathBuf>,
+    // The files each traced file imports directly
+    pub imports: HashMap<AbsoluteSystemPathBuf, HashSet<AbsoluteSystemPathBuf>>,
 }
 
 impl Tracer {
@@ -77,6 +83,7 @@ impl Tracer {
 
         let resolver = Resolver::new(options);
         let mut errors = vec![];
+        let mut imports: HashMap<_, HashSet<_>> = HashMap::new();
 
         while let Some(file_path) = self.files.pop() {
             if matches!(file_path.extension(), Some("json") | Some("css")) {
@@ -143,8 +150,15 @@ impl Tracer {
                     continue;
                 };
                 match resolver.resolve(file_dir, import) {
-                    Ok(resolved) => match resolved.into_path_buf().try_into() {
-                        Ok(path) => self.files.push(path),
+                    Ok(resolved) => match AbsoluteSystemPathBuf::try_from(resolved.into_path_buf())
+                    {
+                        Ok(path) => {
+                            imports
+                                .entry(file_path.clone())
+                                .or_default()
+                                .insert(path.clone());
+                            self.files.push(path);
+                        }
                         Err(err) => {
                             errors.push(TraceError::PathEncoding(err));
                         }
@@ -165,6 +179,7 @@ impl Tracer {
         TraceResult {
             files: self.seen,
             errors,
+            imports,
         }
     }
 }
@@ -81,6 +81,7 @@ pub trait CacheClient {
         body_len: usize,
         duration: u64,
         tag: Option<&str>,
+        checksum: Option<&str>,
         token: &str,
         team_id: Option<&str>,
         team_slug: Option<&str>,
@@ -376,6 +377,7 @@ impl CacheClient for APIClient {
         body_length: usize,
         duration: u64,
         tag: Option<&str>,
+        checksum: Option<&str>,
         token: &str,
         team_id: Option<&str>,
         team_slug: Option<&str>,
@@ -389,7 +391,8 @@ impl CacheClient for APIClient {
                     token,
                     request_url.clone(),
                     "PUT",
-                    "Authorization, Content-Type, User-Agent, x-artifact-duration, x-artifact-tag",
+                    "Authorization, Content-Type, User-Agent, x-artifact-duration, \
+                     x-artifact-tag, x-artifact-checksum",
                 )
                 .await?;
 
@@ -420,6 +423,10 @@ impl CacheClient for APIClient {
             request_builder = request_builder.header("x-artifact-tag", tag);
         }
 
+        if let Some(checksum) = checksum {
+            request_builder = request_builder.header("x-artifact-checksum", checksum);
+        }
+
         let response =
             retry::make_retryable_request(request_builder, retry::RetryStrategy::Connection)
                 .await?
@@ -926,6 +933,7 @@ mod test {
                 body.len(),
                 123,
                 None,
+                None,
                 "token",
                 None,
                 None,
@@ -1,26 +1,26 @@
 use chrono::{DateTime, Local};
 use reqwest::Method;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use turbopath::AnchoredSystemPath;
 use turborepo_vercel_api::SpaceRun;
 
 use crate::{retry, APIAuth, APIClient, Error};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RunStatus {
     Running,
     Completed,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpaceClientSummary {
-    pub id: &'static str,
-    pub name: &'static str,
+    pub id: String,
+    pub name: String,
     pub version: String,
 }
 
-#[derive(Debug, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SpacesCacheStatus {
     pub status: CacheStatus,
@@ -29,21 +29,21 @@ pub struct SpacesCacheStatus {
     pub time_saved: u64,
 }
 
-#[derive(Debug, Serialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum CacheStatus {
     Hit,
     Miss,
 }
 
-#[derive(Debug, Serialize, Copy, Clone)]
+#[derive(Debug, Serialize, Deserialize, Copy, Clone)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum CacheSource {
     Local,
     Remote,
 }
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpaceTaskSummary {
     pub key: String,
@@ -60,13 +60,13 @@ pub struct SpaceTaskSummary {
     pub logs: String,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SpaceRunType {
     Turbo,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateSpaceRunPayload {
     pub start_time: i64,
@@ -77,7 +77,7 @@ pub struct CreateSpaceRunPayload {
     #[serde(rename = "repositoryPath")]
     pub package_inference_root: String,
     #[serde(rename = "context")]
-    pub run_context: &'static str,
+    pub run_context: String,
     pub git_branch: Option<String>,
     pub git_sha: Option<String>,
     #[serde(rename = "originationUser")]
@@ -97,7 +97,7 @@ impl CreateSpaceRunPayload {
     ) -> Self {
         let start_time = start_time.timestamp_millis();
         let vendor = turborepo_ci::Vendor::infer();
-        let run_context = vendor.map(|v| v.constant).unwrap_or("LOCAL");
+        let run_context = vendor.map(|v| v.constant).unwrap_or("LOCAL").to_string();
 
         CreateSpaceRunPayload {
             start_time,
@@ -112,8 +112,8 @@ impl CreateSpaceRunPayload {
             git_sha,
             user,
             client: SpaceClientSummary {
-                id: "turbo",
-                name: "Turbo",
+                id: "turbo".to_string(),
+                name: "Turbo".to_string(),
                 version,
             },
         }
@@ -142,7 +142,7 @@ pub async fn login<T: Client + TokenClient + CacheClient>(
 
 #[cfg(test)]
 mod tests {
-    use std::{assert_matches::assert_matches, sync::atomic::AtomicUsize};
+    use std::{assert_matches, sync::atomic::AtomicUsize};
 
     use async_trait::async_trait;
     use reqwest::{Method, RequestBuilder, Response};
@@ -326,6 +326,7 @@ mod tests {
             _body_len: usize,
             _duration: u64,
             _tag: Option<&str>,
+            _checksum: Option<&str>,
             _token: &str,
             _team_id: Option<&str>,
             _team_slug: Option<&str>,
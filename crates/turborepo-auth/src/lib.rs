@@ -434,6 +434,7 @@ mod tests {
             _body_len: usize,
             _duration: u64,
             _tag: Option<&str>,
+            _checksum: Option<&str>,
             _token: &str,
             _team_id: Option<&str>,
             _team_slug: Option<&str>,
@@ -171,10 +171,10 @@ impl AsyncCache {
         self.real_cache.fetch(anchor, key).await
     }
 
-    // Used for testing to ensure that the workers resolve
-    // before checking the cache.
+    /// Waits for every write that has been queued so far to finish.
+    /// Unlike `shutdown`, the cache keeps accepting writes afterwards.
     #[tracing::instrument(skip_all)]
-    pub async fn wait(&self) -> Result<(), CacheError> {
+    pub async fn flush(&self) -> Result<(), CacheError> {
         let (tx, rx) = oneshot::channel();
         self.writer_sender
             .send(WorkerRequest::Flush(tx))
@@ -215,7 +215,7 @@ impl AsyncCache {
 
 #[cfg(test)]
 mod tests {
-    use std::{assert_matches::assert_matches, time::Duration};
+    use std::{assert_matches, time::Duration};
 
     use anyhow::Result;
     use camino::Utf8PathBuf;
@@ -246,6 +246,69 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_flush_waits_for_pending_writes() -> Result<()> {
+        let repo_root = tempdir()?;
+        let repo_root_path = AbsoluteSystemPathBuf::try_from(repo_root.path())?;
+        let test_cases = get_test_cases();
+        for test_case in &test_cases {
+            test_case.initialize(&repo_root_path)?;
+        }
+
+        let opts = CacheOpts {
+            cache_dir: Utf8PathBuf::from(".turbo/cache"),
+            cache_read_dirs: Vec::new(),
+            cache_max_size: None,
+            remote_cache_read_only: false,
+            skip_remote: true,
+            skip_filesystem: false,
+            workers: 2,
+            remote_cache_opts: None,
+            s3: None,
+        };
+        let api_client = APIClient::new(
+            "http://example.com",
+            Some(Duration::from_secs(200)),
+            None,
+            "2.0.0",
+            true,
+        )?;
+        let async_cache = AsyncCache::new(&opts, &repo_root_path, api_client, None, None)?;
+
+        // Queue more writes than there are workers
+        for test_case in &test_cases {
+            async_cache
+                .put(
+                    repo_root_path.clone(),
+                    test_case.hash.to_string(),
+                    test_case
+                        .files
+                        .iter()
+                        .map(|f| f.path().to_owned())
+                        .collect(),
+                    test_case.duration,
+                )
+                .await?;
+        }
+
+        async_cache.flush().await?;
+
+        for test_case in &test_cases {
+            let fs_cache_path = repo_root_path.join_components(&[
+                ".turbo",
+                "cache",
+                &format!("{}.tar.zst", test_case.hash),
+            ]);
+            assert!(fs_cache_path.exists(), "{} was not written", test_case.hash);
+        }
+
+        // The cache is still usable after a flush
+        assert!(async_cache.exists(test_cases[0].hash).await?.is_some());
+        async_cache.shutdown().await?;
+
+        Ok(())
+    }
+
     async fn round_trip_test_without_fs(test_case: &TestCase, port: u16) -> Result<()> {
         let repo_root = tempdir()?;
         let repo_root_path = AbsoluteSystemPathBuf::try_from(repo_root.path())?;
@@ -255,6 +318,8 @@ mod tests {
 
         let opts = CacheOpts {
             cache_dir: Utf8PathBuf::from(".turbo/cache"),
+            cache_read_dirs: Vec::new(),
+            cache_max_size: None,
             remote_cache_read_only: false,
             skip_remote: false,
             skip_filesystem: true,
@@ -263,6 +328,7 @@ mod tests {
                 unused_team_id: Some("my-team".to_string()),
                 signature: false,
             }),
+            s3: None,
         };
 
         let api_client = APIClient::new(
@@ -300,7 +366,7 @@ mod tests {
             .unwrap();
 
         // Wait for async cache to process
-        async_cache.wait().await.unwrap();
+        async_cache.flush().await.unwrap();
 
         let fs_cache_path =
             repo_root_path.join_components(&[".turbo", "cache", &format!("{}.tar.zst", hash)]);
@@ -337,6 +403,8 @@ mod tests {
 
         let opts = CacheOpts {
             cache_dir: Utf8PathBuf::from(".turbo/cache"),
+            cache_read_dirs: Vec::new(),
+            cache_max_size: None,
             remote_cache_read_only: false,
             skip_remote: true,
             skip_filesystem: false,
@@ -345,6 +413,7 @@ mod tests {
                 unused_team_id: Some("my-team".to_string()),
                 signature: false,
             }),
+            s3: None,
         };
 
         // Initialize client with invalid API url to ensure that we don't hit the
@@ -384,7 +453,7 @@ mod tests {
             .unwrap();
 
         // Wait for async cache to process
-        async_cache.wait().await.unwrap();
+        async_cache.flush().await.unwrap();
 
         let fs_cache_path =
             repo_root_path.join_components(&[".turbo", "cache", &format!("{}.tar.zst", hash)]);
@@ -429,6 +498,8 @@ mod tests {
 
         let opts = CacheOpts {
             cache_dir: Utf8PathBuf::from(".turbo/cache"),
+            cache_read_dirs: Vec::new(),
+            cache_max_size: None,
             remote_cache_read_only: false,
             skip_remote: false,
             skip_filesystem: false,
@@ -437,6 +508,7 @@ mod tests {
                 unused_team_id: Some("my-team".to_string()),
                 signature: false,
             }),
+            s3: None,
         };
 
         let api_client = APIClient::new(
@@ -474,7 +546,7 @@ mod tests {
             .unwrap();
 
         // Wait for async cache to process
-        async_cache.wait().await.unwrap();
+        async_cache.flush().await.unwrap();
 
         let fs_cache_path =
             repo_root_path.join_components(&[".turbo", "cache", &format!("{}.tar.zst", hash)]);
@@ -1,7 +1,15 @@
-use std::{backtrace::Backtrace, fs::OpenOptions};
+use std::{
+    backtrace::Backtrace,
+    collections::HashMap,
+    fs::OpenOptions,
+    io,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPathBuf};
 use turborepo_analytics::AnalyticsSender;
 use turborepo_api_client::{analytics, analytics::AnalyticsEvent};
@@ -13,13 +21,20 @@ use crate::{
 
 pub struct FSCache {
     cache_directory: AbsoluteSystemPathBuf,
+    // Checked in order after `cache_directory`, these are never written to
+    read_directories: Vec<AbsoluteSystemPathBuf>,
     analytics_recorder: Option<AnalyticsSender>,
+    max_size: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 struct CacheMetadata {
     hash: String,
     duration: u64,
+    // SHA-256 of the archive as written to disk. Artifacts written by older
+    // versions of turbo don't have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
 }
 
 impl CacheMetadata {
@@ -29,6 +44,40 @@ impl CacheMetadata {
     }
 }
 
+/// The outcome of verifying a single artifact in the local cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArtifactStatus {
+    Valid,
+    /// The artifact can be read, but has no checksum to compare against
+    Unverified,
+    Corrupt(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactVerification {
+    pub hash: String,
+    pub path: AbsoluteSystemPathBuf,
+    pub status: ArtifactStatus,
+}
+
+fn archive_sha(path: &AbsoluteSystemPath) -> Result<String, CacheError> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut path.open()?, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+// The files belonging to a single hash, used for eviction
+struct CacheEntry {
+    size: u64,
+    last_access: SystemTime,
+}
+
+fn hash_from_file_name(name: &str) -> Option<&str> {
+    name.strip_suffix("-meta.json")
+        .or_else(|| name.strip_suffix(".tar.zst"))
+        .or_else(|| name.strip_suffix(".tar"))
+}
+
 impl FSCache {
     fn resolve_cache_dir(
         repo_root: &AbsoluteSystemPath,
@@ -48,10 +97,48 @@ impl FSCache {
 
         Ok(FSCache {
             cache_directory,
+            read_directories: Vec::new(),
             analytics_recorder,
+            max_size: None,
         })
     }
 
+    /// Bounds the size of the cache directory. Once a write pushes the cache
+    /// over `max_size` bytes, the least recently used artifacts are evicted.
+    pub fn with_max_size(mut self, max_size: Option<u64>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Adds cache directories that artifacts are read from, but never written
+    /// to, e.g. a warm cache shared between runners. They're checked in
+    /// order when an artifact isn't in the cache directory.
+    pub fn with_read_dirs(
+        mut self,
+        repo_root: &AbsoluteSystemPath,
+        read_dirs: &[Utf8PathBuf],
+    ) -> Self {
+        self.read_directories = read_dirs
+            .iter()
+            .map(|read_dir| Self::resolve_cache_dir(repo_root, read_dir))
+            .collect();
+        self
+    }
+
+    // Returns the directory that holds the artifact for `hash` along with the
+    // path to the artifact
+    fn find_artifact(&self, hash: &str) -> Option<(&AbsoluteSystemPath, AbsoluteSystemPathBuf)> {
+        std::iter::once(&self.cache_directory)
+            .chain(&self.read_directories)
+            .find_map(|directory| {
+                [format!("{}.tar", hash), format!("{}.tar.zst", hash)]
+                    .iter()
+                    .map(|file_name| directory.join_component(file_name))
+                    .find(|path| path.exists())
+                    .map(|path| (&**directory, path))
+            })
+    }
+
     fn log_fetch(&self, event: analytics::CacheEvent, hash: &str, duration: u64) {
         // If analytics fails to record, it's not worth failing the cache
         if let Some(analytics_recorder) = &self.analytics_recorder {
@@ -73,18 +160,7 @@ impl FSCache {
         anchor: &AbsoluteSystemPath,
         hash: &str,
     ) -> Result<Option<(CacheHitMetadata, Vec<AnchoredSystemPathBuf>)>, CacheError> {
-        let uncompressed_cache_path = self
-            .cache_directory
-            .join_component(&format!("{}.tar", hash));
-        let compressed_cache_path = self
-            .cache_directory
-            .join_component(&format!("{}.tar.zst", hash));
-
-        let cache_path = if uncompressed_cache_path.exists() {
-            uncompressed_cache_path
-        } else if compressed_cache_path.exists() {
-            compressed_cache_path
-        } else {
+        let Some((cache_directory, cache_path)) = self.find_artifact(hash) else {
             self.log_fetch(analytics::CacheEvent::Miss, hash, 0);
             return Ok(None);
         };
@@ -93,11 +169,18 @@ impl FSCache {
 
         let restored_files = cache_reader.restore(anchor)?;
 
-        let meta = CacheMetadata::read(
-            &self
-                .cache_directory
-                .join_component(&format!("{}-meta.json", hash)),
-        )?;
+        let metadata_path = cache_directory.join_component(&format!("{}-meta.json", hash));
+        let meta = CacheMetadata::read(&metadata_path)?;
+
+        // Nothing is evicted from read-only directories, so there's no access
+        // time to update there
+        if self.max_size.is_some() && cache_directory == &*self.cache_directory {
+            // The modification time of the metadata file doubles as the last
+            // access time for eviction
+            if let Err(e) = Self::touch(&metadata_path) {
+                debug!("failed to update access time for {hash}: {e}");
+            }
+        }
 
         self.log_fetch(analytics::CacheEvent::Hit, hash, meta.duration);
 
@@ -112,24 +195,14 @@ impl FSCache {
 
     #[tracing::instrument(skip_all)]
     pub(crate) fn exists(&self, hash: &str) -> Result<Option<CacheHitMetadata>, CacheError> {
-        let uncompressed_cache_path = self
-            .cache_directory
-            .join_component(&format!("{}.tar", hash));
-        let compressed_cache_path = self
-            .cache_directory
-            .join_component(&format!("{}.tar.zst", hash));
-
-        if !uncompressed_cache_path.exists() && !compressed_cache_path.exists() {
+        let Some((cache_directory, _)) = self.find_artifact(hash) else {
             return Ok(None);
-        }
+        };
 
-        let duration = CacheMetadata::read(
-            &self
-                .cache_directory
-                .join_component(&format!("{}-meta.json", hash)),
-        )
-        .map(|meta| meta.duration)
-        .unwrap_or(0);
+        let duration =
+            CacheMetadata::read(&cache_directory.join_component(&format!("{}-meta.json", hash)))
+                .map(|meta| meta.duration)
+                .unwrap_or(0);
 
         Ok(Some(CacheHitMetadata {
             time_saved: duration,
@@ -155,6 +228,8 @@ impl FSCache {
             cache_item.add_file(anchor, file)?;
         }
 
+        cache_item.finish()?;
+
         let metadata_path = self
             .cache_directory
             .join_component(&format!("{}-meta.json", hash));
@@ -162,6 +237,7 @@ impl FSCache {
         let meta = CacheMetadata {
             hash: hash.to_string(),
             duration,
+            sha: Some(archive_sha(&cache_path)?),
         };
 
         let mut metadata_options = OpenOptions::new();
@@ -172,6 +248,137 @@ impl FSCache {
         serde_json::to_writer(metadata_file, &meta)
             .map_err(|e| CacheError::InvalidMetadata(e, Backtrace::capture()))?;
 
+        // The artifact has been written at this point, so failing to evict
+        // shouldn't fail the write
+        if let Err(e) = self.evict(hash) {
+            warn!("failed to evict artifacts from the local cache: {e}");
+        }
+
+        Ok(())
+    }
+
+    fn touch(path: &AbsoluteSystemPath) -> Result<(), io::Error> {
+        let mut options = OpenOptions::new();
+        options.write(true);
+        path.open_with_options(options)?
+            .set_modified(SystemTime::now())
+    }
+
+    /// Removes the least recently used artifacts until the cache is within
+    /// its size limit. The artifact for `keep` is never removed.
+    fn evict(&self, keep: &str) -> Result<(), CacheError> {
+        let Some(max_size) = self.max_size else {
+            return Ok(());
+        };
+
+        let mut entries: HashMap<String, CacheEntry> = HashMap::new();
+        for dir_entry in self.cache_directory.as_std_path().read_dir()? {
+            let dir_entry = dir_entry?;
+            let file_name = dir_entry.file_name();
+            let Some(hash) = file_name.to_str().and_then(hash_from_file_name) else {
+                continue;
+            };
+            let metadata = dir_entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            let entry = entries
+                .entry(hash.to_string())
+                .or_insert_with(|| CacheEntry {
+                    size: 0,
+                    last_access: UNIX_EPOCH,
+                });
+            entry.size += metadata.len();
+            entry.last_access = entry.last_access.max(metadata.modified()?);
+        }
+
+        let mut total_size: u64 = entries.values().map(|entry| entry.size).sum();
+        if total_size <= max_size {
+            return Ok(());
+        }
+
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|(a_hash, a), (b_hash, b)| {
+            a.last_access
+                .cmp(&b.last_access)
+                .then_with(|| a_hash.cmp(b_hash))
+        });
+
+        for (hash, entry) in entries {
+            if total_size <= max_size {
+                break;
+            }
+            if hash == keep {
+                continue;
+            }
+            debug!("evicting {hash} from the local cache");
+            self.remove(&hash)?;
+            total_size -= entry.size;
+        }
+
+        Ok(())
+    }
+
+    /// Checks every artifact in the cache directory against the checksum
+    /// stored in its metadata. Artifacts without a checksum are only checked
+    /// to be readable.
+    #[tracing::instrument(skip_all)]
+    pub fn verify(&self) -> Result<Vec<ArtifactVerification>, CacheError> {
+        let mut verifications = Vec::new();
+        for entry in self.cache_directory.as_std_path().read_dir()? {
+            let path = AbsoluteSystemPathBuf::try_from(entry?.path())?;
+            let Some(hash) = path.file_name().and_then(|name| {
+                name.strip_suffix(".tar.zst")
+                    .or_else(|| name.strip_suffix(".tar"))
+            }) else {
+                continue;
+            };
+            let hash = hash.to_string();
+            let status = self.verify_artifact(&hash, &path);
+            verifications.push(ArtifactVerification { hash, path, status });
+        }
+        verifications.sort_by(|a, b| a.hash.cmp(&b.hash));
+
+        Ok(verifications)
+    }
+
+    fn verify_artifact(&self, hash: &str, path: &AbsoluteSystemPath) -> ArtifactStatus {
+        let meta = match CacheMetadata::read(
+            &self
+                .cache_directory
+                .join_component(&format!("{}-meta.json", hash)),
+        ) {
+            Ok(meta) => meta,
+            Err(e) => return ArtifactStatus::Corrupt(format!("unreadable metadata: {e}")),
+        };
+
+        match meta.sha {
+            Some(expected) => match archive_sha(path) {
+                Ok(actual) if actual == expected => ArtifactStatus::Valid,
+                Ok(_) => ArtifactStatus::Corrupt("checksum mismatch".to_string()),
+                Err(e) => ArtifactStatus::Corrupt(format!("unreadable artifact: {e}")),
+            },
+            // Decompressing the whole archive at least catches truncated files
+            None => match CacheReader::open(&path.to_owned()).and_then(|reader| reader.get_sha()) {
+                Ok(_) => ArtifactStatus::Unverified,
+                Err(e) => ArtifactStatus::Corrupt(format!("unreadable artifact: {e}")),
+            },
+        }
+    }
+
+    /// Removes an artifact and its metadata from the cache
+    #[tracing::instrument(skip_all)]
+    pub fn remove(&self, hash: &str) -> Result<(), CacheError> {
+        for file_name in [
+            format!("{}.tar", hash),
+            format!("{}.tar.zst", hash),
+            format!("{}-meta.json", hash),
+        ] {
+            let path = self.cache_directory.join_component(&file_name);
+            if path.exists() {
+                path.remove_file()?;
+            }
+        }
         Ok(())
     }
 }
@@ -191,6 +398,103 @@ mod test {
     use super::*;
     use crate::test_cases::{get_test_cases, validate_analytics, TestCase};
 
+    fn set_last_access(cache_dir: &AbsoluteSystemPath, hash: &str, time: SystemTime) -> Result<()> {
+        for file_name in [format!("{hash}.tar.zst"), format!("{hash}-meta.json")] {
+            std::fs::File::options()
+                .write(true)
+                .open(cache_dir.join_component(&file_name))?
+                .set_modified(time)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() -> Result<()> {
+        let repo_root = tempdir()?;
+        let repo_root_path = AbsoluteSystemPath::from_std_path(repo_root.path())?;
+        let cache_dir = repo_root_path.join_component("cache");
+        let file = AnchoredSystemPathBuf::from_raw("output.txt")?;
+        repo_root_path
+            .join_component("output.txt")
+            .create_with_contents("some output")?;
+        let files = [file];
+
+        // Every artifact has the same contents and the same length hash, so
+        // they all take up the same amount of space
+        let cache = FSCache::new(Utf8Path::new("cache"), repo_root_path, None)?;
+        cache.put(repo_root_path, "a", &files, 10)?;
+        let entry_size = ["a.tar.zst", "a-meta.json"]
+            .iter()
+            .map(|name| Ok(cache_dir.join_component(name).symlink_metadata()?.len()))
+            .sum::<Result<u64>>()?;
+
+        // Room for two artifacts, but not three
+        let cache = cache.with_max_size(Some(entry_size * 2 + entry_size / 2));
+        cache.put(repo_root_path, "b", &files, 10)?;
+        let an_hour_ago = SystemTime::now() - Duration::from_secs(60 * 60);
+        set_last_access(&cache_dir, "a", an_hour_ago)?;
+        set_last_access(&cache_dir, "b", an_hour_ago + Duration::from_secs(1))?;
+
+        // Reading "a" makes "b" the least recently used artifact
+        assert!(cache.fetch(repo_root_path, "a")?.is_some());
+        cache.put(repo_root_path, "c", &files, 10)?;
+        assert!(cache.exists("a")?.is_some());
+        assert!(cache.exists("b")?.is_none());
+        assert!(cache.exists("c")?.is_some());
+
+        set_last_access(&cache_dir, "a", an_hour_ago)?;
+        cache.put(repo_root_path, "d", &files, 10)?;
+        assert!(cache.exists("a")?.is_none());
+        assert!(cache.exists("c")?.is_some());
+        assert!(cache.exists("d")?.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reads_from_read_only_dirs() -> Result<()> {
+        let repo_root = tempdir()?;
+        let repo_root_path = AbsoluteSystemPath::from_std_path(repo_root.path())?;
+        let file = AnchoredSystemPathBuf::from_raw("output.txt")?;
+        let output = repo_root_path.resolve(&file);
+        output.create_with_contents("shared output")?;
+
+        let shared = FSCache::new(Utf8Path::new("shared"), repo_root_path, None)?;
+        shared.put(repo_root_path, "warm", &[file.clone()], 10)?;
+        output.remove_file()?;
+        let shared_dir = repo_root_path.join_component("shared");
+        let shared_files = || -> Result<Vec<_>> {
+            let mut files = std::fs::read_dir(&shared_dir)?
+                .map(|entry| Ok(entry?.file_name()))
+                .collect::<Result<Vec<_>>>()?;
+            files.sort();
+            Ok(files)
+        };
+        let shared_before = shared_files()?;
+
+        let cache = FSCache::new(Utf8Path::new("cache"), repo_root_path, None)?
+            .with_read_dirs(
+                repo_root_path,
+                &[Utf8PathBuf::from("empty"), Utf8PathBuf::from("shared")],
+            )
+            .with_max_size(Some(u64::MAX));
+        assert!(cache.exists("warm")?.is_some());
+        let (hit, files) = cache.fetch(repo_root_path, "warm")?.unwrap();
+        assert_eq!(hit.time_saved, 10);
+        assert_eq!(files, vec![file.clone()]);
+        assert_eq!(output.read_to_string()?, "shared output");
+        assert!(cache.fetch(repo_root_path, "cold")?.is_none());
+
+        // New artifacts only go to the cache directory
+        cache.put(repo_root_path, "new", &[file], 10)?;
+        assert!(repo_root_path
+            .join_components(&["cache", "new.tar.zst"])
+            .exists());
+        assert_eq!(shared_files()?, shared_before);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_fs_cache() -> Result<()> {
         let port = port_scanner::request_open_port().unwrap();
@@ -209,6 +513,48 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_verify_flags_corrupt_artifacts() -> Result<()> {
+        let repo_root = tempdir()?;
+        let repo_root_path = AbsoluteSystemPath::from_std_path(repo_root.path())?;
+        let file = AnchoredSystemPathBuf::from_raw("output.txt")?;
+        repo_root_path
+            .resolve(&file)
+            .create_with_contents("some build output")?;
+
+        let cache = FSCache::new(Utf8Path::new("cache"), repo_root_path, None)?;
+        cache.put(repo_root_path, "good", &[file.clone()], 10)?;
+        cache.put(repo_root_path, "bad", &[file], 10)?;
+
+        let bad_path = repo_root_path.join_components(&["cache", "bad.tar.zst"]);
+        let mut contents = std::fs::read(&bad_path)?;
+        let last = contents.len() - 1;
+        contents[last] ^= 0xff;
+        bad_path.create_with_contents(contents)?;
+
+        let verifications = cache.verify()?;
+        let statuses = verifications
+            .iter()
+            .map(|v| (v.hash.as_str(), v.status.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                (
+                    "bad",
+                    ArtifactStatus::Corrupt("checksum mismatch".to_string())
+                ),
+                ("good", ArtifactStatus::Valid),
+            ]
+        );
+
+        cache.remove("bad")?;
+        assert!(!bad_path.exists());
+        assert_eq!(cache.verify()?.len(), 1);
+
+        Ok(())
+    }
+
     async fn round_trip_test(test_case: &TestCase, port: u16) -> Result<()> {
         let repo_root = tempdir()?;
         let repo_root_path = AbsoluteSystemPath::from_std_path(repo_root.path())?;
@@ -5,18 +5,20 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+use sha2::{Digest, Sha256};
 use tokio_stream::StreamExt;
 use tracing::debug;
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPathBuf};
 use turborepo_analytics::AnalyticsSender;
 use turborepo_api_client::{
     analytics::{self, AnalyticsEvent},
-    APIAuth, APIClient, CacheClient, Response,
+    APIAuth, APIClient,
 };
 
 use crate::{
     cache_archive::{CacheReader, CacheWriter},
     signature_authentication::ArtifactSignatureAuthenticator,
+    transport::{ArtifactMetadata, CacheTransport, RemoteTransport, VercelTransport},
     upload_progress::{UploadProgress, UploadProgressQuery},
     CacheError, CacheHitMetadata, CacheOpts, CacheSource,
 };
@@ -24,10 +26,9 @@ use crate::{
 pub type UploadMap = HashMap<String, UploadProgressQuery<10, 100>>;
 
 pub struct HTTPCache {
-    client: APIClient,
+    transport: RemoteTransport,
     signer_verifier: Option<ArtifactSignatureAuthenticator>,
     repo_root: AbsoluteSystemPathBuf,
-    api_auth: APIAuth,
     analytics_recorder: Option<AnalyticsSender>,
     uploads: Arc<Mutex<UploadMap>>,
 }
@@ -40,6 +41,26 @@ impl HTTPCache {
         repo_root: AbsoluteSystemPathBuf,
         api_auth: APIAuth,
         analytics_recorder: Option<AnalyticsSender>,
+    ) -> HTTPCache {
+        let team_id = api_auth.team_id.clone();
+        Self::with_transport(
+            RemoteTransport::Vercel(VercelTransport::new(client, api_auth)),
+            opts,
+            repo_root,
+            team_id.as_deref(),
+            analytics_recorder,
+        )
+    }
+
+    /// Creates a remote cache that stores artifacts using `transport`.
+    /// `team_id` is used when signing artifacts.
+    #[tracing::instrument(skip_all)]
+    pub fn with_transport(
+        transport: RemoteTransport,
+        opts: &CacheOpts,
+        repo_root: AbsoluteSystemPathBuf,
+        team_id: Option<&str>,
+        analytics_recorder: Option<AnalyticsSender>,
     ) -> HTTPCache {
         let signer_verifier = if opts
             .remote_cache_opts
@@ -47,24 +68,19 @@ impl HTTPCache {
             .map_or(false, |remote_cache_opts| remote_cache_opts.signature)
         {
             Some(ArtifactSignatureAuthenticator {
-                team_id: api_auth
-                    .team_id
-                    .as_deref()
-                    .unwrap_or_default()
-                    .as_bytes()
-                    .to_vec(),
+                team_id: team_id.unwrap_or_default().as_bytes().to_vec(),
                 secret_key_override: None,
+                previous_secret_key_override: None,
             })
         } else {
             None
         };
 
         HTTPCache {
-            client,
+            transport,
             signer_verifier,
             repo_root,
             uploads: Arc::new(Mutex::new(HashMap::new())),
-            api_auth,
             analytics_recorder,
         }
     }
@@ -86,6 +102,11 @@ impl HTTPCache {
             .as_ref()
             .map(|signer| signer.generate_tag(hash.as_bytes(), &artifact_body))
             .transpose()?;
+        let metadata = ArtifactMetadata {
+            duration,
+            tag,
+            checksum: Some(artifact_checksum(&artifact_body)),
+        };
 
         let stream = tokio_util::codec::FramedRead::new(
             Cursor::new(artifact_body),
@@ -105,20 +126,7 @@ impl HTTPCache {
 
         tracing::debug!("uploading {}", hash);
 
-        match self
-            .client
-            .put_artifact(
-                hash,
-                progress,
-                bytes,
-                duration,
-                tag.as_deref(),
-                &self.api_auth.token,
-                self.api_auth.team_id.as_deref(),
-                self.api_auth.team_slug.as_deref(),
-            )
-            .await
-        {
+        match self.transport.put(hash, progress, bytes, &metadata).await {
             Ok(_) => {
                 tracing::debug!("uploaded {}", hash);
                 Ok(())
@@ -150,41 +158,16 @@ impl HTTPCache {
 
     #[tracing::instrument(skip_all)]
     pub async fn exists(&self, hash: &str) -> Result<Option<CacheHitMetadata>, CacheError> {
-        let Some(response) = self
-            .client
-            .artifact_exists(
-                hash,
-                &self.api_auth.token,
-                self.api_auth.team_id.as_deref(),
-                self.api_auth.team_slug.as_deref(),
-            )
-            .await?
-        else {
+        let Some(metadata) = self.transport.exists(hash).await? else {
             return Ok(None);
         };
 
-        let duration = Self::get_duration_from_response(&response)?;
-
         Ok(Some(CacheHitMetadata {
             source: CacheSource::Remote,
-            time_saved: duration,
+            time_saved: metadata.duration,
         }))
     }
 
-    fn get_duration_from_response(response: &Response) -> Result<u64, CacheError> {
-        if let Some(duration_value) = response.headers().get("x-artifact-duration") {
-            let duration = duration_value
-                .to_str()
-                .map_err(|_| CacheError::InvalidDuration(Backtrace::capture()))?;
-
-            duration
-                .parse::<u64>()
-                .map_err(|_| CacheError::InvalidDuration(Backtrace::capture()))
-        } else {
-            Ok(0)
-        }
-    }
-
     fn log_fetch(&self, event: analytics::CacheEvent, hash: &str, duration: u64) {
         // If analytics fails to record, it's not worth failing the cache
         if let Some(analytics_recorder) = &self.analytics_recorder {
@@ -205,67 +188,54 @@ impl HTTPCache {
         &self,
         hash: &str,
     ) -> Result<Option<(CacheHitMetadata, Vec<AnchoredSystemPathBuf>)>, CacheError> {
-        let Some(response) = self
-            .client
-            .fetch_artifact(
-                hash,
-                &self.api_auth.token,
-                self.api_auth.team_id.as_deref(),
-                self.api_auth.team_slug.as_deref(),
-            )
-            .await?
-        else {
+        let Some((metadata, body)) = self.transport.fetch(hash).await? else {
             self.log_fetch(analytics::CacheEvent::Miss, hash, 0);
             return Ok(None);
         };
 
-        let duration = Self::get_duration_from_response(&response)?;
-
-        let body = if let Some(signer_verifier) = &self.signer_verifier {
-            let expected_tag = response
-                .headers()
-                .get("x-artifact-tag")
-                .ok_or(CacheError::ArtifactTagMissing(Backtrace::capture()))?;
-
-            let expected_tag = expected_tag
-                .to_str()
-                .map_err(|_| CacheError::InvalidTag(Backtrace::capture()))?
-                .to_string();
-
-            let body = response.bytes().await.map_err(|e| {
-                CacheError::ApiClientError(
-                    Box::new(turborepo_api_client::Error::ReqwestError(e)),
-                    Backtrace::capture(),
-                )
-            })?;
-            let is_valid = signer_verifier.validate(hash.as_bytes(), &body, &expected_tag)?;
-
-            if !is_valid {
-                return Err(CacheError::InvalidTag(Backtrace::capture()));
-            }
-
-            body
-        } else {
-            response.bytes().await.map_err(|e| {
-                CacheError::ApiClientError(
-                    Box::new(turborepo_api_client::Error::ReqwestError(e)),
-                    Backtrace::capture(),
-                )
-            })?
-        };
+        self.verify_artifact(hash, &body, &metadata)?;
 
         let files = Self::restore_tar(&self.repo_root, &body)?;
 
-        self.log_fetch(analytics::CacheEvent::Hit, hash, duration);
+        self.log_fetch(analytics::CacheEvent::Hit, hash, metadata.duration);
         Ok(Some((
             CacheHitMetadata {
                 source: CacheSource::Remote,
-                time_saved: duration,
+                time_saved: metadata.duration,
             },
             files,
         )))
     }
 
+    // Makes sure a downloaded artifact wasn't corrupted or tampered with before
+    // anything is restored from it. Servers that don't store checksums won't
+    // send one, in which case only the signature can be checked.
+    fn verify_artifact(
+        &self,
+        hash: &str,
+        body: &[u8],
+        metadata: &ArtifactMetadata,
+    ) -> Result<(), CacheError> {
+        if let Some(expected_checksum) = &metadata.checksum {
+            if *expected_checksum != artifact_checksum(body) {
+                return Err(CacheError::ChecksumMismatch(Backtrace::capture()));
+            }
+        }
+
+        if let Some(signer_verifier) = &self.signer_verifier {
+            let expected_tag = metadata
+                .tag
+                .as_deref()
+                .ok_or(CacheError::ArtifactTagMissing(Backtrace::capture()))?;
+
+            if !signer_verifier.validate(hash.as_bytes(), body, expected_tag)? {
+                return Err(CacheError::InvalidTag(Backtrace::capture()));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn requests(&self) -> Arc<Mutex<UploadMap>> {
         self.uploads.clone()
     }
@@ -280,6 +250,10 @@ impl HTTPCache {
     }
 }
 
+fn artifact_checksum(body: &[u8]) -> String {
+    hex::encode(Sha256::digest(body))
+}
+
 #[cfg(test)]
 mod test {
     use std::time::Duration;
@@ -293,9 +267,11 @@ mod test {
     use turborepo_vercel_api_mock::start_test_server;
 
     use crate::{
-        http::{APIAuth, HTTPCache},
+        http::{artifact_checksum, APIAuth, HTTPCache},
+        signature_authentication::ArtifactSignatureAuthenticator,
         test_cases::{get_test_cases, validate_analytics, TestCase},
-        CacheOpts, CacheSource,
+        transport::ArtifactMetadata,
+        CacheError, CacheOpts, CacheSource,
     };
 
     #[tokio::test]
@@ -316,6 +292,52 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_tampered_artifact_is_rejected() -> Result<()> {
+        let repo_root = tempdir()?;
+        let api_client = APIClient::new("http://localhost:0", None, None, "2.0.0", true)?;
+        let mut cache = HTTPCache::new(
+            api_client,
+            &CacheOpts::default(),
+            AbsoluteSystemPathBuf::try_from(repo_root.path())?,
+            APIAuth {
+                team_id: Some("my-team".to_string()),
+                token: "my-token".to_string(),
+                team_slug: None,
+            },
+            None,
+        );
+        cache.signer_verifier = Some(ArtifactSignatureAuthenticator::new(
+            b"my-team".to_vec(),
+            Some(b"secret".to_vec()),
+        ));
+        let signer = cache.signer_verifier.as_ref().unwrap();
+
+        let body = b"artifact contents".to_vec();
+        let mut metadata = ArtifactMetadata {
+            duration: 0,
+            tag: Some(signer.generate_tag(b"some-hash", &body)?),
+            checksum: Some(artifact_checksum(&body)),
+        };
+        cache.verify_artifact("some-hash", &body, &metadata)?;
+
+        let mut tampered = body.clone();
+        tampered[0] ^= 1;
+        assert!(matches!(
+            cache.verify_artifact("some-hash", &tampered, &metadata),
+            Err(CacheError::ChecksumMismatch(_))
+        ));
+
+        // A matching checksum isn't enough when the signature doesn't match
+        metadata.checksum = Some(artifact_checksum(&tampered));
+        assert!(matches!(
+            cache.verify_artifact("some-hash", &tampered, &metadata),
+            Err(CacheError::InvalidTag(_))
+        ));
+
+        Ok(())
+    }
+
     async fn round_trip_test(test_case: &TestCase, port: u16) -> Result<()> {
         let repo_root = tempdir()?;
         let repo_root_path = AbsoluteSystemPathBuf::try_from(repo_root.path())?;
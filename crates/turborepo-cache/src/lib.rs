@@ -14,14 +14,18 @@ pub mod http;
 /// A wrapper that allows reads and writes from the file system and remote
 /// cache.
 mod multiplexer;
+/// Remote cache transport for S3-compatible object storage
+pub mod s3;
 /// Cache signature authentication lets users provide a private key to sign
 /// their cache payloads.
 pub mod signature_authentication;
 #[cfg(test)]
 mod test_cases;
+/// Transports move artifacts to and from the remote cache
+pub mod transport;
 mod upload_progress;
 
-use std::{backtrace, backtrace::Backtrace};
+use std::{backtrace, backtrace::Backtrace, fmt};
 
 pub use async_cache::AsyncCache;
 use camino::Utf8PathBuf;
@@ -41,6 +45,8 @@ pub enum CacheError {
     ArtifactTagMissing(#[backtrace] Backtrace),
     #[error("invalid artifact verification tag")]
     InvalidTag(#[backtrace] Backtrace),
+    #[error("artifact verification failed: Downloaded artifact does not match its checksum")]
+    ChecksumMismatch(#[backtrace] Backtrace),
     #[error("cannot untar file to {0}")]
     InvalidFilePath(String, #[backtrace] Backtrace),
     #[error("failed to contact remote cache: {0}")]
@@ -103,17 +109,28 @@ pub struct CacheHitMetadata {
     pub time_saved: u64,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CacheOpts {
     pub cache_dir: Utf8PathBuf,
+    /// Directories that are only read from, checked in order when an artifact
+    /// isn't in `cache_dir`.
+    pub cache_read_dirs: Vec<Utf8PathBuf>,
+    /// Upper bound on the size of the local cache in bytes. Least recently
+    /// used artifacts are evicted once it is exceeded.
+    pub cache_max_size: Option<u64>,
     pub remote_cache_read_only: bool,
     pub skip_remote: bool,
     pub skip_filesystem: bool,
     pub workers: u32,
     pub remote_cache_opts: Option<RemoteCacheOpts>,
+    /// Use an S3-compatible bucket as the remote cache instead of the Vercel
+    /// Remote Cache API.
+    pub s3: Option<S3Opts>,
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
 pub struct RemoteCacheOpts {
     unused_team_id: Option<String>,
     signature: bool,
@@ -127,3 +144,34 @@ impl RemoteCacheOpts {
         }
     }
 }
+
+/// Location and credentials of an S3-compatible bucket
+#[derive(Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Opts {
+    /// Base URL of the S3 API, e.g. `https://s3.us-east-1.amazonaws.com`
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    #[serde(skip_serializing)]
+    pub access_key_id: String,
+    #[serde(skip_serializing)]
+    pub secret_access_key: String,
+    /// Timeout for requests in seconds, `0` means no timeout
+    pub timeout: u64,
+    /// Timeout for uploads in seconds, `0` means no timeout
+    pub upload_timeout: u64,
+}
+
+// Credentials are left out so they don't end up in logs
+impl fmt::Debug for S3Opts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Opts")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("timeout", &self.timeout)
+            .field("upload_timeout", &self.upload_timeout)
+            .finish_non_exhaustive()
+    }
+}
@@ -11,6 +11,8 @@ use turborepo_api_client::{APIAuth, APIClient};
 use crate::{
     fs::FSCache,
     http::{HTTPCache, UploadMap},
+    s3::S3Transport,
+    transport::RemoteTransport,
     CacheError, CacheHitMetadata, CacheOpts,
 };
 
@@ -48,21 +50,42 @@ impl CacheMultiplexer {
         }
 
         let fs_cache = use_fs_cache
-            .then(|| FSCache::new(&opts.cache_dir, repo_root, analytics_recorder.clone()))
+            .then(|| {
+                FSCache::new(&opts.cache_dir, repo_root, analytics_recorder.clone()).map(|cache| {
+                    cache
+                        .with_max_size(opts.cache_max_size)
+                        .with_read_dirs(repo_root, &opts.cache_read_dirs)
+                })
+            })
             .transpose()?;
 
+        // An S3 bucket brings its own credentials, so it doesn't need to be
+        // logged in to Vercel
         let http_cache = use_http_cache
-            .then_some(api_auth)
-            .flatten()
-            .map(|api_auth| {
-                HTTPCache::new(
-                    api_client,
-                    opts,
-                    repo_root.to_owned(),
-                    api_auth,
-                    analytics_recorder.clone(),
-                )
-            });
+            .then(|| match &opts.s3 {
+                Some(s3_opts) => S3Transport::new(s3_opts).map(|transport| {
+                    Some(HTTPCache::with_transport(
+                        RemoteTransport::S3(transport),
+                        opts,
+                        repo_root.to_owned(),
+                        api_auth
+                            .as_ref()
+                            .and_then(|api_auth| api_auth.team_id.as_deref()),
+                        analytics_recorder.clone(),
+                    ))
+                }),
+                None => Ok(api_auth.map(|api_auth| {
+                    HTTPCache::new(
+                        api_client,
+                        opts,
+                        repo_root.to_owned(),
+                        api_auth,
+                        analytics_recorder.clone(),
+                    )
+                })),
+            })
+            .transpose()?
+            .flatten();
 
         Ok(CacheMultiplexer {
             should_print_skipping_remote_put: AtomicBool::new(true),
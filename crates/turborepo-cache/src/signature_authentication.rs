@@ -28,6 +28,8 @@ pub struct ArtifactSignatureAuthenticator {
     pub(crate) team_id: Vec<u8>,
     // An override for testing purposes (to avoid env var race conditions)
     pub(crate) secret_key_override: Option<Vec<u8>>,
+    // Same as above, but for the key that was used before the last rotation
+    pub(crate) previous_secret_key_override: Option<Vec<u8>>,
 }
 
 impl ArtifactSignatureAuthenticator {
@@ -35,6 +37,7 @@ impl ArtifactSignatureAuthenticator {
         Self {
             team_id,
             secret_key_override,
+            previous_secret_key_override: None,
         }
     }
 
@@ -52,6 +55,26 @@ impl ArtifactSignatureAuthenticator {
             .into_raw_vec())
     }
 
+    // Gets the key that was in use before the current one was rotated in.
+    // Artifacts signed with it are still accepted, but nothing new is signed
+    // with it.
+    fn previous_secret_key(&self) -> Option<Vec<u8>> {
+        if let Some(secret_key) = &self.previous_secret_key_override {
+            return Some(secret_key.to_vec());
+        }
+
+        env::var_os("TURBO_REMOTE_CACHE_PREVIOUS_SIGNATURE_KEY")
+            .map(|secret_key| secret_key.into_raw_vec())
+            .filter(|secret_key| !secret_key.is_empty())
+    }
+
+    // All keys that an artifact's tag is checked against, current key first
+    fn verification_keys(&self) -> Result<Vec<Vec<u8>>, SignatureError> {
+        let mut keys = vec![self.secret_key()?];
+        keys.extend(self.previous_secret_key());
+        Ok(keys)
+    }
+
     fn construct_metadata(&self, hash: &[u8]) -> Result<Vec<u8>, SignatureError> {
         let mut metadata = hash.to_vec();
         metadata.extend_from_slice(&self.team_id);
@@ -101,13 +124,18 @@ impl ArtifactSignatureAuthenticator {
         artifact_body: &[u8],
         expected_tag: &str,
     ) -> Result<bool, SignatureError> {
-        let mut mac = HmacSha256::new_from_slice(&self.secret_key()?)?;
         let message = self.construct_metadata(hash)?;
-        mac.update(&message);
-        mac.update(artifact_body);
-
         let expected_bytes = BASE64_STANDARD.decode(expected_tag)?;
-        Ok(mac.verify_slice(&expected_bytes).is_ok())
+        for secret_key in self.verification_keys()? {
+            let mut mac = HmacSha256::new_from_slice(&secret_key)?;
+            mac.update(&message);
+            mac.update(artifact_body);
+            if mac.verify_slice(&expected_bytes).is_ok() {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
     }
 }
 
@@ -248,6 +276,7 @@ mod tests {
         let signature = ArtifactSignatureAuthenticator {
             team_id: test_case.team_id.to_vec(),
             secret_key_override: None,
+            previous_secret_key_override: None,
         };
 
         let hash = test_case.artifact_hash;
@@ -273,4 +302,29 @@ mod tests {
         assert!(signature.validate(hash, artifact_body, &tag)?);
         Ok(())
     }
+
+    #[test]
+    fn test_previous_key_still_verifies() -> Result<()> {
+        let hash = b"d5b7e4688f";
+        let artifact_body = &[5, 72, 219, 39, 156];
+        let old = ArtifactSignatureAuthenticator::new(b"my-team".to_vec(), Some(b"old".to_vec()));
+        let old_tag = old.generate_tag(hash, artifact_body)?;
+
+        let rotated = ArtifactSignatureAuthenticator {
+            previous_secret_key_override: Some(b"old".to_vec()),
+            ..ArtifactSignatureAuthenticator::new(b"my-team".to_vec(), Some(b"new".to_vec()))
+        };
+        assert!(rotated.validate(hash, artifact_body, &old_tag)?);
+
+        // New artifacts are signed with the current key
+        let new_tag = rotated.generate_tag(hash, artifact_body)?;
+        assert_ne!(new_tag, old_tag);
+        assert!(rotated.validate(hash, artifact_body, &new_tag)?);
+
+        // Once the old key is dropped its artifacts are rejected
+        let retired =
+            ArtifactSignatureAuthenticator::new(b"my-team".to_vec(), Some(b"new".to_vec()));
+        assert!(!retired.validate(hash, artifact_body, &old_tag)?);
+        Ok(())
+    }
 }
@@ -0,0 +1,230 @@
+use std::{backtrace::Backtrace, future::Future};
+
+use bytes::Bytes;
+use reqwest::header::HeaderMap;
+use turborepo_api_client::{APIAuth, APIClient, CacheClient, Stream};
+
+use crate::{s3::S3Transport, CacheError};
+
+const VERCEL_METADATA_PREFIX: &str = "x-artifact-";
+
+/// Metadata stored alongside an artifact in the remote cache
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArtifactMetadata {
+    /// How long the task took to run in milliseconds
+    pub duration: u64,
+    /// Signature of the artifact, only present if signing is enabled
+    pub tag: Option<String>,
+    /// Hex encoded SHA-256 of the artifact
+    pub checksum: Option<String>,
+}
+
+impl ArtifactMetadata {
+    // Reads the `duration`, `tag` and `checksum` headers, each of which is
+    // expected to start with `prefix`. Missing headers are left unset.
+    pub(crate) fn from_headers(headers: &HeaderMap, prefix: &str) -> Result<Self, CacheError> {
+        let header = |name: &str| headers.get(format!("{prefix}{name}"));
+
+        let duration = header("duration")
+            .map(|duration| {
+                duration
+                    .to_str()
+                    .ok()
+                    .and_then(|duration| duration.parse::<u64>().ok())
+                    .ok_or_else(|| CacheError::InvalidDuration(Backtrace::capture()))
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let tag = header("tag")
+            .map(|tag| {
+                tag.to_str()
+                    .map(str::to_string)
+                    .map_err(|_| CacheError::InvalidTag(Backtrace::capture()))
+            })
+            .transpose()?;
+        let checksum = header("checksum")
+            .map(|checksum| {
+                checksum
+                    .to_str()
+                    .map(str::to_string)
+                    .map_err(|_| CacheError::ChecksumMismatch(Backtrace::capture()))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            duration,
+            tag,
+            checksum,
+        })
+    }
+}
+
+/// Moves artifacts to and from a remote cache. Creating, signing and restoring
+/// artifacts is handled by `HTTPCache`, a transport only stores the bytes and
+/// their metadata.
+pub trait CacheTransport {
+    fn exists(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<Option<ArtifactMetadata>, CacheError>> + Send;
+    fn fetch(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<Option<(ArtifactMetadata, Bytes)>, CacheError>> + Send;
+    fn put(
+        &self,
+        hash: &str,
+        artifact_body: impl Stream<Item = turborepo_api_client::Result<Bytes>> + Send + Sync + 'static,
+        body_len: usize,
+        metadata: &ArtifactMetadata,
+    ) -> impl Future<Output = turborepo_api_client::Result<()>> + Send;
+}
+
+/// Transport for the Vercel Remote Cache API, or any server implementing it
+pub struct VercelTransport {
+    client: APIClient,
+    api_auth: APIAuth,
+}
+
+impl VercelTransport {
+    pub fn new(client: APIClient, api_auth: APIAuth) -> Self {
+        Self { client, api_auth }
+    }
+}
+
+impl CacheTransport for VercelTransport {
+    async fn exists(&self, hash: &str) -> Result<Option<ArtifactMetadata>, CacheError> {
+        let response = self
+            .client
+            .artifact_exists(
+                hash,
+                &self.api_auth.token,
+                self.api_auth.team_id.as_deref(),
+                self.api_auth.team_slug.as_deref(),
+            )
+            .await?;
+
+        response
+            .map(|response| {
+                ArtifactMetadata::from_headers(response.headers(), VERCEL_METADATA_PREFIX)
+            })
+            .transpose()
+    }
+
+    async fn fetch(&self, hash: &str) -> Result<Option<(ArtifactMetadata, Bytes)>, CacheError> {
+        let Some(response) = self
+            .client
+            .fetch_artifact(
+                hash,
+                &self.api_auth.token,
+                self.api_auth.team_id.as_deref(),
+                self.api_auth.team_slug.as_deref(),
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let metadata = ArtifactMetadata::from_headers(response.headers(), VERCEL_METADATA_PREFIX)?;
+        let body = response
+            .bytes()
+            .await
+            .map_err(turborepo_api_client::Error::ReqwestError)?;
+
+        Ok(Some((metadata, body)))
+    }
+
+    async fn put(
+        &self,
+        hash: &str,
+        artifact_body: impl Stream<Item = turborepo_api_client::Result<Bytes>> + Send + Sync + 'static,
+        body_len: usize,
+        metadata: &ArtifactMetadata,
+    ) -> turborepo_api_client::Result<()> {
+        self.client
+            .put_artifact(
+                hash,
+                artifact_body,
+                body_len,
+                metadata.duration,
+                metadata.tag.as_deref(),
+                metadata.checksum.as_deref(),
+                &self.api_auth.token,
+                self.api_auth.team_id.as_deref(),
+                self.api_auth.team_slug.as_deref(),
+            )
+            .await
+    }
+}
+
+/// The transports a remote cache can be configured to use
+pub enum RemoteTransport {
+    Vercel(VercelTransport),
+    S3(S3Transport),
+}
+
+impl CacheTransport for RemoteTransport {
+    async fn exists(&self, hash: &str) -> Result<Option<ArtifactMetadata>, CacheError> {
+        match self {
+            RemoteTransport::Vercel(transport) => transport.exists(hash).await,
+            RemoteTransport::S3(transport) => transport.exists(hash).await,
+        }
+    }
+
+    async fn fetch(&self, hash: &str) -> Result<Option<(ArtifactMetadata, Bytes)>, CacheError> {
+        match self {
+            RemoteTransport::Vercel(transport) => transport.fetch(hash).await,
+            RemoteTransport::S3(transport) => transport.fetch(hash).await,
+        }
+    }
+
+    async fn put(
+        &self,
+        hash: &str,
+        artifact_body: impl Stream<Item = turborepo_api_client::Result<Bytes>> + Send + Sync + 'static,
+        body_len: usize,
+        metadata: &ArtifactMetadata,
+    ) -> turborepo_api_client::Result<()> {
+        match self {
+            RemoteTransport::Vercel(transport) => {
+                transport.put(hash, artifact_body, body_len, metadata).await
+            }
+            RemoteTransport::S3(transport) => {
+                transport.put(hash, artifact_body, body_len, metadata).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use reqwest::header::HeaderMap;
+
+    use super::{ArtifactMetadata, VERCEL_METADATA_PREFIX};
+    use crate::CacheError;
+
+    #[test]
+    fn test_metadata_from_headers() -> anyhow::Result<()> {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-artifact-duration", "42".parse()?);
+        headers.insert("x-artifact-checksum", "abc".parse()?);
+
+        let metadata = ArtifactMetadata::from_headers(&headers, VERCEL_METADATA_PREFIX)?;
+        assert_eq!(
+            metadata,
+            ArtifactMetadata {
+                duration: 42,
+                tag: None,
+                checksum: Some("abc".to_string()),
+            }
+        );
+
+        headers.insert("x-artifact-duration", "soon".parse()?);
+        assert!(matches!(
+            ArtifactMetadata::from_headers(&headers, VERCEL_METADATA_PREFIX),
+            Err(CacheError::InvalidDuration(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -669,7 +669,7 @@ impl Subscriber {
 #[cfg(test)]
 mod tests {
     use std::{
-        assert_matches::assert_matches,
+        assert_matches,
         time::{Duration, Instant},
     };
 
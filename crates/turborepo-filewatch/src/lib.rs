@@ -475,7 +475,7 @@ async fn wait_for_cookie(
 
 #[cfg(test)]
 mod test {
-    use std::{assert_matches::assert_matches, sync::atomic::AtomicUsize, time::Duration};
+    use std::{assert_matches, sync::atomic::AtomicUsize, time::Duration};
 
     #[cfg(not(target_os = "windows"))]
     use notify::event::RenameMode;
@@ -214,7 +214,7 @@ impl GlobWatcher {
                                 // requester. flushes should not be considered as events.
                                 for flush_id in e
                                     .paths
-                                    .extract_if(|p| p.starts_with(flush_dir.as_path()))
+                                    .extract_if(.., |p| p.starts_with(flush_dir.as_path()))
                                     .filter_map(|p| {
                                         get_flush_id(
                                             p.strip_prefix(flush_dir.as_path())
@@ -374,6 +374,17 @@ impl<T: Watcher> WatchConfig<T> {
             .map_err(|e| ConfigError::WatchError(vec![e]))
     }
 
+    /// Register a directory to be included by the watcher, along with
+    /// everything below it.
+    pub async fn include_dir(&self, path: &Path) -> Result<(), ConfigError> {
+        trace!("watching {:?} recursively", path);
+        self.watcher
+            .lock()
+            .expect("watcher lock poisoned")
+            .watch(path, notify::RecursiveMode::Recursive)
+            .map_err(|e| ConfigError::WatchError(vec![e]))
+    }
+
     /// Register a glob to be excluded by the watcher.
     #[tracing::instrument(skip(self))]
     pub async fn exclude(&self, relative_to: &Path, glob: &str) {
@@ -8,7 +8,7 @@ use turborepo_telemetry::events::command::CommandEventBuilder;
 use turborepo_ui::{color, BOLD, GREY};
 
 use crate::{
-    commands::{bin, generate, ls, prune, run::get_signal, CommandBase},
+    commands::{bin, generate, ls, prune, run::get_signal, validate, CommandBase},
     daemon::DaemonError,
     query,
     rewrite_json::RewriteError,
@@ -60,8 +60,15 @@ pub enum Error {
     #[error(transparent)]
     SerdeJson(#[from] serde_json::Error),
     #[error(transparent)]
+    RunSummary(#[from] crate::run::summary::Error),
+    #[error(transparent)]
+    Cache(#[from] turborepo_cache::CacheError),
+    #[error(transparent)]
     #[diagnostic(transparent)]
     Watch(#[from] watch::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Validate(#[from] validate::Error),
 }
 
 const MAX_CHARS_PER_TASK_LINE: usize = 100;
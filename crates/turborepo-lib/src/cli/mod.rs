@@ -22,11 +22,11 @@ use turborepo_ui::{ColorConfig, GREY};
 use crate::{
     cli::error::print_potential_tasks,
     commands::{
-        bin, config, daemon, generate, link, login, logout, ls, prune, query, run, scan, telemetry,
-        unlink, CommandBase,
+        bin, cache, completion, config, daemon, flush_summaries, generate, link, login, logout, ls,
+        prune, query, run, scan, telemetry, unlink, validate, CommandBase,
     },
     get_version,
-    run::watch::WatchClient,
+    run::{invocation_depth, watch::WatchClient},
     shim::TurboState,
     tracing::TurboSubscriber,
     turbo_json::UIMode,
@@ -121,7 +121,8 @@ impl LogOrder {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, ValueEnum)]
+#[derive(Copy, Clone, Debug, PartialEq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum DryRunMode {
     Text,
     Json,
@@ -136,6 +137,23 @@ impl Display for DryRunMode {
     }
 }
 
+/// The value of `--force`, either whether to force every task or a task name
+/// or glob that limits forcing to the matching tasks
+#[derive(Clone, Debug, PartialEq)]
+pub enum Force {
+    All(bool),
+    Tasks(String),
+}
+
+fn parse_force(s: &str) -> Result<Force, String> {
+    match s {
+        "" => Err("task name or glob must not be empty".to_string()),
+        "true" => Ok(Force::All(true)),
+        "false" => Ok(Force::All(false)),
+        tasks => Ok(Force::Tasks(tasks.to_string())),
+    }
+}
+
 #[derive(
     Copy, Clone, Debug, Default, PartialEq, Serialize, ValueEnum, Deserialize, Eq, Deserializable,
 )]
@@ -144,6 +162,19 @@ pub enum EnvMode {
     Loose,
     #[default]
     Strict,
+    // Behaves like `Loose`, but warns about environment variables that a task
+    // uses without declaring them. Not a doc comment so that clap keeps listing
+    // the possible values inline in `--help`.
+    #[serde(rename = "loose-warn")]
+    #[deserializable(rename = "loose-warn")]
+    LooseWarn,
+}
+
+impl EnvMode {
+    /// Returns true if the entire environment is passed through to tasks
+    pub fn is_loose(&self) -> bool {
+        matches!(self, EnvMode::Loose | EnvMode::LooseWarn)
+    }
 }
 
 impl fmt::Display for EnvMode {
@@ -151,6 +182,7 @@ impl fmt::Display for EnvMode {
         f.write_str(match self {
             EnvMode::Loose => "loose",
             EnvMode::Strict => "strict",
+            EnvMode::LooseWarn => "loose-warn",
         })
     }
 }
@@ -205,15 +237,26 @@ pub struct Args {
     /// Set the auth token for API calls
     #[clap(long, global = true, value_parser)]
     pub token: Option<String>,
+    /// Read the auth token for API calls from a file
+    #[clap(long, global = true, value_parser, conflicts_with = "token")]
+    pub token_file: Option<Utf8PathBuf>,
     /// Specify a file to save a pprof trace
     #[clap(long, global = true, value_parser)]
     pub trace: Option<String>,
     /// verbosity
     #[clap(flatten)]
     pub verbosity: Verbosity,
+    /// Set the format of turbo's own log messages. Use "json" to write each
+    /// message as a JSON object on its own line
+    #[clap(long, global = true, value_enum)]
+    pub log_format: Option<LogFormat>,
     /// Force a check for a new version of turbo
     #[clap(long, global = true, hide = true)]
     pub check_for_update: bool,
+    /// Fail instead of warning when the package manager running turbo
+    /// doesn't match the `packageManager` field of package.json
+    #[clap(long, global = true)]
+    pub strict_package_manager: bool,
     #[clap(long = "__test-run", global = true, hide = true)]
     pub test_run: bool,
     /// Allow for missing `packageManager` in `package.json`.
@@ -269,6 +312,13 @@ impl From<Verbosity> for u8 {
     }
 }
 
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
 #[derive(Subcommand, Copy, Clone, Debug, PartialEq)]
 pub enum DaemonCommand {
     /// Restarts the turbo daemon
@@ -313,6 +363,16 @@ impl fmt::Display for OutputFormat {
     }
 }
 
+#[derive(Subcommand, Copy, Clone, Debug, PartialEq)]
+pub enum CacheCommand {
+    /// Check local cache artifacts for corruption
+    Verify {
+        /// Remove corrupt artifacts from the cache
+        #[clap(long)]
+        prune: bool,
+    },
+}
+
 #[derive(Subcommand, Copy, Clone, Debug, PartialEq)]
 pub enum TelemetryCommand {
     /// Enables anonymous telemetry
@@ -429,6 +489,7 @@ impl Args {
         track_usage!(tel, &self.heap, Option::is_some);
         track_usage!(tel, &self.team, Option::is_some);
         track_usage!(tel, &self.token, Option::is_some);
+        track_usage!(tel, &self.token_file, Option::is_some);
         track_usage!(tel, &self.trace, Option::is_some);
         track_usage!(tel, &self.api, Option::is_some);
 
@@ -481,10 +542,24 @@ impl Args {
 #[derive(Subcommand, Clone, Debug, PartialEq)]
 pub enum Command {
     /// Get the path to the Turbo binary
-    Bin,
+    Bin {
+        /// Print the path to the turbo installed in the repository instead of
+        /// the one currently running
+        #[clap(long)]
+        local: bool,
+    },
+    /// Inspect and maintain the local cache
+    Cache {
+        #[clap(subcommand)]
+        command: CacheCommand,
+    },
     /// Generate the autocompletion script for the specified shell
     Completion {
         shell: Shell,
+        /// Also complete the names of the tasks in the current repository's
+        /// turbo.json
+        #[clap(long)]
+        tasks: bool,
     },
     /// Runs the Turborepo background daemon
     Daemon {
@@ -494,6 +569,9 @@ pub enum Command {
         #[clap(subcommand)]
         command: Option<DaemonCommand>,
     },
+    /// Send run summaries that previously failed to upload
+    #[clap(hide = true)]
+    FlushSummaries,
     /// Generate a new app / package
     #[clap(aliases = ["g", "gen"])]
     Generate {
@@ -554,6 +632,14 @@ pub enum Command {
         /// Specify what should be linked (default "remote cache")
         #[clap(long, value_enum, default_value_t = LinkTarget::RemoteCache)]
         target: LinkTarget,
+
+        /// The slug of the team to link to. Skips the team selection prompt
+        #[clap(long)]
+        scope: Option<String>,
+
+        /// Answer yes to all confirmation prompts. Requires --scope
+        #[clap(long)]
+        yes: bool,
     },
     /// Login to your Vercel account
     Login {
@@ -617,6 +703,8 @@ pub enum Command {
         #[clap(long, value_enum, default_value_t = LinkTarget::RemoteCache)]
         target: LinkTarget,
     },
+    /// Check turbo.json for mistakes without running any tasks
+    Validate,
 }
 
 #[derive(Parser, Clone, Debug, Default, Serialize, PartialEq)]
@@ -708,9 +796,11 @@ fn path_non_empty(s: &str) -> Result<Utf8PathBuf, String> {
 ArgGroup::new("scope-filter-group").multiple(true).required(false),
 ])]
 pub struct ExecutionArgs {
-    /// Override the filesystem cache directory.
+    /// Override the filesystem cache directory. Pass it again to add
+    /// read-only cache directories that are checked before the remote cache,
+    /// these can't be set with TURBO_CACHE_DIR.
     #[clap(long, value_parser = path_non_empty)]
-    pub cache_dir: Option<Utf8PathBuf>,
+    pub cache_dir: Vec<Utf8PathBuf>,
     /// Limit the concurrency of task execution. Use 1 for serial (i.e.
     /// one-at-a-time) execution.
     #[clap(long)]
@@ -719,12 +809,16 @@ pub struct ExecutionArgs {
     /// exit code. The default behavior is to bail
     #[clap(long = "continue")]
     pub continue_execution: bool,
+    /// Stop running tasks once this many have failed. Implies --continue
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_failures: Option<u32>,
     /// Run turbo in single-package mode
     #[clap(long)]
     pub single_package: bool,
-    /// Ignore the existing cache (to force execution)
-    #[clap(long, default_missing_value = "true")]
-    pub force: Option<Option<bool>>,
+    /// Ignore the existing cache (to force execution). Pass a task name or
+    /// glob to only force matching tasks
+    #[clap(long, default_missing_value = "true", value_parser = parse_force)]
+    pub force: Option<Option<Force>>,
     /// Specify whether or not to do framework inference for tasks
     #[clap(long, value_name = "BOOL", action = ArgAction::Set, default_value = "true", default_missing_value = "true", num_args = 0..=1)]
     pub framework_inference: bool,
@@ -735,8 +829,14 @@ pub struct ExecutionArgs {
     /// Environment variable mode.
     /// Use "loose" to pass the entire existing environment.
     /// Use "strict" to use an allowlist specified in turbo.json.
+    /// Use "loose-warn" to behave like "loose" while warning about
+    /// environment variables that aren't in the allowlist.
     #[clap(long = "env-mode", num_args = 0..=1, default_missing_value = "strict")]
     pub env_mode: Option<EnvMode>,
+    /// Apply the task overrides of the given entry in the `environments`
+    /// section of turbo.json.
+    #[clap(long)]
+    pub environment: Option<String>,
     /// Use the given selector to specify package(s) to act as
     /// entry points. The syntax mirrors pnpm's syntax, and
     /// additional documentation and examples can be found in
@@ -749,6 +849,17 @@ pub struct ExecutionArgs {
     #[clap(long, group = "scope-filter-group", conflicts_with = "filter")]
     pub affected: bool,
 
+    /// Run only tasks that are affected by the files listed in the given
+    /// file, one path per line relative to the repository root. Use "-" to
+    /// read the list from stdin. Changes are not computed with git.
+    #[clap(
+        long,
+        value_name = "PATH",
+        group = "scope-filter-group",
+        conflicts_with_all = ["filter", "affected"]
+    )]
+    pub changed_files_from: Option<String>,
+
     /// Set type of process output logging. Use "full" to show
     /// all output. Use "hash-only" to show only turbo-computed
     /// task hashes. Use "new-only" to show only new output with
@@ -780,6 +891,10 @@ pub struct ExecutionArgs {
     /// auto)
     #[clap(long, value_enum, default_value_t = LogPrefix::Auto)]
     pub log_prefix: LogPrefix,
+    /// Remove color codes from task output written to log files, and from
+    /// task output written to stdout when it isn't a terminal
+    #[clap(long)]
+    pub no_color_logs: bool,
     // NOTE: The following two are hidden because clap displays them in the help text incorrectly:
     // > Usage: turbo [OPTIONS] [TASKS]... [-- <FORWARDED_ARGS>...] [COMMAND]
     #[clap(hide = true)]
@@ -794,6 +909,24 @@ impl ExecutionArgs {
         Some(remote_only.unwrap_or(true))
     }
 
+    /// Whether `--force` applies to every task. Forcing only some tasks
+    /// doesn't force the rest, even if `TURBO_FORCE` is set.
+    pub fn force(&self) -> Option<bool> {
+        match self.force.as_ref()? {
+            None => Some(true),
+            Some(Force::All(force)) => Some(*force),
+            Some(Force::Tasks(_)) => Some(false),
+        }
+    }
+
+    /// The task name or glob that `--force` is limited to
+    pub fn force_tasks(&self) -> Option<&str> {
+        match &self.force {
+            Some(Some(Force::Tasks(tasks))) => Some(tasks),
+            _ => None,
+        }
+    }
+
     fn track(&self, telemetry: &CommandEventBuilder) {
         // default to false
         track_usage!(telemetry, self.framework_inference, |val: bool| !val);
@@ -801,10 +934,13 @@ impl ExecutionArgs {
         track_usage!(telemetry, self.continue_execution, |val| val);
         track_usage!(telemetry, self.single_package, |val| val);
         track_usage!(telemetry, self.only, |val| val);
+        track_usage!(telemetry, self.no_color_logs, |val| val);
         track_usage!(telemetry, self.remote_only().unwrap_or_default(), |val| val);
-        track_usage!(telemetry, &self.cache_dir, Option::is_some);
+        track_usage!(telemetry, &self.cache_dir, |dirs: &Vec<_>| !dirs.is_empty());
         track_usage!(telemetry, &self.force, Option::is_some);
         track_usage!(telemetry, &self.pkg_inference_root, Option::is_some);
+        track_usage!(telemetry, &self.changed_files_from, Option::is_some);
+        track_usage!(telemetry, &self.max_failures, Option::is_some);
 
         if let Some(concurrency) = &self.concurrency {
             telemetry.track_arg_value("concurrency", concurrency, EventType::NonSensitive);
@@ -822,6 +958,8 @@ impl ExecutionArgs {
             telemetry.track_arg_value("env-mode", env_mode, EventType::NonSensitive);
         }
 
+        track_usage!(telemetry, &self.environment, Option::is_some);
+
         if let Some(output_logs) = &self.output_logs {
             telemetry.track_arg_value("output-logs", output_logs, EventType::NonSensitive);
         }
@@ -849,6 +987,10 @@ pub struct RunArgs {
     /// Set the number of concurrent cache operations (default 10)
     #[clap(long, default_value_t = DEFAULT_NUM_WORKERS)]
     pub cache_workers: u32,
+    /// Set the number of threads used to hash files (defaults to the number
+    /// of CPUs)
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub hash_workers: Option<u32>,
     #[clap(alias = "dry", long = "dry-run", num_args = 0..=1, default_missing_value = "text")]
     pub dry_run: Option<DryRunMode>,
     /// Generate a graph of the task execution and output to a file when a
@@ -891,20 +1033,46 @@ pub struct RunArgs {
     /// Generate a summary of the turbo run
     #[clap(long, default_missing_value = "true")]
     pub summarize: Option<Option<bool>>,
+    /// Print the global hash and the hash of each task as JSON without
+    /// running tasks or using the cache
+    #[clap(long, conflicts_with_all = ["dry_run", "graph"])]
+    pub summarize_only: bool,
+    /// Print the options resolved from the command line, configuration and
+    /// environment as JSON without running tasks
+    #[clap(long, conflicts_with_all = ["dry_run", "graph", "summarize_only"])]
+    pub print_opts: bool,
+    /// Write the duration and cache status of each task to a CSV file
+    #[clap(long, value_parser = path_non_empty)]
+    pub csv_output: Option<Utf8PathBuf>,
 
     // Pass a string to enable posting Run Summaries to Vercel
     #[clap(long, hide = true)]
     pub experimental_space_id: Option<String>,
 
+    // Replace package and task names with hashed tokens in Run Summaries
+    // posted to Vercel
+    #[clap(long, hide = true)]
+    pub anonymize_spaces: bool,
+
     /// Execute all tasks in parallel.
     #[clap(long)]
     pub parallel: bool,
+
+    /// Restart persistent tasks when their inputs change.
+    #[clap(long)]
+    pub restart_on_change: bool,
+
+    /// Fail instead of continuing when run from inside a task of another
+    /// turbo run.
+    #[clap(long)]
+    pub no_nested: bool,
 }
 
 impl Default for RunArgs {
     fn default() -> Self {
         Self {
             cache_workers: DEFAULT_NUM_WORKERS,
+            hash_workers: None,
             dry_run: None,
             graph: None,
             no_cache: false,
@@ -914,8 +1082,14 @@ impl Default for RunArgs {
             anon_profile: None,
             remote_cache_read_only: None,
             summarize: None,
+            summarize_only: false,
+            print_opts: false,
+            csv_output: None,
             experimental_space_id: None,
+            anonymize_spaces: false,
             parallel: false,
+            restart_on_change: false,
+            no_nested: false,
         }
     }
 }
@@ -958,6 +1132,11 @@ impl RunArgs {
         track_usage!(telemetry, self.daemon, |val| val);
         track_usage!(telemetry, self.no_daemon, |val| val);
         track_usage!(telemetry, self.parallel, |val| val);
+        track_usage!(telemetry, self.restart_on_change, |val| val);
+        track_usage!(telemetry, self.no_nested, |val| val);
+        track_usage!(telemetry, self.summarize_only, |val| val);
+        track_usage!(telemetry, self.print_opts, |val| val);
+        track_usage!(telemetry, self.anonymize_spaces, |val| val);
         track_usage!(
             telemetry,
             self.remote_cache_read_only().unwrap_or_default(),
@@ -968,6 +1147,7 @@ impl RunArgs {
         track_usage!(telemetry, &self.profile, Option::is_some);
         track_usage!(telemetry, &self.anon_profile, Option::is_some);
         track_usage!(telemetry, &self.summarize, Option::is_some);
+        track_usage!(telemetry, &self.csv_output, Option::is_some);
         track_usage!(telemetry, &self.experimental_space_id, Option::is_some);
 
         // track values
@@ -979,6 +1159,10 @@ impl RunArgs {
             telemetry.track_arg_value("cache-workers", self.cache_workers, EventType::NonSensitive);
         }
 
+        if let Some(hash_workers) = self.hash_workers {
+            telemetry.track_arg_value("hash-workers", hash_workers, EventType::NonSensitive);
+        }
+
         if let Some(graph) = &self.graph {
             // track the extension used only
             let extension = Utf8Path::new(graph).extension().unwrap_or("stdout");
@@ -1031,7 +1215,7 @@ impl Display for LogPrefix {
 /// * `color_config`: The color configuration to use for the run, i.e. whether
 ///   we should colorize output.
 ///
-/// returns: Result<Payload, Error>
+/// returns: Result<i32, Error>, the exit code of the command
 #[tokio::main]
 pub async fn run(
     repo_state: Option<RepoState>,
@@ -1160,14 +1344,23 @@ pub async fn run(
     cli_args.track(&root_telemetry);
 
     let cli_result = match cli_args.command.as_ref().unwrap() {
-        Command::Bin => {
+        Command::Bin { local } => {
             CommandEventBuilder::new("bin")
                 .with_parent(&root_telemetry)
                 .track_call();
-            bin::run()?;
+            bin::run(&repo_root, *local)?;
 
             Ok(0)
         }
+        Command::Cache { command } => {
+            CommandEventBuilder::new("cache")
+                .with_parent(&root_telemetry)
+                .track_call();
+            let command = *command;
+            let base = CommandBase::new(cli_args.clone(), repo_root, version, color_config);
+
+            cache::run(&base, command)
+        }
         #[allow(unused_variables)]
         Command::Daemon { command, idle_time } => {
             CommandEventBuilder::new("daemon")
@@ -1182,6 +1375,15 @@ pub async fn run(
 
             Ok(0)
         }
+        Command::FlushSummaries => {
+            CommandEventBuilder::new("flush-summaries")
+                .with_parent(&root_telemetry)
+                .track_call();
+            let base = CommandBase::new(cli_args.clone(), repo_root, version, color_config);
+            flush_summaries::run(&base).await?;
+
+            Ok(0)
+        }
         Command::Generate {
             tag,
             generator_name,
@@ -1224,6 +1426,14 @@ pub async fn run(
             config::run(base).await?;
             Ok(0)
         }
+        Command::Validate => {
+            CommandEventBuilder::new("validate")
+                .with_parent(&root_telemetry)
+                .track_call();
+            let base = CommandBase::new(cli_args.clone(), repo_root, version, color_config);
+            validate::run(&base).await?;
+            Ok(0)
+        }
         Command::Ls {
             affected,
             filter,
@@ -1247,6 +1457,8 @@ pub async fn run(
         Command::Link {
             no_gitignore,
             target,
+            scope,
+            yes,
         } => {
             CommandEventBuilder::new("link")
                 .with_parent(&root_telemetry)
@@ -1258,9 +1470,13 @@ pub async fn run(
 
             let modify_gitignore = !*no_gitignore;
             let to = *target;
+            let scope = scope.clone();
+            let yes = *yes;
             let mut base = CommandBase::new(cli_args, repo_root, version, color_config);
 
-            if let Err(err) = link::link(&mut base, modify_gitignore, to).await {
+            if let Err(err) =
+                link::link(&mut base, scope.as_deref(), yes, modify_gitignore, to).await
+            {
                 error!("error: {}", err.to_string())
             }
 
@@ -1336,6 +1552,7 @@ pub async fn run(
             }
 
             run_args.track(&event);
+            invocation_depth::check(invocation_depth::current(), run_args.no_nested)?;
             let exit_code = run::run(base, event).await.inspect(|code| {
                 if *code != 0 {
                     error!("run failed: command  exited ({code})");
@@ -1384,11 +1601,29 @@ pub async fn run(
             prune::prune(&base, &scope, docker, &output_dir, event_child).await?;
             Ok(0)
         }
-        Command::Completion { shell } => {
+        Command::Completion { shell, tasks } => {
             CommandEventBuilder::new("completion")
                 .with_parent(&root_telemetry)
                 .track_call();
-            generate(*shell, &mut Args::command(), "turbo", &mut io::stdout());
+            let mut command = Args::command();
+            // Generated up front since the generators panic if the reader stops
+            // early, e.g. `turbo completion bash --tasks | grep -q build`
+            let mut completions = Vec::new();
+            if *tasks {
+                let base = CommandBase::new(cli_args.clone(), repo_root, version, color_config);
+                completion::generate_with_tasks(
+                    *shell,
+                    command,
+                    &completion::task_names(&base)?,
+                    &mut completions,
+                );
+            } else {
+                generate(*shell, &mut command, "turbo", &mut completions);
+            }
+            match io::Write::write_all(&mut io::stdout(), &completions) {
+                Err(e) if e.kind() == io::ErrorKind::BrokenPipe => (),
+                result => result.expect("failed to write completion file"),
+            }
             Ok(0)
         }
     };
@@ -1404,7 +1639,7 @@ pub async fn run(
 
 #[cfg(test)]
 mod test {
-    use std::assert_matches::assert_matches;
+    use std::assert_matches;
 
     use camino::Utf8PathBuf;
     use clap::Parser;
@@ -1468,7 +1703,9 @@ mod test {
         }
     }
 
-    use crate::cli::{Args, Command, DryRunMode, EnvMode, LogOrder, LogPrefix, OutputLogsMode};
+    use crate::cli::{
+        Args, Command, DryRunMode, EnvMode, Force, LogOrder, LogPrefix, OutputLogsMode,
+    };
 
     #[test_case::test_case(
         &["turbo", "run", "build"],
@@ -1575,6 +1812,21 @@ mod test {
 		} ;
         "env_mode: specified loose"
 	)]
+    #[test_case::test_case(
+		&["turbo", "run", "build", "--env-mode", "loose-warn"],
+        Args {
+            command: Some(Command::Run {
+                execution_args: Box::new(ExecutionArgs {
+                    tasks: vec!["build".to_string()],
+                    env_mode: Some(EnvMode::LooseWarn),
+                    ..get_default_execution_args()
+                }),
+                run_args: Box::new(get_default_run_args())
+            }),
+            ..Args::default()
+		} ;
+        "env_mode: specified loose-warn"
+	)]
     #[test_case::test_case(
 		&["turbo", "run", "build", "--env-mode", "strict"],
         Args {
@@ -1610,7 +1862,7 @@ mod test {
             command: Some(Command::Run {
                 execution_args: Box::new(ExecutionArgs {
                     tasks: vec!["build".to_string()],
-                    cache_dir: Some(Utf8PathBuf::from("foobar")),
+                    cache_dir: vec![Utf8PathBuf::from("foobar")],
                     ..get_default_execution_args()
                 }),
                 run_args: Box::new(get_default_run_args())
@@ -1619,6 +1871,21 @@ mod test {
         } ;
         "cache dir"
 	)]
+    #[test_case::test_case(
+		&["turbo", "run", "build", "--cache-dir", "foobar", "--cache-dir", "/shared/cache"],
+        Args {
+            command: Some(Command::Run {
+                execution_args: Box::new(ExecutionArgs {
+                    tasks: vec!["build".to_string()],
+                    cache_dir: vec![Utf8PathBuf::from("foobar"), Utf8PathBuf::from("/shared/cache")],
+                    ..get_default_execution_args()
+                }),
+                run_args: Box::new(get_default_run_args())
+            }),
+            ..Args::default()
+        } ;
+        "cache dir with read-only dirs"
+	)]
     #[test_case::test_case(
 		&["turbo", "run", "build", "--cache-workers", "100"],
         Args {
@@ -1766,7 +2033,7 @@ mod test {
             command: Some(Command::Run {
                 execution_args: Box::new(ExecutionArgs {
                     tasks: vec!["build".to_string()],
-                    force: Some(Some(true)),
+                    force: Some(Some(Force::All(true))),
                     ..get_default_execution_args()
                 }),
                 run_args: Box::new(get_default_run_args())
@@ -1775,6 +2042,36 @@ mod test {
         } ;
         "force"
 	)]
+    #[test_case::test_case(
+		&["turbo", "run", "build", "--force=false"],
+        Args {
+            command: Some(Command::Run {
+                execution_args: Box::new(ExecutionArgs {
+                    tasks: vec!["build".to_string()],
+                    force: Some(Some(Force::All(false))),
+                    ..get_default_execution_args()
+                }),
+                run_args: Box::new(get_default_run_args())
+            }),
+            ..Args::default()
+        } ;
+        "force false"
+	)]
+    #[test_case::test_case(
+		&["turbo", "run", "build", "lint", "--force=build"],
+        Args {
+            command: Some(Command::Run {
+                execution_args: Box::new(ExecutionArgs {
+                    tasks: vec!["build".to_string(), "lint".to_string()],
+                    force: Some(Some(Force::Tasks("build".to_string()))),
+                    ..get_default_execution_args()
+                }),
+                run_args: Box::new(get_default_run_args())
+            }),
+            ..Args::default()
+        } ;
+        "force task"
+	)]
     #[test_case::test_case(
 		&["turbo", "run", "build", "--global-deps", ".env"],
         Args {
@@ -2161,7 +2458,7 @@ mod test {
         Args {
             command: Some(Command::Watch(Box::new(ExecutionArgs {
                 tasks: vec!["build".to_string()],
-                cache_dir: Some(Utf8PathBuf::from("foobar")),
+                cache_dir: vec![Utf8PathBuf::from("foobar")],
                 ..get_default_execution_args()
             }))),
             ..Args::default()
@@ -2215,7 +2512,15 @@ mod test {
         assert_eq!(
             Args::try_parse_from(["turbo", "bin"]).unwrap(),
             Args {
-                command: Some(Command::Bin {}),
+                command: Some(Command::Bin { local: false }),
+                ..Args::default()
+            }
+        );
+
+        assert_eq!(
+            Args::try_parse_from(["turbo", "bin", "--local"]).unwrap(),
+            Args {
+                command: Some(Command::Bin { local: true }),
                 ..Args::default()
             }
         );
@@ -2225,7 +2530,7 @@ mod test {
             command_args: vec![],
             global_args: vec![vec!["--cwd", "../examples/with-yarn"]],
             expected_output: Args {
-                command: Some(Command::Bin {}),
+                command: Some(Command::Bin { local: false }),
                 cwd: Some(Utf8PathBuf::from("../examples/with-yarn")),
                 ..Args::default()
             },
@@ -2658,4 +2963,29 @@ mod test {
         assert!(Args::try_parse_from(["turbo", "build", "--filter", "foo", "--affected"]).is_err(),);
         assert!(Args::try_parse_from(["turbo", "ls", "--filter", "foo", "--affected"]).is_err(),);
     }
+
+    #[test]
+    fn test_summarize_only() {
+        let args = Args::try_parse_from(["turbo", "run", "build", "--summarize-only"]).unwrap();
+        let Some(Command::Run { run_args, .. }) = args.command else {
+            panic!("expected run command");
+        };
+        assert!(run_args.summarize_only);
+        assert!(
+            Args::try_parse_from(["turbo", "run", "build", "--summarize-only", "--dry"]).is_err()
+        );
+        assert!(
+            Args::try_parse_from(["turbo", "run", "build", "--summarize-only", "--graph"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_print_opts() {
+        let args = Args::try_parse_from(["turbo", "run", "build", "--print-opts"]).unwrap();
+        let Some(Command::Run { run_args, .. }) = args.command else {
+            panic!("expected run command");
+        };
+        assert!(run_args.print_opts);
+        assert!(Args::try_parse_from(["turbo", "run", "build", "--print-opts", "--dry"]).is_err());
+    }
 }
@@ -1,18 +1,68 @@
-use std::{env::current_exe, io};
+use std::{env::current_exe, io, path::PathBuf};
 
 use thiserror::Error;
+use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
+
+use crate::shim::LocalTurboState;
 
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("could not get path to turbo binary: {0}")]
     NoCurrentExe(#[from] io::Error),
+    #[error("could not find a local turbo installation in {0}")]
+    NoLocalTurbo(AbsoluteSystemPathBuf),
 }
 
-pub fn run() -> Result<(), Error> {
-    let path = current_exe()?;
+pub fn run(repo_root: &AbsoluteSystemPath, local: bool) -> Result<(), Error> {
+    let path = if local {
+        local_turbo_path(repo_root)?
+    } else {
+        current_exe()?
+    };
     // NOTE: The Go version uses `base.UI.Output`, we should use the Rust equivalent
     // eventually.
     println!("{}", path.to_string_lossy());
 
     Ok(())
 }
+
+/// Resolves the turbo binary that the shim would delegate to when invoked
+/// from `repo_root`.
+fn local_turbo_path(repo_root: &AbsoluteSystemPath) -> Result<PathBuf, Error> {
+    LocalTurboState::infer(repo_root)
+        .map(|state| state.binary().to_path_buf())
+        .ok_or_else(|| Error::NoLocalTurbo(repo_root.to_owned()))
+}
+
+#[cfg(test)]
+mod test {
+    use tempfile::TempDir;
+    use turbopath::AbsoluteSystemPathBuf;
+
+    use super::{local_turbo_path, Error};
+    use crate::shim::TurboState;
+
+    #[test]
+    fn test_local_turbo_path() {
+        let tmp = TempDir::new().unwrap();
+        let repo_root = AbsoluteSystemPathBuf::try_from(tmp.path()).unwrap();
+
+        assert!(matches!(
+            local_turbo_path(&repo_root),
+            Err(Error::NoLocalTurbo(path)) if path == repo_root
+        ));
+
+        let platform_package =
+            repo_root.join_components(&["node_modules", TurboState::platform_package_name()]);
+        let binary = platform_package.join_components(&["bin", TurboState::binary_name()]);
+        binary.ensure_dir().unwrap();
+        binary.create_with_contents("").unwrap();
+        platform_package
+            .join_component("package.json")
+            .create_with_contents(r#"{ "version": "2.0.0" }"#)
+            .unwrap();
+
+        let expected = dunce::canonicalize(binary.as_path()).unwrap();
+        assert_eq!(local_turbo_path(&repo_root).unwrap(), expected);
+    }
+}
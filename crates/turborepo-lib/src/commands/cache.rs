@@ -0,0 +1,69 @@
+use turborepo_cache::fs::{ArtifactStatus, FSCache};
+use turborepo_ui::{cprintln, BOLD_GREEN, BOLD_RED, GREY};
+
+use crate::{cli, cli::CacheCommand, commands::CommandBase};
+
+pub fn run(base: &CommandBase, command: CacheCommand) -> Result<i32, cli::Error> {
+    match command {
+        CacheCommand::Verify { prune } => verify(base, prune),
+    }
+}
+
+/// Checks the local cache for corrupt artifacts, optionally removing them.
+/// Returns a non-zero exit code if corrupt artifacts were left in the cache.
+fn verify(base: &CommandBase, prune: bool) -> Result<i32, cli::Error> {
+    let config = base.config()?;
+    let cache = FSCache::new(config.cache_dir(), &base.repo_root, None)?;
+    let color_config = base.color_config;
+
+    let verifications = cache.verify()?;
+    let mut corrupt = 0;
+    let mut unverified = 0;
+    for verification in &verifications {
+        match &verification.status {
+            ArtifactStatus::Valid => {}
+            ArtifactStatus::Unverified => unverified += 1,
+            ArtifactStatus::Corrupt(reason) => {
+                corrupt += 1;
+                cprintln!(
+                    color_config,
+                    BOLD_RED,
+                    "corrupt: {} ({})",
+                    verification.path,
+                    reason
+                );
+                if prune {
+                    cache.remove(&verification.hash)?;
+                }
+            }
+        }
+    }
+
+    cprintln!(
+        color_config,
+        GREY,
+        "Checked {} artifacts, {} without a checksum",
+        verifications.len(),
+        unverified
+    );
+    if corrupt == 0 {
+        cprintln!(color_config, BOLD_GREEN, "No corrupt artifacts found");
+        Ok(0)
+    } else if prune {
+        cprintln!(
+            color_config,
+            BOLD_GREEN,
+            "Removed {} corrupt artifacts",
+            corrupt
+        );
+        Ok(0)
+    } else {
+        cprintln!(
+            color_config,
+            BOLD_RED,
+            "Found {} corrupt artifacts, run with --prune to remove them",
+            corrupt
+        );
+        Ok(1)
+    }
+}
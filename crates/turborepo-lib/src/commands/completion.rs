@@ -0,0 +1,110 @@
+//! Adds the tasks of the current repository to the generated shell
+//! completions.
+
+use std::io::Write;
+
+use clap::builder::PossibleValuesParser;
+use clap_complete::{generate, Shell};
+use itertools::Itertools;
+
+use crate::{commands::CommandBase, config, turbo_json::TurboJson};
+
+// Subcommands that take task names, `turbo <task>` takes them as well
+const TASK_SUBCOMMANDS: &[&str] = &["run", "watch"];
+// The id of the positional argument that holds the task names
+const TASKS_ARG: &str = "tasks";
+// The id of the positional argument that holds the arguments after `--`
+const PASS_THROUGH_ARGS_ARG: &str = "pass_through_args";
+
+/// The names of the tasks in the root turbo.json
+pub fn task_names(base: &CommandBase) -> Result<Vec<String>, config::Error> {
+    let root_turbo_json_path = base.config()?.root_turbo_json_path(&base.repo_root);
+    let turbo_json = TurboJson::read(&base.repo_root, &root_turbo_json_path)?;
+
+    Ok(turbo_json
+        .tasks
+        .keys()
+        .map(|task_name| task_name.task().to_string())
+        .sorted()
+        .dedup()
+        .collect())
+}
+
+/// Writes the completions for `shell` to `buf`, offering `tasks` wherever
+/// turbo takes task names
+pub fn generate_with_tasks(
+    shell: Shell,
+    command: clap::Command,
+    tasks: &[String],
+    buf: &mut dyn Write,
+) {
+    let mut command = with_task_completions(command, tasks);
+    generate(shell, &mut command, "turbo", buf);
+    // The fish generator only completes named options, so the task names
+    // are added with the subcommand helpers it generated
+    if shell == Shell::Fish {
+        let tasks = tasks.iter().map(|task| fish_escape(task)).join(" ");
+        writeln!(
+            buf,
+            "complete -c turbo -n \"__fish_turbo_needs_command\" -f -a '{tasks}'"
+        )
+        .expect("failed to write completion file");
+        for subcommand in TASK_SUBCOMMANDS {
+            writeln!(
+                buf,
+                "complete -c turbo -n \"__fish_turbo_using_subcommand {subcommand}\" -f -a \
+                 '{tasks}'"
+            )
+            .expect("failed to write completion file");
+        }
+    }
+}
+
+// Escapes a word for use inside a single quoted fish string
+fn fish_escape(word: &str) -> String {
+    word.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// Offers `tasks` as completions wherever turbo takes task names
+fn with_task_completions(command: clap::Command, tasks: &[String]) -> clap::Command {
+    let add_tasks = |command: clap::Command| {
+        command
+            .mut_arg(TASKS_ARG, |arg| {
+                // Task names are hidden from the help text, but completion
+                // generators skip hidden arguments
+                arg.hide(false)
+                    .value_parser(PossibleValuesParser::new(tasks.iter().cloned()))
+            })
+            // `mut_arg` moves the argument to the end, the arguments after `--`
+            // have to stay the last positional argument
+            .mut_arg(PASS_THROUGH_ARGS_ARG, |arg| arg)
+    };
+
+    TASK_SUBCOMMANDS
+        .iter()
+        .fold(add_tasks(command), |command, subcommand| {
+            command.mut_subcommand(subcommand, add_tasks)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use clap::CommandFactory;
+    use clap_complete::Shell;
+    use test_case::test_case;
+
+    use super::generate_with_tasks;
+    use crate::cli::Args;
+
+    #[test_case(Shell::Bash ; "bash")]
+    #[test_case(Shell::Zsh ; "zsh")]
+    #[test_case(Shell::Fish ; "fish")]
+    fn test_completions_include_tasks(shell: Shell) {
+        let tasks = vec!["build".to_string(), "check-types".to_string()];
+        let mut script = Vec::new();
+        generate_with_tasks(shell, Args::command(), &tasks, &mut script);
+        let script = String::from_utf8(script).unwrap();
+
+        assert!(script.contains("check-types"));
+    }
+}
@@ -38,7 +38,7 @@ pub async fn daemon_client(command: &DaemonCommand, base: &CommandBase) -> Resul
     match command {
         DaemonCommand::Restart => {
             let result: Result<_, DaemonError> = try {
-                let client = connector.clone().connect().await?;
+                let client = connector.clone().connect().await.map_err(DaemonError::from)?;
                 client.restart().await?
             };
 
@@ -0,0 +1,38 @@
+use turborepo_ui::GREY;
+
+use crate::{cli, commands::CommandBase, run::summary::SpacesQueue};
+
+/// Sends any run summaries that failed to upload to spaces and were queued
+/// in `.turbo/spaces-queue`.
+pub async fn run(base: &CommandBase) -> Result<(), cli::Error> {
+    let queue = SpacesQueue::new(&base.repo_root);
+    let queued = queue.entries()?.len();
+    if queued == 0 {
+        println!(
+            "{}",
+            base.color_config
+                .apply(GREY.apply_to("> No queued run summaries"))
+        );
+        return Ok(());
+    }
+
+    let Some(api_auth) = base.api_auth()? else {
+        println!(
+            "{}",
+            base.color_config.apply(GREY.apply_to(format!(
+                "> {queued} queued run summaries, log in to send them"
+            )))
+        );
+        return Ok(());
+    };
+    let api_client = base.api_client()?;
+
+    let flushed = queue.drain(&api_client, &api_auth).await?;
+    println!(
+        "{}",
+        base.color_config
+            .apply(GREY.apply_to(format!("> Sent {flushed} queued run summaries")))
+    );
+
+    Ok(())
+}
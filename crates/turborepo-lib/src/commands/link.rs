@@ -66,6 +66,8 @@ pub enum Error {
     OpenBrowser(String, #[source] io::Error),
     #[error("please re-run `link` after enabling caching")]
     EnableCaching,
+    #[error("`--yes` requires `--scope` to choose a team without prompting")]
+    ScopeRequired,
     #[error(
         "Could not persist selected space ({space_id}) to `experimentalSpaces.id` in turbo.json"
     )]
@@ -104,6 +106,7 @@ pub(crate) const SPACES_URL: &str = "https://vercel.com/docs/workflow-collaborat
 /// * `team_id`: ID for team selected
 /// * `token`: API token
 /// * `selected_team`: The team selected
+/// * `yes`: Skip the prompt, failing if caching is disabled
 ///
 /// returns: Result<(), Error>
 pub(crate) async fn verify_caching_enabled<'a>(
@@ -111,6 +114,7 @@ pub(crate) async fn verify_caching_enabled<'a>(
     team_id: &str,
     token: &str,
     selected_team: Option<SelectedTeam<'a>>,
+    yes: bool,
 ) -> Result<(), Error> {
     let team_slug = selected_team.as_ref().and_then(|team| match team {
         SelectedTeam::Team(team) => Some(team.slug.as_str()),
@@ -123,6 +127,9 @@ pub(crate) async fn verify_caching_enabled<'a>(
         .map_err(Error::CachingStatusNotFound)?;
 
     match response.status {
+        // Enabling caching needs the browser, so there's nothing we can do
+        // without prompting
+        CachingStatus::Disabled if yes => Err(Error::EnableCaching),
         CachingStatus::Disabled => {
             let should_enable = should_enable_caching()?;
             if should_enable {
@@ -163,9 +170,15 @@ pub(crate) async fn verify_caching_enabled<'a>(
 
 pub async fn link(
     base: &mut CommandBase,
+    scope: Option<&str>,
+    yes: bool,
     modify_gitignore: bool,
     target: LinkTarget,
 ) -> Result<(), Error> {
+    if yes && scope.is_none() {
+        return Err(Error::ScopeRequired);
+    }
+
     let homedir_path = home_dir().ok_or_else(|| Error::HomeDirectoryNotFound)?;
     let homedir = homedir_path.to_string_lossy();
     let repo_root_with_tilde = base.repo_root.to_string().replacen(&*homedir, "~", 1);
@@ -183,7 +196,7 @@ pub async fn link(
                 REMOTE_CACHING_URL
             );
 
-            if !should_link_remote_cache(base, &repo_root_with_tilde)? {
+            if !yes && !should_link_remote_cache(base, &repo_root_with_tilde)? {
                 return Err(Error::NotLinking);
             }
 
@@ -203,15 +216,24 @@ pub async fn link(
                 .await
                 .map_err(Error::TeamsRequest)?;
 
-            let selected_team = select_team(base, &teams_response.teams)?;
+            let selected_team = match scope {
+                Some(scope) => find_team(&teams_response.teams, scope)?,
+                None => select_team(base, &teams_response.teams)?,
+            };
 
             let team_id = match selected_team {
                 SelectedTeam::User => user_response.user.id.as_str(),
                 SelectedTeam::Team(team) => team.id.as_str(),
             };
 
-            verify_caching_enabled(&api_client, team_id, token, Some(selected_team.clone()))
-                .await?;
+            verify_caching_enabled(
+                &api_client,
+                team_id,
+                token,
+                Some(selected_team.clone()),
+                yes,
+            )
+            .await?;
 
             let local_config_path = base.local_config_path();
             let before = local_config_path
@@ -280,7 +302,7 @@ pub async fn link(
                 SPACES_URL
             );
 
-            if !should_link_spaces(base, &repo_root_with_tilde)? {
+            if !yes && !should_link_spaces(base, &repo_root_with_tilde)? {
                 return Err(Error::NotLinking);
             }
 
@@ -294,7 +316,10 @@ pub async fn link(
                 .await
                 .map_err(Error::TeamsRequest)?;
 
-            let selected_team = select_team(base, &teams_response.teams)?;
+            let selected_team = match scope {
+                Some(scope) => find_team(&teams_response.teams, scope)?,
+                None => select_team(base, &teams_response.teams)?,
+            };
 
             let team_id = match selected_team {
                 SelectedTeam::User => user_response.user.id.as_str(),
@@ -378,6 +403,14 @@ fn should_enable_caching() -> Result<bool, Error> {
         .map_err(Error::UserCanceled)
 }
 
+fn find_team<'a>(teams: &'a [Team], slug: &str) -> Result<SelectedTeam<'a>, Error> {
+    teams
+        .iter()
+        .find(|team| team.slug == slug)
+        .map(SelectedTeam::Team)
+        .ok_or_else(|| Error::TeamNotFound(slug.to_string()))
+}
+
 #[cfg(test)]
 fn select_team<'a>(_: &CommandBase, teams: &'a [Team]) -> Result<SelectedTeam<'a>, Error> {
     let mut rng = rand::thread_rng();
@@ -632,7 +665,7 @@ mod test {
             )
             .unwrap();
 
-        link::link(&mut base, false, LinkTarget::RemoteCache)
+        link::link(&mut base, None, false, false, LinkTarget::RemoteCache)
             .await
             .unwrap();
 
@@ -650,6 +683,94 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_link_remote_cache_with_scope() -> Result<()> {
+        // user config
+        let user_config_file = NamedTempFile::new().unwrap();
+        fs::write(user_config_file.path(), r#"{ "token": "hello" }"#).unwrap();
+
+        // repo
+        let repo_root_tmp_dir = TempDir::new().unwrap();
+        let handle = repo_root_tmp_dir.path();
+        let repo_root = AbsoluteSystemPathBuf::try_from(handle).unwrap();
+        repo_root
+            .join_component("turbo.json")
+            .create_with_contents("{}")
+            .unwrap();
+        repo_root
+            .join_component("package.json")
+            .create_with_contents("{}")
+            .unwrap();
+
+        let repo_config_path = repo_root.join_components(&[".turbo", "config.json"]);
+        repo_config_path.ensure_dir().unwrap();
+        repo_config_path
+            .create_with_contents(r#"{ "apiurl": "http://localhost:3000", "teamslug": "old" }"#)
+            .unwrap();
+
+        let port = port_scanner::request_open_port().unwrap();
+        let handle = tokio::spawn(start_test_server(port));
+        let mut base = CommandBase {
+            override_global_config_path: Some(
+                AbsoluteSystemPathBuf::try_from(user_config_file.path().to_path_buf()).unwrap(),
+            ),
+            repo_root: repo_root.clone(),
+            color_config: ColorConfig::new(false),
+            config: OnceCell::new(),
+            args: Args::default(),
+            version: "",
+        };
+        base.config
+            .set(
+                TurborepoConfigBuilder::new(&base)
+                    .with_api_url(Some(format!("http://localhost:{}", port)))
+                    .with_login_url(Some(format!("http://localhost:{}", port)))
+                    .with_token(Some("token".to_string()))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        link::link(
+            &mut base,
+            Some(turborepo_vercel_api_mock::EXPECTED_TEAM_SLUG),
+            true,
+            false,
+            LinkTarget::RemoteCache,
+        )
+        .await
+        .unwrap();
+
+        let unknown_scope = link::link(
+            &mut base,
+            Some("unknown"),
+            true,
+            false,
+            LinkTarget::RemoteCache,
+        )
+        .await;
+
+        let missing_scope = link::link(&mut base, None, true, false, LinkTarget::RemoteCache).await;
+
+        handle.abort();
+
+        assert!(matches!(
+            unknown_scope,
+            Err(link::Error::TeamNotFound(slug)) if slug == "unknown"
+        ));
+        assert!(matches!(missing_scope, Err(link::Error::ScopeRequired)));
+
+        // read the config
+        let updated_config = TurborepoConfigBuilder::new(&base).build().unwrap();
+        assert_eq!(
+            updated_config.team_id(),
+            Some(turborepo_vercel_api_mock::EXPECTED_TEAM_ID)
+        );
+        assert_eq!(updated_config.team_slug(), None);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_link_spaces() {
         // user config
@@ -707,7 +828,7 @@ mod test {
         )
         .unwrap();
 
-        link::link(&mut base, false, LinkTarget::Spaces)
+        link::link(&mut base, None, false, false, LinkTarget::Spaces)
             .await
             .unwrap();
 
@@ -7,13 +7,16 @@ use turborepo_dirs::config_dir;
 use turborepo_ui::ColorConfig;
 
 use crate::{
-    config::{ConfigurationOptions, Error as ConfigError, TurborepoConfigBuilder},
+    config::{token_file, ConfigurationOptions, Error as ConfigError, TurborepoConfigBuilder},
     Args,
 };
 
 pub(crate) mod bin;
+pub(crate) mod cache;
+pub(crate) mod completion;
 pub(crate) mod config;
 pub(crate) mod daemon;
+pub(crate) mod flush_summaries;
 pub(crate) mod generate;
 pub(crate) mod link;
 pub(crate) mod login;
@@ -25,6 +28,7 @@ pub(crate) mod run;
 pub(crate) mod scan;
 pub(crate) mod telemetry;
 pub(crate) mod unlink;
+pub(crate) mod validate;
 
 #[derive(Debug, Clone)]
 pub struct CommandBase {
@@ -64,7 +68,7 @@ impl CommandBase {
             .with_api_url(self.args.api.clone())
             .with_login_url(self.args.login.clone())
             .with_team_slug(self.args.team.clone())
-            .with_token(self.args.token.clone())
+            .with_token(self.token()?)
             .with_timeout(self.args.remote_cache_timeout)
             .with_preflight(self.args.preflight.then_some(true))
             .with_ui(self.args.ui)
@@ -82,7 +86,13 @@ impl CommandBase {
             .with_cache_dir(
                 self.args
                     .execution_args()
-                    .and_then(|execution_args| execution_args.cache_dir.clone()),
+                    .and_then(|execution_args| execution_args.cache_dir.first().cloned()),
+            )
+            .with_cache_read_dirs(
+                self.args
+                    .execution_args()
+                    .filter(|execution_args| execution_args.cache_dir.len() > 1)
+                    .map(|execution_args| execution_args.cache_dir[1..].to_vec()),
             )
             .with_root_turbo_json_path(
                 self.args
@@ -91,11 +101,7 @@ impl CommandBase {
                     .map(AbsoluteSystemPathBuf::from_cwd)
                     .transpose()?,
             )
-            .with_force(
-                self.args
-                    .execution_args()
-                    .and_then(|args| args.force.map(|value| value.unwrap_or(true))),
-            )
+            .with_force(self.args.execution_args().and_then(|args| args.force()))
             .with_log_order(self.args.execution_args().and_then(|args| args.log_order))
             .with_remote_only(
                 self.args
@@ -109,9 +115,24 @@ impl CommandBase {
             )
             .with_run_summary(self.args.run_args().and_then(|args| args.summarize()))
             .with_allow_no_turbo_json(self.args.allow_no_turbo_json.then_some(true))
+            .with_environment(
+                self.args
+                    .execution_args()
+                    .and_then(|args| args.environment.clone()),
+            )
             .build()
     }
 
+    fn token(&self) -> Result<Option<String>, ConfigError> {
+        match &self.args.token_file {
+            Some(path) => {
+                let path = AbsoluteSystemPathBuf::from_cwd(path.clone())?;
+                Ok(Some(token_file::read(&path)?))
+            }
+            None => Ok(self.args.token.clone()),
+        }
+    }
+
     pub fn config(&self) -> Result<&ConfigurationOptions, ConfigError> {
         self.config.get_or_try_init(|| self.config_init())
     }
@@ -54,6 +54,15 @@ impl QueryError {
     }
 }
 
+// If the arg starts with "query", "mutation" or is a shorthand `{ ... }` query,
+// and ends in a bracket, it's likely a direct query. If it doesn't, it's a file
+// path, so we need to read it
+fn is_query_string(query: &str) -> bool {
+    let query = query.trim();
+    (query.starts_with("query") || query.starts_with("mutation") || query.starts_with('{'))
+        && query.ends_with('}')
+}
+
 pub async fn run(
     mut base: CommandBase,
     telemetry: CommandEventBuilder,
@@ -72,13 +81,7 @@ pub async fn run(
     let run = run_builder.build(&handler, telemetry).await?;
 
     if let Some(query) = query {
-        let trimmed_query = query.trim();
-        // If the arg starts with "query" or "mutation", and ends in a bracket, it's
-        // likely a direct query If it doesn't, it's a file path, so we need to
-        // read it
-        let query = if (trimmed_query.starts_with("query") || trimmed_query.starts_with("mutation"))
-            && trimmed_query.ends_with('}')
-        {
+        let query = if is_query_string(&query) {
             query
         } else {
             fs::read_to_string(AbsoluteSystemPathBuf::from_unknown(run.repo_root(), query))?
@@ -105,3 +108,19 @@ pub async fn run(
 
     Ok(0)
 }
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::is_query_string;
+
+    #[test_case("query { packages { items { name } } }", true ; "query")]
+    #[test_case("{ packages { items { name } } }", true ; "shorthand")]
+    #[test_case("  mutation { foo }\n", true ; "surrounding whitespace")]
+    #[test_case("queries/packages.graphql", false ; "file path")]
+    #[test_case("query.graphql", false ; "file named query")]
+    fn test_is_query_string(arg: &str, expected: bool) {
+        assert_eq!(is_query_string(arg), expected);
+    }
+}
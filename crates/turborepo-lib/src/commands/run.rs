@@ -1,10 +1,17 @@
 use std::{future::Future, sync::Arc};
 
 use tracing::error;
+use turbopath::AbsoluteSystemPathBuf;
 use turborepo_telemetry::events::command::CommandEventBuilder;
-use turborepo_ui::sender::UISender;
+use turborepo_ui::{sender::UISender, ColorConfig};
 
-use crate::{commands::CommandBase, run, run::builder::RunBuilder, signal::SignalHandler};
+use crate::{
+    cli::Args,
+    commands::CommandBase,
+    get_version, run,
+    run::{builder::RunBuilder, RunResult},
+    signal::SignalHandler,
+};
 
 #[cfg(windows)]
 pub fn get_signal() -> Result<impl Future<Output = Option<()>>, run::Error> {
@@ -37,6 +44,10 @@ pub async fn run(base: CommandBase, telemetry: CommandEventBuilder) -> Result<i3
     let handler = SignalHandler::new(signal);
 
     let run_builder = RunBuilder::new(base)?;
+    if run_builder.opts().run_opts.print_opts {
+        println!("{}", serde_json::to_string_pretty(run_builder.opts())?);
+        return Ok(0);
+    }
 
     let run_fut = async {
         let (analytics_sender, analytics_handle) = run_builder.start_analytics();
@@ -55,9 +66,12 @@ pub async fn run(base: CommandBase, telemetry: CommandEventBuilder) -> Result<i3
             analytics_handle.close_with_timeout().await;
         }
 
-        // We only stop if it's the TUI, for the web UI we don't need to stop
-        if let Some(UISender::Tui(sender)) = sender {
-            sender.stop();
+        // We only stop the UIs that render in the terminal, for the web UI we don't
+        // need to stop
+        match sender {
+            Some(UISender::Tui(sender)) => sender.stop(),
+            Some(UISender::Compact(sender)) => sender.stop(),
+            _ => {}
         }
 
         if let Some(handle) = handle {
@@ -85,3 +99,75 @@ pub async fn run(base: CommandBase, telemetry: CommandEventBuilder) -> Result<i3
         },
     }
 }
+
+/// Executes a run for the repository at `repo_root` without going through the
+/// CLI. `args` should hold a parsed `turbo run` invocation.
+///
+/// Unlike `turbo run` this doesn't listen for OS signals or start a UI, so the
+/// caller stays in control of the process.
+pub async fn execute_run(
+    repo_root: AbsoluteSystemPathBuf,
+    args: Args,
+) -> Result<RunResult, run::Error> {
+    let base = CommandBase::new(args, repo_root, get_version(), ColorConfig::new(true));
+    let handler = SignalHandler::new(std::future::pending());
+    let telemetry = CommandEventBuilder::new("run");
+
+    let run = RunBuilder::new(base)?.build(&handler, telemetry).await?;
+    let result = run.run_with_result(None, false).await;
+
+    handler.close().await;
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use clap::Parser;
+    use tempfile::TempDir;
+    use turbopath::AbsoluteSystemPathBuf;
+
+    use super::execute_run;
+    use crate::{cli::Args, run::TaskOutcome};
+
+    #[tokio::test]
+    async fn test_execute_run() {
+        let tmp = TempDir::new().unwrap();
+        let repo_root = AbsoluteSystemPathBuf::try_from(tmp.path()).unwrap();
+        repo_root
+            .join_component("package.json")
+            .create_with_contents(
+                r#"{ "name": "root", "packageManager": "npm@10.5.0", "workspaces": ["packages/*"] }"#,
+            )
+            .unwrap();
+        repo_root
+            .join_component("turbo.json")
+            .create_with_contents(r#"{ "tasks": { "build": {} } }"#)
+            .unwrap();
+        let package_json = repo_root.join_components(&["packages", "a", "package.json"]);
+        package_json.ensure_dir().unwrap();
+        package_json
+            .create_with_contents(r#"{ "name": "a", "scripts": { "build": "echo building" } }"#)
+            .unwrap();
+
+        let args =
+            Args::try_parse_from(["turbo", "run", "build", "--dry=json", "--no-daemon"]).unwrap();
+        let result = execute_run(repo_root, args).await.unwrap();
+
+        assert_eq!(result.exit_code, 0);
+        assert_eq!(result.tasks.len(), 1);
+        let TaskOutcome {
+            task_id,
+            hash,
+            cache_hit,
+            exit_code,
+        } = &result.tasks[0];
+        assert_eq!(task_id, "a#build");
+        assert!(!hash.is_empty());
+        assert!(!cache_hit);
+        assert_eq!(*exit_code, None);
+        let summary = result.summary.unwrap();
+        assert_eq!(summary["tasks"][0]["taskId"], "a#build");
+        assert_eq!(summary["tasks"][0]["hash"], hash.as_str());
+    }
+}
@@ -0,0 +1,122 @@
+//! Checks the root turbo.json and every package turbo.json for mistakes
+//! without running any tasks.
+
+use std::collections::HashMap;
+
+use miette::Diagnostic;
+use thiserror::Error;
+use turborepo_repository::{
+    package_graph::{self, PackageGraph, PackageName, ROOT_PKG_NAME},
+    package_json::PackageJson,
+};
+use turborepo_ui::{color, BOLD_GREEN};
+
+use crate::{
+    commands::CommandBase,
+    config,
+    turbo_json::{
+        validate_depends_on, validate_extends, validate_no_package_task_syntax, TurboJson,
+        TurboJsonLoader,
+    },
+};
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Config(#[from] config::Error),
+    #[error(transparent)]
+    PackageGraph(#[from] package_graph::builder::Error),
+    #[error("invalid turbo.json")]
+    Validation {
+        #[related]
+        errors: Vec<config::Error>,
+    },
+}
+
+pub async fn run(base: &CommandBase) -> Result<(), Error> {
+    let root_turbo_json_path = base.config()?.root_turbo_json_path(&base.repo_root);
+    let root_package_json = PackageJson::load(&base.repo_root.join_component("package.json"))
+        .map_err(config::Error::from)?;
+    let package_graph = PackageGraph::builder(&base.repo_root, root_package_json.clone())
+        .build()
+        .await?;
+
+    // Load configs the same way a run does so both agree on what is valid
+    let mut loader = TurboJsonLoader::workspace(
+        base.repo_root.clone(),
+        root_turbo_json_path,
+        package_graph.packages(),
+    );
+    let root_turbo_json = loader.load(&PackageName::Root)?.clone();
+
+    let mut package_names = package_graph
+        .packages()
+        .map(|(name, _)| name)
+        .filter(|name| **name != PackageName::Root)
+        .collect::<Vec<_>>();
+    package_names.sort();
+
+    let mut errors = Vec::new();
+    let mut package_turbo_jsons = HashMap::new();
+    for package_name in &package_names {
+        match loader.load(package_name) {
+            Ok(turbo_json) => {
+                package_turbo_jsons.insert((*package_name).clone(), turbo_json.clone());
+            }
+            Err(config::Error::NoTurboJSON) => (),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    errors.extend(validate_depends_on(
+        &root_turbo_json,
+        &root_turbo_json,
+        &package_turbo_jsons,
+    ));
+    errors.extend(validate_root_scripts(&root_turbo_json, &root_package_json));
+
+    for package_name in &package_names {
+        let Some(turbo_json) = package_turbo_jsons.get(*package_name) else {
+            continue;
+        };
+        errors.extend(turbo_json.validate(&[validate_no_package_task_syntax, validate_extends]));
+        errors.extend(validate_depends_on(
+            turbo_json,
+            &root_turbo_json,
+            &package_turbo_jsons,
+        ));
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::Validation { errors });
+    }
+
+    println!(
+        "{}",
+        color!(base.color_config, BOLD_GREEN, "turbo.json is valid")
+    );
+    Ok(())
+}
+
+// Root tasks only run if the root package.json has a script for them
+fn validate_root_scripts(
+    turbo_json: &TurboJson,
+    root_package_json: &PackageJson,
+) -> Vec<config::Error> {
+    turbo_json
+        .tasks
+        .iter()
+        .filter(|(task_name, _)| task_name.package() == Some(ROOT_PKG_NAME))
+        .filter(|(task_name, _)| !root_package_json.scripts.contains_key(task_name.task()))
+        .map(|(task_name, entry)| {
+            let (span, text) = entry.span_and_text("turbo.json");
+            config::Error::MissingRootTaskScript {
+                task_id: task_name.to_string(),
+                script: task_name.task().to_string(),
+                span,
+                text,
+            }
+        })
+        .collect()
+}
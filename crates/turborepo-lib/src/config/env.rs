@@ -7,9 +7,10 @@ use clap::ValueEnum;
 use itertools::Itertools;
 use turbopath::AbsoluteSystemPathBuf;
 
-use super::{ConfigurationOptions, Error, ResolvedConfigurationOptions};
+use super::{token_file, ConfigurationOptions, Error, ResolvedConfigurationOptions};
 use crate::{
     cli::{EnvMode, LogOrder},
+    hash::HashAlgorithm,
     turbo_json::UIMode,
 };
 
@@ -19,6 +20,7 @@ const TURBO_MAPPING: &[(&str, &str)] = [
     ("turbo_team", "team_slug"),
     ("turbo_teamid", "team_id"),
     ("turbo_token", "token"),
+    ("turbo_token_file", "token_file"),
     ("turbo_remote_cache_timeout", "timeout"),
     ("turbo_remote_cache_upload_timeout", "upload_timeout"),
     ("turbo_ui", "ui"),
@@ -29,6 +31,8 @@ const TURBO_MAPPING: &[(&str, &str)] = [
     ("turbo_daemon", "daemon"),
     ("turbo_env_mode", "env_mode"),
     ("turbo_cache_dir", "cache_dir"),
+    ("turbo_cache_max_size", "cache_max_size"),
+    ("turbo_hash_algorithm", "hash_algorithm"),
     ("turbo_preflight", "preflight"),
     ("turbo_scm_base", "scm_base"),
     ("turbo_scm_head", "scm_head"),
@@ -39,6 +43,12 @@ const TURBO_MAPPING: &[(&str, &str)] = [
     ("turbo_remote_cache_read_only", "remote_cache_read_only"),
     ("turbo_run_summary", "run_summary"),
     ("turbo_allow_no_turbo_json", "allow_no_turbo_json"),
+    ("turbo_env", "environment"),
+    ("turbo_s3_endpoint", "s3_endpoint"),
+    ("turbo_s3_bucket", "s3_bucket"),
+    ("turbo_s3_region", "s3_region"),
+    ("turbo_s3_access_key_id", "s3_access_key_id"),
+    ("turbo_s3_secret_access_key", "s3_secret_access_key"),
 ]
 .as_slice();
 
@@ -104,6 +114,14 @@ impl ResolvedConfigurationOptions for EnvVars {
             .transpose()
             .map_err(Error::InvalidUploadTimeout)?;
 
+        let cache_max_size = self
+            .output_map
+            .get("cache_max_size")
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse())
+            .transpose()
+            .map_err(Error::InvalidCacheMaxSize)?;
+
         // Process experimentalUI
         let ui =
             self.truthy_value("ui")
@@ -122,11 +140,31 @@ impl ResolvedConfigurationOptions for EnvVars {
             .and_then(|s| match s {
                 "strict" => Some(EnvMode::Strict),
                 "loose" => Some(EnvMode::Loose),
+                "loose-warn" => Some(EnvMode::LooseWarn),
+                _ => None,
+            });
+
+        let hash_algorithm = self
+            .output_map
+            .get("hash_algorithm")
+            .map(|s| s.as_str())
+            .and_then(|s| match s {
+                "xxh64" => Some(HashAlgorithm::Xxh64),
+                "sha256" => Some(HashAlgorithm::Sha256),
                 _ => None,
             });
 
         let cache_dir = self.output_map.get("cache_dir").map(|s| s.clone().into());
 
+        // A non-empty TURBO_TOKEN takes precedence over TURBO_TOKEN_FILE
+        let token = self.output_map.get("token");
+        let token = match self.output_map.get("token_file") {
+            Some(path) if !path.is_empty() && token.map_or(true, |token| token.is_empty()) => {
+                Some(token_file::read(&AbsoluteSystemPathBuf::from_cwd(path)?)?)
+            }
+            _ => token.cloned(),
+        };
+
         let root_turbo_json_path = self
             .output_map
             .get("root_turbo_json_path")
@@ -159,9 +197,15 @@ impl ResolvedConfigurationOptions for EnvVars {
             login_url: self.output_map.get("login_url").cloned(),
             team_slug: self.output_map.get("team_slug").cloned(),
             team_id: self.output_map.get("team_id").cloned(),
-            token: self.output_map.get("token").cloned(),
+            token,
             scm_base: self.output_map.get("scm_base").cloned(),
             scm_head: self.output_map.get("scm_head").cloned(),
+            environment: self.output_map.get("environment").cloned(),
+            s3_endpoint: self.output_map.get("s3_endpoint").cloned(),
+            s3_bucket: self.output_map.get("s3_bucket").cloned(),
+            s3_region: self.output_map.get("s3_region").cloned(),
+            s3_access_key_id: self.output_map.get("s3_access_key_id").cloned(),
+            s3_secret_access_key: self.output_map.get("s3_secret_access_key").cloned(),
             // Processed booleans
             signature,
             preflight,
@@ -178,9 +222,13 @@ impl ResolvedConfigurationOptions for EnvVars {
             // Processed numbers
             timeout,
             upload_timeout,
+            cache_max_size,
             spaces_id,
             env_mode,
+            hash_algorithm,
             cache_dir,
+            // Only `--cache-dir` adds read-only cache directories
+            cache_read_dirs: None,
             root_turbo_json_path,
             log_order,
         };
@@ -313,6 +361,7 @@ mod test {
         env.insert("turbo_daemon".into(), "true".into());
         env.insert("turbo_preflight".into(), "true".into());
         env.insert("turbo_env_mode".into(), "strict".into());
+        env.insert("turbo_hash_algorithm".into(), "sha256".into());
         env.insert("turbo_cache_dir".into(), cache_dir.clone().into());
         env.insert("turbo_root_turbo_json".into(), root_turbo_json.into());
         env.insert("turbo_force".into(), "1".into());
@@ -321,6 +370,7 @@ mod test {
         env.insert("turbo_remote_cache_read_only".into(), "1".into());
         env.insert("turbo_run_summary".into(), "true".into());
         env.insert("turbo_allow_no_turbo_json".into(), "true".into());
+        env.insert("turbo_env".into(), "ci".into());
 
         let config = EnvVars::new(&env)
             .unwrap()
@@ -333,6 +383,8 @@ mod test {
         assert!(config.remote_cache_read_only());
         assert!(config.run_summary());
         assert!(config.allow_no_turbo_json());
+        assert_eq!(config.environment(), Some("ci"));
+        assert_eq!(HashAlgorithm::Sha256, config.hash_algorithm());
         assert_eq!(turbo_api, config.api_url.unwrap());
         assert_eq!(turbo_login, config.login_url.unwrap());
         assert_eq!(turbo_team, config.team_slug.unwrap());
@@ -361,6 +413,7 @@ mod test {
         env.insert("turbo_ui".into(), "".into());
         env.insert("turbo_daemon".into(), "".into());
         env.insert("turbo_env_mode".into(), "".into());
+        env.insert("turbo_hash_algorithm".into(), "".into());
         env.insert("turbo_preflight".into(), "".into());
         env.insert("turbo_scm_head".into(), "".into());
         env.insert("turbo_scm_base".into(), "".into());
@@ -371,6 +424,7 @@ mod test {
         env.insert("turbo_remote_cache_read_only".into(), "".into());
         env.insert("turbo_run_summary".into(), "".into());
         env.insert("turbo_allow_no_turbo_json".into(), "".into());
+        env.insert("turbo_env".into(), "".into());
 
         let config = EnvVars::new(&env)
             .unwrap()
@@ -384,6 +438,7 @@ mod test {
         assert_eq!(config.ui, None);
         assert_eq!(config.daemon, None);
         assert_eq!(config.env_mode, None);
+        assert_eq!(config.hash_algorithm, None);
         assert!(!config.preflight());
         assert_eq!(config.scm_base(), None);
         assert_eq!(config.scm_head(), None);
@@ -394,6 +449,71 @@ mod test {
         assert!(!config.remote_cache_read_only());
         assert!(!config.run_summary());
         assert!(!config.allow_no_turbo_json());
+        assert_eq!(config.environment(), None);
+    }
+
+    #[test]
+    fn test_s3_env_setting() {
+        let mut env: HashMap<OsString, OsString> = HashMap::new();
+        env.insert("turbo_s3_endpoint".into(), "http://localhost:9000".into());
+        env.insert("turbo_s3_access_key_id".into(), "minioadmin".into());
+        env.insert("turbo_s3_secret_access_key".into(), "minio-secret".into());
+
+        let config = EnvVars::new(&env)
+            .unwrap()
+            .get_configuration_options(&ConfigurationOptions::default())
+            .unwrap();
+        // A bucket is required
+        assert_eq!(config.s3().unwrap(), None);
+
+        env.insert("turbo_s3_bucket".into(), "turbo-cache".into());
+        let config = EnvVars::new(&env)
+            .unwrap()
+            .get_configuration_options(&ConfigurationOptions::default())
+            .unwrap();
+        let s3 = config.s3().unwrap().unwrap();
+        assert_eq!(s3.endpoint, "http://localhost:9000");
+        assert_eq!(s3.bucket, "turbo-cache");
+        assert_eq!(s3.region, "us-east-1");
+        assert_eq!(s3.access_key_id, "minioadmin");
+        assert_eq!(s3.secret_access_key, "minio-secret");
+        assert_eq!(s3.timeout, config.timeout());
+        assert_eq!(s3.upload_timeout, config.upload_timeout());
+
+        // Both credentials are required once a bucket is configured
+        env.remove(OsStr::new("turbo_s3_secret_access_key"));
+        let config = EnvVars::new(&env)
+            .unwrap()
+            .get_configuration_options(&ConfigurationOptions::default())
+            .unwrap();
+        assert!(matches!(
+            config.s3(),
+            Err(Error::MissingS3Credentials("TURBO_S3_SECRET_ACCESS_KEY"))
+        ));
+    }
+
+    #[test]
+    fn test_token_file_env_setting() {
+        let tmp = tempfile::tempdir().unwrap();
+        let token_file = AbsoluteSystemPathBuf::try_from(tmp.path())
+            .unwrap()
+            .join_component("token");
+        token_file.create_with_contents("file-token\n").unwrap();
+
+        let mut env: HashMap<OsString, OsString> = HashMap::new();
+        env.insert("turbo_token_file".into(), token_file.as_str().into());
+        let config = EnvVars::new(&env)
+            .unwrap()
+            .get_configuration_options(&ConfigurationOptions::default())
+            .unwrap();
+        assert_eq!(config.token(), Some("file-token"));
+
+        env.insert("turbo_token".into(), "env-token".into());
+        let config = EnvVars::new(&env)
+            .unwrap()
+            .get_configuration_options(&ConfigurationOptions::default())
+            .unwrap();
+        assert_eq!(config.token(), Some("env-token"));
     }
 
     #[test]
@@ -1,5 +1,6 @@
 mod env;
 mod file;
+pub mod token_file;
 mod turbo_json;
 
 use std::{collections::HashMap, ffi::OsString, io};
@@ -17,6 +18,7 @@ use thiserror::Error;
 use tracing::debug;
 use turbo_json::TurboJsonReader;
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
+use turborepo_cache::S3Opts;
 use turborepo_errors::TURBO_SITE;
 use turborepo_repository::package_graph::PackageName;
 
@@ -24,6 +26,7 @@ pub use crate::turbo_json::{RawTurboJson, UIMode};
 use crate::{
     cli::{EnvMode, LogOrder},
     commands::CommandBase,
+    hash::HashAlgorithm,
     turbo_json::CONFIG_FILE,
 };
 
@@ -69,6 +72,11 @@ pub enum Error {
     Camino(#[from] camino::FromPathBufError),
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
+    #[error("Encountered an IO error while attempting to read token file {path}: {error}")]
+    FailedToReadTokenFile {
+        path: AbsoluteSystemPathBuf,
+        error: io::Error,
+    },
     #[error("Encountered an IO error while attempting to read {config_path}: {error}")]
     FailedToReadConfig {
         config_path: AbsoluteSystemPathBuf,
@@ -139,6 +147,27 @@ pub enum Error {
         #[source_code]
         text: NamedSource,
     },
+    #[error("Could not find task \"{task_name}\" that \"{dependant}\" depends on")]
+    MissingDependsOnTask {
+        task_name: String,
+        dependant: String,
+        #[label("no task defined for this dependency")]
+        span: Option<SourceSpan>,
+        #[source_code]
+        text: NamedSource,
+    },
+    #[error(
+        "\"{task_id}\" is defined in turbo.json, but the root package.json has no \"{script}\" \
+         script"
+    )]
+    MissingRootTaskScript {
+        task_id: String,
+        script: String,
+        #[label("root task defined here")]
+        span: Option<SourceSpan>,
+        #[source_code]
+        text: NamedSource,
+    },
     #[error("Tasks cannot be marked as interactive and cacheable")]
     InteractiveNoCacheable {
         #[label("marked interactive here")]
@@ -146,6 +175,31 @@ pub enum Error {
         #[source_code]
         text: NamedSource,
     },
+    #[error("`readyOutput` can only be set on persistent tasks")]
+    ReadyOutputNotPersistent {
+        #[label("ready output set here")]
+        span: Option<SourceSpan>,
+        #[source_code]
+        text: NamedSource,
+    },
+    #[error("`concurrencyGroup` cannot be set on persistent tasks")]
+    #[diagnostic(help(
+        "persistent tasks never exit, so the other tasks in the group would never run"
+    ))]
+    PersistentConcurrencyGroup {
+        #[label("concurrency group set here")]
+        span: Option<SourceSpan>,
+        #[source_code]
+        text: NamedSource,
+    },
+    #[error("`noopExitCode` cannot be 0")]
+    #[diagnostic(help("0 already means the task succeeded"))]
+    ZeroNoopExitCode {
+        #[label("noop exit code set here")]
+        span: Option<SourceSpan>,
+        #[source_code]
+        text: NamedSource,
+    },
     #[error("found `pipeline` field instead of `tasks`")]
     #[diagnostic(help("changed in 2.0: `pipeline` has been renamed to `tasks`"))]
     PipelineField {
@@ -166,6 +220,14 @@ pub enum Error {
     InvalidRemoteCacheTimeout(#[source] std::num::ParseIntError),
     #[error("TURBO_REMOTE_CACHE_UPLOAD_TIMEOUT: error parsing timeout.")]
     InvalidUploadTimeout(#[source] std::num::ParseIntError),
+    #[error("{0} must be set to use an S3 remote cache.")]
+    #[diagnostic(help(
+        "requests to the bucket are signed with TURBO_S3_ACCESS_KEY_ID and \
+         TURBO_S3_SECRET_ACCESS_KEY, so both need to be set"
+    ))]
+    MissingS3Credentials(&'static str),
+    #[error("TURBO_CACHE_MAX_SIZE: error parsing size.")]
+    InvalidCacheMaxSize(#[source] std::num::ParseIntError),
     #[error("TURBO_PREFLIGHT should be either 1 or 0.")]
     InvalidPreflight,
     #[error("TURBO_LOG_ORDER should be one of: {0}")]
@@ -183,12 +245,17 @@ pub enum Error {
     },
     #[error("Cannot load turbo.json for in {0} single package mode")]
     InvalidTurboJsonLoad(PackageName),
+    #[error(
+        "environment `{name}` isn't defined in turbo.json, available environments: {available}"
+    )]
+    UnknownEnvironment { name: String, available: String },
 }
 
 const DEFAULT_API_URL: &str = "https://vercel.com/api";
 const DEFAULT_LOGIN_URL: &str = "https://vercel.com";
 const DEFAULT_TIMEOUT: u64 = 30;
 const DEFAULT_UPLOAD_TIMEOUT: u64 = 60;
+const DEFAULT_S3_REGION: &str = "us-east-1";
 
 // We intentionally don't derive Serialize so that different parts
 // of the code that want to display the config can tune how they
@@ -235,6 +302,12 @@ pub struct ConfigurationOptions {
     pub(crate) scm_head: Option<String>,
     #[serde(rename = "cacheDir")]
     pub(crate) cache_dir: Option<Utf8PathBuf>,
+    #[serde(rename = "cacheReadDirs")]
+    pub(crate) cache_read_dirs: Option<Vec<Utf8PathBuf>>,
+    #[serde(rename = "cacheMaxSize")]
+    pub(crate) cache_max_size: Option<u64>,
+    #[serde(rename = "hashAlgorithm")]
+    pub(crate) hash_algorithm: Option<HashAlgorithm>,
     // This is skipped as we never want this to be stored in a file
     #[serde(skip)]
     pub(crate) root_turbo_json_path: Option<AbsoluteSystemPathBuf>,
@@ -244,6 +317,12 @@ pub struct ConfigurationOptions {
     pub(crate) remote_cache_read_only: Option<bool>,
     pub(crate) run_summary: Option<bool>,
     pub(crate) allow_no_turbo_json: Option<bool>,
+    pub(crate) environment: Option<String>,
+    pub(crate) s3_endpoint: Option<String>,
+    pub(crate) s3_bucket: Option<String>,
+    pub(crate) s3_region: Option<String>,
+    pub(crate) s3_access_key_id: Option<String>,
+    pub(crate) s3_secret_access_key: Option<String>,
 }
 
 #[derive(Default)]
@@ -356,6 +435,21 @@ impl ConfigurationOptions {
         })
     }
 
+    /// Cache directories that are only read from, checked in order after
+    /// `cache_dir`
+    pub fn cache_read_dirs(&self) -> &[Utf8PathBuf] {
+        self.cache_read_dirs.as_deref().unwrap_or_default()
+    }
+
+    /// The maximum size of the local cache in bytes, if it should be bounded
+    pub fn cache_max_size(&self) -> Option<u64> {
+        self.cache_max_size
+    }
+
+    pub fn hash_algorithm(&self) -> HashAlgorithm {
+        self.hash_algorithm.unwrap_or_default()
+    }
+
     pub fn force(&self) -> bool {
         self.force.unwrap_or_default()
     }
@@ -372,6 +466,34 @@ impl ConfigurationOptions {
         self.remote_cache_read_only.unwrap_or_default()
     }
 
+    /// The S3-compatible bucket to use as the remote cache instead of Vercel.
+    /// Only set if both an endpoint and a bucket are configured, in which case
+    /// the credentials are required.
+    pub fn s3(&self) -> Result<Option<S3Opts>, Error> {
+        let (Some(endpoint), Some(bucket)) = (
+            non_empty_str(self.s3_endpoint.as_deref()),
+            non_empty_str(self.s3_bucket.as_deref()),
+        ) else {
+            return Ok(None);
+        };
+        let access_key_id = non_empty_str(self.s3_access_key_id.as_deref())
+            .ok_or(Error::MissingS3Credentials("TURBO_S3_ACCESS_KEY_ID"))?;
+        let secret_access_key = non_empty_str(self.s3_secret_access_key.as_deref())
+            .ok_or(Error::MissingS3Credentials("TURBO_S3_SECRET_ACCESS_KEY"))?;
+
+        Ok(Some(S3Opts {
+            endpoint: endpoint.to_string(),
+            bucket: bucket.to_string(),
+            region: non_empty_str(self.s3_region.as_deref())
+                .unwrap_or(DEFAULT_S3_REGION)
+                .to_string(),
+            access_key_id: access_key_id.to_string(),
+            secret_access_key: secret_access_key.to_string(),
+            timeout: self.timeout(),
+            upload_timeout: self.upload_timeout(),
+        }))
+    }
+
     pub fn run_summary(&self) -> bool {
         self.run_summary.unwrap_or_default()
     }
@@ -385,6 +507,11 @@ impl ConfigurationOptions {
     pub fn allow_no_turbo_json(&self) -> bool {
         self.allow_no_turbo_json.unwrap_or_default()
     }
+
+    /// The turbo.json `environments` entry to apply to task definitions
+    pub fn environment(&self) -> Option<&str> {
+        non_empty_str(self.environment.as_deref())
+    }
 }
 
 // Maps Some("") to None to emulate how Go handles empty strings
@@ -0,0 +1,86 @@
+//! Reads the remote cache token from a file so that it doesn't end up in
+//! shell history or process listings.
+
+use tracing::warn;
+use turbopath::AbsoluteSystemPath;
+
+use super::Error;
+
+/// Reads the token from `path`, ignoring surrounding whitespace. Warns if the
+/// file can be read by other users.
+pub fn read(path: &AbsoluteSystemPath) -> Result<String, Error> {
+    let token = path
+        .read_to_string()
+        .map_err(|error| Error::FailedToReadTokenFile {
+            path: path.to_owned(),
+            error,
+        })?;
+    if is_world_readable(path) {
+        warn!(
+            "token file {path} is readable by all users, restrict its permissions with `chmod 600 \
+             {path}`"
+        );
+    }
+    Ok(token.trim().to_string())
+}
+
+#[cfg(unix)]
+fn is_world_readable(path: &AbsoluteSystemPath) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.stat()
+        .map_or(false, |metadata| metadata.permissions().mode() & 0o004 != 0)
+}
+
+// Windows doesn't have a notion of world readable files
+#[cfg(not(unix))]
+fn is_world_readable(_path: &AbsoluteSystemPath) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use turbopath::AbsoluteSystemPath;
+
+    use super::read;
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_reads_token() {
+        let tmp = tempfile::tempdir().unwrap();
+        let token_file = AbsoluteSystemPath::from_std_path(tmp.path())
+            .unwrap()
+            .join_component("token");
+        token_file.create_with_contents("my-token\n").unwrap();
+        #[cfg(unix)]
+        token_file.set_mode(0o600).unwrap();
+
+        assert_eq!(read(&token_file).unwrap(), "my-token");
+        assert!(!logs_contain("readable by all users"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_world_readable_token_file_warns() {
+        let tmp = tempfile::tempdir().unwrap();
+        let token_file = AbsoluteSystemPath::from_std_path(tmp.path())
+            .unwrap()
+            .join_component("token");
+        token_file.create_with_contents("my-token").unwrap();
+        token_file.set_mode(0o644).unwrap();
+
+        assert_eq!(read(&token_file).unwrap(), "my-token");
+        assert!(logs_contain("readable by all users"));
+    }
+
+    #[test]
+    fn test_missing_token_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let token_file = AbsoluteSystemPath::from_std_path(tmp.path())
+            .unwrap()
+            .join_component("token");
+
+        assert!(read(&token_file).is_err());
+    }
+}
@@ -60,6 +60,8 @@ impl<'a> ResolvedConfigurationOptions for TurboJsonReader<'a> {
         opts.daemon = turbo_json.daemon.map(|daemon| *daemon.as_inner());
         opts.env_mode = turbo_json.env_mode;
         opts.cache_dir = cache_dir;
+        opts.cache_max_size = turbo_json.cache_max_size;
+        opts.hash_algorithm = turbo_json.hash_algorithm;
         Ok(opts)
     }
 }
@@ -69,6 +71,7 @@ mod test {
     use tempfile::tempdir;
 
     use super::*;
+    use crate::hash::HashAlgorithm;
 
     #[test]
     fn test_reads_from_default() {
@@ -82,7 +85,8 @@ mod test {
             .join_component("turbo.json")
             .create_with_contents(
                 serde_json::to_string_pretty(&serde_json::json!({
-                    "daemon": false
+                    "daemon": false,
+                    "hashAlgorithm": "sha256"
                 }))
                 .unwrap(),
             )
@@ -92,6 +96,7 @@ mod test {
         let config = reader.get_configuration_options(&existing_config).unwrap();
         // Make sure we read the default turbo.json
         assert_eq!(config.daemon(), Some(false));
+        assert_eq!(config.hash_algorithm(), HashAlgorithm::Sha256);
     }
 
     #[test]
@@ -1,4 +1,7 @@
-use std::{io, time::Duration};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
 
 use globwalk::ValidatedGlob;
 use miette::Diagnostic;
@@ -22,6 +25,8 @@ use crate::{
 pub struct DaemonClient<T> {
     client: proto::turbod_client::TurbodClient<tonic::transport::Channel>,
     connect_settings: T,
+    // Whether connecting started the daemon
+    started_daemon: bool,
 }
 
 impl DaemonClient<()> {
@@ -29,6 +34,7 @@ impl DaemonClient<()> {
         Self {
             client,
             connect_settings: (),
+            started_daemon: false,
         }
     }
 
@@ -37,10 +43,12 @@ impl DaemonClient<()> {
     pub fn with_connect_settings(
         self,
         connect_settings: DaemonConnector,
+        started_daemon: bool,
     ) -> DaemonClient<DaemonConnector> {
         DaemonClient {
             client: self.client,
             connect_settings,
+            started_daemon,
         }
     }
 }
@@ -128,6 +136,14 @@ impl<T> DaemonClient<T> {
             .ok_or(DaemonError::MalformedResponse)
     }
 
+    /// Sends a no-op request to the daemon and returns how long it took to
+    /// get a response.
+    pub async fn ping(&mut self) -> Result<Duration, DaemonError> {
+        let start = Instant::now();
+        self.client.ping(proto::PingRequest {}).await?;
+        Ok(start.elapsed())
+    }
+
     pub async fn discover_packages(&mut self) -> Result<DiscoverPackagesResponse, DaemonError> {
         let req = proto::DiscoverPackagesRequest {};
         let mut req = req.into_request();
@@ -186,6 +202,12 @@ impl DaemonClient<DaemonConnector> {
     pub fn paths(&self) -> &Paths {
         &self.connect_settings.paths
     }
+
+    /// Whether the daemon was started while connecting, rather than already
+    /// running.
+    pub fn started_daemon(&self) -> bool {
+        self.started_daemon
+    }
 }
 
 fn format_repo_relative_glob(glob: &str) -> String {
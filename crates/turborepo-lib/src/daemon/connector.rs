@@ -102,9 +102,11 @@ impl DaemonConnector {
     #[tracing::instrument(skip(self))]
     pub async fn connect(self) -> Result<DaemonClient<DaemonConnector>, DaemonConnectorError> {
         let time = Instant::now();
+        let mut started_daemon = false;
         for _ in 0..Self::CONNECT_RETRY_MAX {
-            let pid = self.get_or_start_daemon().await?;
+            let (pid, started) = self.get_or_start_daemon().await?;
             debug!("got daemon with pid: {}", pid);
+            started_daemon |= started;
 
             let conn = match self.get_connection(self.paths.sock_file.clone()).await {
                 Err(DaemonConnectorError::Watcher(_)) => continue,
@@ -123,7 +125,7 @@ impl DaemonConnector {
                 Ok(_) => {
                     return {
                         debug!("connected in {}µs", time.elapsed().as_micros());
-                        Ok(client.with_connect_settings(self))
+                        Ok(client.with_connect_settings(self, started_daemon))
                     }
                 }
                 Err(DaemonError::VersionMismatch(_)) if self.can_kill_server => {
@@ -139,10 +141,10 @@ impl DaemonConnector {
         ))
     }
 
-    /// Gets the PID of the daemon process.
+    /// Gets the PID of the daemon process, and whether it was just started.
     ///
     /// If a daemon is not running, it starts one.
-    async fn get_or_start_daemon(&self) -> Result<sysinfo::Pid, DaemonConnectorError> {
+    async fn get_or_start_daemon(&self) -> Result<(sysinfo::Pid, bool), DaemonConnectorError> {
         debug!("looking for pid in lockfile: {:?}", self.paths.pid_file);
 
         let pidfile = self.pid_lock();
@@ -150,11 +152,11 @@ impl DaemonConnector {
         match pidfile.get_owner()? {
             Some(pid) => {
                 debug!("found pid: {}", pid);
-                Ok(sysinfo::Pid::from(pid as usize))
+                Ok((sysinfo::Pid::from(pid as usize), false))
             }
             None if self.can_start_server => {
                 debug!("no pid found, starting daemon");
-                Self::start_daemon().await
+                Ok((Self::start_daemon().await?, true))
             }
             None => Err(DaemonConnectorError::NotRunning),
         }
@@ -409,7 +411,7 @@ enum WaitAction {
 
 #[cfg(test)]
 mod test {
-    use std::assert_matches::assert_matches;
+    use std::assert_matches;
 
     use tokio::{
         select,
@@ -451,6 +453,27 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn finds_running_daemon_without_starting_one() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let repo_root = AbsoluteSystemPathBuf::try_from(tmp_dir.path()).unwrap();
+
+        let connector = DaemonConnector::new(true, false, &repo_root);
+        connector.paths.pid_file.ensure_dir().unwrap();
+        // Any live process stands in for the daemon
+        let pid = std::process::id();
+        connector
+            .paths
+            .pid_file
+            .create_with_contents(pid.to_string())
+            .unwrap();
+
+        assert_matches!(
+            connector.get_or_start_daemon().await,
+            Ok((found, false)) if found == Pid::from(pid as usize)
+        );
+    }
+
     #[tokio::test]
     async fn handles_missing_server_connect() {
         let tmp_dir = tempfile::tempdir().unwrap();
@@ -613,6 +636,13 @@ mod test {
             unimplemented!()
         }
 
+        async fn ping(
+            &self,
+            _req: tonic::Request<proto::PingRequest>,
+        ) -> tonic::Result<tonic::Response<proto::PingResponse>> {
+            unimplemented!()
+        }
+
         async fn notify_outputs_written(
             &self,
             _req: tonic::Request<proto::NotifyOutputsWrittenRequest>,
@@ -487,6 +487,13 @@ impl proto::turbod_server::Turbod for TurboGrpcServiceInner {
         }))
     }
 
+    async fn ping(
+        &self,
+        _request: tonic::Request<proto::PingRequest>,
+    ) -> Result<tonic::Response<proto::PingResponse>, tonic::Status> {
+        Ok(tonic::Response::new(proto::PingResponse {}))
+    }
+
     async fn notify_outputs_written(
         &self,
         request: tonic::Request<proto::NotifyOutputsWrittenRequest>,
@@ -678,7 +685,7 @@ impl NamedService for TurboGrpcServiceInner {
 #[cfg(test)]
 mod test {
     use std::{
-        assert_matches::{self, assert_matches},
+        assert_matches,
         time::{Duration, Instant},
     };
 
@@ -693,7 +700,9 @@ mod test {
     };
 
     use super::compare_versions;
-    use crate::daemon::{proto::VersionRange, CloseReason, Paths, TurboGrpcService};
+    use crate::daemon::{
+        proto::VersionRange, CloseReason, DaemonConnector, Paths, TurboGrpcService,
+    };
 
     #[test_case("1.2.3", "1.2.3", VersionRange::Exact, true ; "exact match")]
     #[test_case("1.2.3", "1.2.3", VersionRange::Patch, true ; "patch match")]
@@ -803,6 +812,60 @@ mod test {
         tracing::info!("and files cleaned up");
     }
 
+    // the windows runner starts a new thread to accept uds requests,
+    // so we need a multi-threaded runtime
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ping() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let path = AbsoluteSystemPathBuf::try_from(tempdir.path())
+            .unwrap()
+            .to_realpath()
+            .unwrap();
+
+        let repo_root = path.join_component("repo");
+        let paths = Paths::from_repo_root(&repo_root);
+
+        let (tx, rx) = oneshot::channel::<CloseReason>();
+        let exit_signal = rx.map(|_result| CloseReason::Interrupt);
+
+        let service = TurboGrpcService::new(
+            repo_root.clone(),
+            paths.clone(),
+            Duration::from_secs(60 * 60),
+            exit_signal,
+        );
+
+        repo_root.create_dir_all().unwrap();
+        repo_root
+            .join_component("package.json")
+            .create_with_contents(r#"{"workspaces": ["packages/*"]}"#)
+            .unwrap();
+        repo_root
+            .join_component("package-lock.json")
+            .create_with_contents("")
+            .unwrap();
+
+        let handle = tokio::task::spawn(service.serve());
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !paths.sock_file.exists() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("daemon should start listening");
+
+        let mut client = DaemonConnector::new(false, false, &repo_root)
+            .connect()
+            .await
+            .unwrap();
+        let latency = client.ping().await.unwrap();
+        assert!(latency > Duration::ZERO);
+        assert!(latency < Duration::from_secs(1), "ping took {latency:?}");
+
+        tx.send(CloseReason::Interrupt).unwrap();
+        handle.await.unwrap().unwrap();
+    }
+
     // the windows runner starts a new thread to accept uds requests,
     // so we need a multi-threaded runtime
     #[tokio::test(flavor = "multi_thread")]
@@ -846,7 +909,7 @@ mod test {
             now.elapsed() >= Duration::from_millis(10),
             "must wait at least 5ms"
         );
-        assert_matches::assert_matches!(
+        assert_matches!(
             close_reason,
             Ok(CloseReason::Timeout),
             "must close due to timeout"
@@ -3,6 +3,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use convert_case::{Case, Casing};
 use itertools::Itertools;
 use miette::{Diagnostic, NamedSource, SourceSpan};
+use tracing::warn;
 use turbopath::AbsoluteSystemPath;
 use turborepo_errors::{Spanned, TURBO_SITE};
 use turborepo_graph_utils as graph;
@@ -263,6 +264,10 @@ impl<'a> EngineBuilder<'a> {
             return Err(Error::MissingTasks(errors));
         }
 
+        for warning in self.tasks_without_scripts(&mut turbo_json_loader) {
+            warn!("{warning}");
+        }
+
         let allowed_tasks = self.allowed_tasks();
 
         let mut visited = HashSet::new();
@@ -401,6 +406,76 @@ impl<'a> EngineBuilder<'a> {
 
     // Helper methods used when building the engine
 
+    /// Warnings for tasks configured in the root turbo.json that don't have a
+    /// matching script to run. These are usually typos in the task name.
+    fn tasks_without_scripts(&self, loader: &mut TurboJsonLoader) -> Vec<String> {
+        if self.add_all_tasks {
+            return Vec::new();
+        }
+        let Ok(root_turbo_json) = loader.load(&PackageName::Root) else {
+            return Vec::new();
+        };
+        let definitions = root_turbo_json
+            .tasks
+            .iter()
+            .filter_map(|(task_name, raw_definition)| {
+                let definition =
+                    TaskDefinition::try_from(raw_definition.as_inner().clone()).ok()?;
+                Some((task_name, definition))
+            })
+            .collect::<Vec<_>>();
+        let depended_on = definitions
+            .iter()
+            .flat_map(|(_, definition)| {
+                definition
+                    .task_dependencies
+                    .iter()
+                    .chain(&definition.topological_dependencies)
+            })
+            .map(|task_name| task_name.task())
+            .collect::<HashSet<_>>();
+
+        definitions
+            .iter()
+            // Transit tasks have no script on purpose, they're only there to
+            // order other tasks
+            .filter(|(task_name, definition)| {
+                definition.task_dependencies.is_empty()
+                    && definition.topological_dependencies.is_empty()
+                    && !depended_on.contains(task_name.task())
+            })
+            .filter(|(task_name, _)| match task_name.package() {
+                // Package tasks are only checked if their package is in scope
+                Some(package) => {
+                    let package = PackageName::from(package);
+                    self.workspaces.contains(&package)
+                        && !self
+                            .package_graph
+                            .package_json(&package)
+                            .map_or(false, |package_json| {
+                                package_json.has_script(task_name.task())
+                            })
+                }
+                // A script in a package outside of the filter still means the
+                // task isn't a typo
+                None => {
+                    !self.workspaces.is_empty()
+                        && !self
+                            .package_graph
+                            .packages()
+                            .any(|(_, info)| info.package_json.has_script(task_name.task()))
+                }
+            })
+            .map(|(task_name, _)| {
+                format!(
+                    "task `{task_name}` is configured in turbo.json but there is no `{}` script \
+                     for it to run",
+                    task_name.task()
+                )
+            })
+            .collect()
+    }
+
     fn has_task_definition(
         loader: &mut TurboJsonLoader,
         workspace: &PackageName,
@@ -544,7 +619,7 @@ fn validate_task_name(task: Spanned<&str>) -> Result<(), Error> {
 
 #[cfg(test)]
 mod test {
-    use std::assert_matches::assert_matches;
+    use std::assert_matches;
 
     use pretty_assertions::assert_eq;
     use serde_json::json;
@@ -1095,6 +1170,61 @@ mod test {
         assert_matches!(engine, Err(Error::MissingRootTaskInTurboJson { .. }));
     }
 
+    #[test]
+    fn test_tasks_without_scripts() {
+        let repo_root_dir = TempDir::with_prefix("repo").unwrap();
+        let repo_root = AbsoluteSystemPathBuf::new(repo_root_dir.path().to_str().unwrap()).unwrap();
+        let mut package_jsons = package_jsons! {
+            repo_root,
+            "a" => [],
+            "b" => [],
+            "c" => []
+        };
+        for package_json in package_jsons.values_mut() {
+            package_json
+                .scripts
+                .insert("build".to_string(), Spanned::new("echo build".to_string()));
+        }
+        let package_graph = mock_package_graph(&repo_root, package_jsons);
+        let turbo_jsons = vec![(
+            PackageName::Root,
+            turbo_json(json!({
+                "tasks": {
+                    "build": {
+                        "dependsOn": ["^transit", "prepare"]
+                    },
+                    "biuld": {},
+                    "a#biuld": {},
+                    // c isn't in scope
+                    "c#biuld": {},
+                    // Tasks that only order other tasks don't need scripts
+                    "transit": {
+                        "dependsOn": ["^transit"]
+                    },
+                    "prepare": {},
+                }
+            })),
+        )]
+        .into_iter()
+        .collect();
+        let mut loader = TurboJsonLoader::noop(turbo_jsons);
+        let builder = EngineBuilder::new(&repo_root, &package_graph, loader.clone(), false)
+            .with_tasks(Some(Spanned::new(TaskName::from("build"))))
+            .with_workspaces(vec![PackageName::from("a"), PackageName::from("b")]);
+
+        let mut warnings = builder.tasks_without_scripts(&mut loader);
+        warnings.sort();
+        assert_eq!(
+            warnings,
+            vec![
+                "task `a#biuld` is configured in turbo.json but there is no `biuld` script for it \
+                 to run",
+                "task `biuld` is configured in turbo.json but there is no `biuld` script for it \
+                 to run",
+            ]
+        );
+    }
+
     #[test]
     fn test_engine_tasks_only() {
         let repo_root_dir = TempDir::with_prefix("repo").unwrap();
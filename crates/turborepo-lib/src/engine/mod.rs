@@ -5,7 +5,7 @@ mod dot;
 mod mermaid;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt,
 };
 
@@ -425,7 +425,9 @@ impl Engine<Built> {
                         .ok_or_else(|| ValidateError::MissingPackageJson {
                             package: dep_id.package().to_string(),
                         })?;
+                    // Persistent tasks that signal readiness can be depended on
                     if task_definition.persistent
+                        && task_definition.ready_output.is_none()
                         && package_json.scripts.contains_key(dep_id.task())
                     {
                         let (span, text) = self
@@ -508,6 +510,188 @@ impl Engine<Built> {
             })
             .collect()
     }
+
+    /// Finds tasks in different packages whose outputs resolve to the same
+    /// location. Restoring either task from the cache can overwrite files
+    /// produced by the other.
+    pub fn output_overlaps(&self, package_graph: &PackageGraph) -> Vec<OutputOverlap> {
+        let mut task_outputs = self
+            .tasks()
+            .filter_map(|node| match node {
+                TaskNode::Root => None,
+                TaskNode::Task(task) => Some(task),
+            })
+            .filter_map(|task| {
+                let package = PackageName::from(task.package());
+                // Tasks without a script never write any outputs
+                if !package_graph
+                    .package_json(&package)?
+                    .scripts
+                    .contains_key(task.task())
+                {
+                    return None;
+                }
+                let package_dir = package_graph.package_dir(&package)?.to_unix();
+                let globs = self
+                    .task_definitions
+                    .get(task)?
+                    .outputs
+                    .inclusions
+                    .iter()
+                    .filter_map(|glob| OutputGlob::new(package_dir.as_str(), glob))
+                    .collect::<Vec<_>>();
+                Some((task, globs))
+            })
+            .collect::<Vec<_>>();
+        task_outputs.sort_by_key(|(task, _)| *task);
+
+        // Two globs can only match the same file if the literal directory of
+        // one contains the literal directory of the other, so we only need to
+        // compare each glob against the globs rooted in one of its ancestors.
+        let mut globs_by_base: HashMap<&[String], Vec<(usize, &OutputGlob)>> = HashMap::new();
+        for (i, (_, globs)) in task_outputs.iter().enumerate() {
+            for glob in globs {
+                globs_by_base
+                    .entry(glob.base())
+                    .or_default()
+                    .push((i, glob));
+            }
+        }
+
+        let mut shared_paths = BTreeMap::new();
+        for (i, (task, globs)) in task_outputs.iter().enumerate() {
+            for glob in globs {
+                let base = glob.base();
+                let candidates = (0..=base.len())
+                    .filter_map(|len| globs_by_base.get(&base[..len]))
+                    .flatten();
+                for (j, other_glob) in candidates {
+                    let (other, _) = &task_outputs[*j];
+                    if task.package() == other.package() || !glob.overlaps(other_glob) {
+                        continue;
+                    }
+                    shared_paths
+                        .entry((i.min(*j), i.max(*j)))
+                        .or_insert_with(|| base.join("/"));
+                }
+            }
+        }
+
+        shared_paths
+            .into_iter()
+            .map(|((i, j), path)| OutputOverlap {
+                first: task_outputs[i].0.clone(),
+                second: task_outputs[j].0.clone(),
+                path,
+            })
+            .collect()
+    }
+}
+
+/// Two tasks from different packages with outputs in the same location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputOverlap {
+    pub first: TaskId<'static>,
+    pub second: TaskId<'static>,
+    /// The repo relative path both tasks write to
+    pub path: String,
+}
+
+impl fmt::Display for OutputOverlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} and {} both have outputs in {}. Restoring one from the cache may overwrite files \
+             written by the other.",
+            self.first, self.second, self.path
+        )
+    }
+}
+
+// An output glob made relative to the repository root
+struct OutputGlob {
+    segments: Vec<String>,
+    // Number of leading segments without any wildcards
+    base_len: usize,
+}
+
+impl OutputGlob {
+    // Returns `None` for globs that escape the repository
+    fn new(package_dir: &str, glob: &str) -> Option<Self> {
+        let mut segments = package_dir
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>();
+        let mut base_len = None;
+        for segment in glob.split('/') {
+            match segment {
+                "" | "." => {}
+                ".." if base_len.is_none() => {
+                    segments.pop()?;
+                }
+                segment => {
+                    if base_len.is_none() && is_pattern(segment) {
+                        base_len = Some(segments.len());
+                    }
+                    segments.push(segment.to_string());
+                }
+            }
+        }
+        let base_len = base_len.unwrap_or(segments.len());
+        Some(Self { segments, base_len })
+    }
+
+    fn base(&self) -> &[String] {
+        &self.segments[..self.base_len]
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        segments_overlap(&self.segments, &other.segments)
+    }
+}
+
+fn is_pattern(segment: &str) -> bool {
+    segment.contains(['*', '?', '[', '{'])
+}
+
+// Whether some path could be matched by both sequences of glob segments
+fn segments_overlap(a: &[String], b: &[String]) -> bool {
+    match (a.split_first(), b.split_first()) {
+        (None, None) => true,
+        // `**` matches any number of segments, including none
+        (Some((first, rest)), _) if first == "**" => {
+            segments_overlap(rest, b) || (!b.is_empty() && segments_overlap(a, &b[1..]))
+        }
+        (_, Some((first, rest))) if first == "**" => {
+            segments_overlap(a, rest) || (!a.is_empty() && segments_overlap(&a[1..], b))
+        }
+        (Some((a_first, a_rest)), Some((b_first, b_rest))) => {
+            segment_overlaps(a_first, b_first) && segments_overlap(a_rest, b_rest)
+        }
+        _ => false,
+    }
+}
+
+// Whether some file name could be matched by both segments. Patterns are only
+// compared by their literal prefix and suffix, so this errs on the side of
+// reporting an overlap.
+fn segment_overlaps(a: &str, b: &str) -> bool {
+    if !is_pattern(a) && !is_pattern(b) {
+        return a == b;
+    }
+    let literal_prefix = |segment: &str| {
+        let end = segment.find(['*', '?', '[', '{']).unwrap_or(segment.len());
+        segment[..end].to_string()
+    };
+    let literal_suffix = |segment: &str| {
+        let start = segment.rfind(['*', '?', ']', '}']).map_or(0, |i| i + 1);
+        segment[start..].to_string()
+    };
+    let (a_prefix, b_prefix) = (literal_prefix(a), literal_prefix(b));
+    let (a_suffix, b_suffix) = (literal_suffix(a), literal_suffix(b));
+    (a_prefix.starts_with(&b_prefix) || b_prefix.starts_with(&a_prefix))
+        && (a_suffix.ends_with(&b_suffix) || b_suffix.ends_with(&a_suffix))
 }
 
 #[derive(Debug, Error, Diagnostic)]
@@ -555,9 +739,10 @@ impl fmt::Display for TaskNode {
 #[cfg(test)]
 mod test {
 
-    use std::collections::BTreeMap;
+    use std::{io::Write, sync::Arc, time::Duration};
 
     use tempfile::TempDir;
+    use tokio::sync::mpsc;
     use turbopath::AbsoluteSystemPath;
     use turborepo_repository::{
         discovery::{DiscoveryResponse, PackageDiscovery, WorkspaceData},
@@ -565,7 +750,10 @@ mod test {
     };
 
     use super::*;
-    use crate::run::task_id::TaskName;
+    use crate::{
+        run::task_id::TaskName,
+        task_graph::{ReadyWriter, TaskOutputs},
+    };
 
     struct DummyDiscovery<'a>(&'a TempDir);
 
@@ -584,18 +772,17 @@ mod test {
                     let package_json = path.join_component(&format!("{}.json", name));
 
                     let scripts = if had_build {
-                        BTreeMap::from_iter(
-                            [
-                                ("build".to_string(), Spanned::new("echo built!".to_string())),
-                                (
-                                    "dev".to_string(),
-                                    Spanned::new("echo running dev!".to_string()),
-                                ),
-                            ]
-                            .into_iter(),
-                        )
+                        [
+                            ("build".to_string(), Spanned::new("echo built!".to_string())),
+                            (
+                                "dev".to_string(),
+                                Spanned::new("echo running dev!".to_string()),
+                            ),
+                        ]
+                        .into_iter()
+                        .collect()
                     } else {
-                        BTreeMap::default()
+                        Default::default()
                     };
 
                     let package = PackageJson {
@@ -683,6 +870,188 @@ mod test {
         engine.validate(&graph, 4, UIMode::Stream).expect("ok");
     }
 
+    #[tokio::test]
+    async fn test_dependent_waits_for_persistent_task_ready_output() {
+        let tmp = tempfile::TempDir::with_prefix("ready_output").unwrap();
+
+        let mut engine = Engine::new();
+
+        // `b#build` depends on the dev server in `a`
+        let dev_task_id = TaskId::new("a", "dev");
+        let build_task_id = TaskId::new("b", "build");
+        let dev_idx = engine.get_index(&dev_task_id);
+        engine.add_definition(
+            dev_task_id.clone(),
+            TaskDefinition {
+                persistent: true,
+                ready_output: Some("ready on".to_string()),
+                ..Default::default()
+            },
+        );
+        let build_idx = engine.get_index(&build_task_id);
+        engine.add_definition(
+            build_task_id.clone(),
+            TaskDefinition {
+                task_dependencies: vec![Spanned::new(TaskName::from(dev_task_id.clone()))],
+                ..Default::default()
+            },
+        );
+        engine.task_graph.add_edge(build_idx, dev_idx, ());
+        engine.connect_to_root(&dev_task_id);
+        let engine = Arc::new(engine.seal());
+
+        let graph = PackageGraph::builder(
+            AbsoluteSystemPath::from_std_path(tmp.path()).unwrap(),
+            PackageJson::default(),
+        )
+        .with_package_discovery(DummyDiscovery(&tmp))
+        .build()
+        .await
+        .unwrap();
+        engine.validate(&graph, 2, UIMode::Stream).expect("ok");
+
+        let (sender, mut receiver) = mpsc::channel(1);
+        let execution = tokio::spawn(engine.execute(ExecutionOptions::new(false, 2), sender));
+
+        let Message { info, callback } = receiver.recv().await.unwrap();
+        assert_eq!(info, dev_task_id);
+        let mut callback = Some(callback);
+        let mut output = Vec::new();
+        let mut dev_output = ReadyWriter::new(&mut output, "ready on", &mut callback);
+
+        dev_output.write_all(b"starting dev server\n").unwrap();
+        let not_ready = tokio::time::timeout(Duration::from_millis(50), receiver.recv()).await;
+        assert!(
+            not_ready.is_err(),
+            "dependent started before dev server was ready"
+        );
+
+        dev_output
+            .write_all(b"ready on http://localhost:3000\n")
+            .unwrap();
+        let Message { info, callback } = receiver.recv().await.unwrap();
+        assert_eq!(info, build_task_id);
+        callback.send(Ok(())).unwrap();
+
+        // The walk finishes while the dev server is still running
+        execution.await.unwrap().unwrap();
+        dev_output.write_all(b"GET / 200\n").unwrap();
+    }
+
+    // Packages in their own directories under `packages/`, each with a build
+    // script
+    struct NestedDiscovery<'a>(&'a TempDir, &'a [&'a str]);
+
+    impl<'a> PackageDiscovery for NestedDiscovery<'a> {
+        async fn discover_packages(
+            &self,
+        ) -> Result<
+            turborepo_repository::discovery::DiscoveryResponse,
+            turborepo_repository::discovery::Error,
+        > {
+            let root = AbsoluteSystemPath::from_std_path(self.0.path()).unwrap();
+            let workspaces = self
+                .1
+                .iter()
+                .map(|name| {
+                    let package_json = root.join_components(&["packages", name, "package.json"]);
+                    let package = PackageJson {
+                        name: Some(name.to_string()),
+                        scripts: [("build".to_string(), Spanned::new("echo built!".to_string()))]
+                            .into_iter()
+                            .collect(),
+                        ..Default::default()
+                    };
+                    package_json.ensure_dir().unwrap();
+                    package_json
+                        .create_with_contents(serde_json::to_string(&package).unwrap())
+                        .unwrap();
+
+                    WorkspaceData {
+                        package_json,
+                        turbo_json: None,
+                    }
+                })
+                .collect();
+
+            Ok(DiscoveryResponse {
+                package_manager: turborepo_repository::package_manager::PackageManager::Pnpm,
+                workspaces,
+            })
+        }
+
+        async fn discover_packages_blocking(
+            &self,
+        ) -> Result<
+            turborepo_repository::discovery::DiscoveryResponse,
+            turborepo_repository::discovery::Error,
+        > {
+            self.discover_packages().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_overlaps() {
+        let tmp = tempfile::TempDir::with_prefix("output_overlaps").unwrap();
+
+        let mut engine = Engine::new();
+        for (package, outputs) in [
+            ("a", vec!["../../dist/**"]),
+            ("b", vec!["../../dist/b/**", "lib/**"]),
+            ("c", vec!["dist/**"]),
+            ("d", vec!["../../*.log"]),
+            ("e", vec!["../../out/*.js"]),
+            ("f", vec!["../../out/*.css"]),
+            ("g", vec!["../../out/index.js"]),
+        ] {
+            let task_id = TaskId::new(package, "build");
+            engine.get_index(&task_id);
+            engine.add_definition(
+                task_id,
+                TaskDefinition {
+                    outputs: TaskOutputs {
+                        inclusions: outputs.into_iter().map(String::from).collect(),
+                        exclusions: vec![],
+                    },
+                    ..Default::default()
+                },
+            );
+        }
+        let engine = engine.seal();
+
+        let graph = PackageGraph::builder(
+            AbsoluteSystemPath::from_std_path(tmp.path()).unwrap(),
+            PackageJson::default(),
+        )
+        .with_package_discovery(NestedDiscovery(&tmp, &["a", "b", "c", "d", "e", "f", "g"]))
+        .build()
+        .await
+        .unwrap();
+
+        let overlaps = engine.output_overlaps(&graph);
+        assert_eq!(
+            overlaps,
+            vec![
+                OutputOverlap {
+                    first: TaskId::new("a", "build").into_owned(),
+                    second: TaskId::new("b", "build").into_owned(),
+                    path: "dist/b".to_string(),
+                },
+                // e and f share a directory but never write the same file
+                OutputOverlap {
+                    first: TaskId::new("e", "build").into_owned(),
+                    second: TaskId::new("g", "build").into_owned(),
+                    path: "out/index.js".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            overlaps[0].to_string(),
+            "a#build and b#build both have outputs in dist/b. Restoring one from the cache may \
+             overwrite files written by the other."
+        );
+    }
+
     #[tokio::test]
     async fn test_prune_persistent_tasks() {
         // Verifies that we can prune the `Engine` to include only the persistent tasks
@@ -0,0 +1,74 @@
+use std::hash::Hasher;
+
+use biome_deserialize_macros::Deserializable;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The function that turns the serialized inputs of a task into its hash.
+///
+/// Hashes are only comparable between runs that use the same algorithm, so
+/// its version is recorded in the run summary. Hashes of any version after
+/// the first are prefixed with it, this way changing the algorithm misses the
+/// existing caches instead of risking a collision with an artifact that was
+/// stored under a different algorithm.
+///
+/// It's set with `hashAlgorithm` in `turbo.json` or `TURBO_HASH_ALGORITHM`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Deserializable)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    #[default]
+    Xxh64,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    pub fn version(self) -> u32 {
+        match self {
+            HashAlgorithm::Xxh64 => 1,
+            HashAlgorithm::Sha256 => 2,
+        }
+    }
+
+    pub fn hash(self, buf: &[u8]) -> String {
+        match self {
+            // The first algorithm isn't prefixed so that existing caches stay valid
+            HashAlgorithm::Xxh64 => {
+                let mut hasher = twox_hash::XxHash64::with_seed(0);
+                hasher.write(buf);
+                hex::encode(hasher.finish().to_be_bytes())
+            }
+            HashAlgorithm::Sha256 => {
+                format!("v{}-{}", self.version(), hex::encode(Sha256::digest(buf)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::HashAlgorithm;
+
+    #[test_case(HashAlgorithm::Xxh64, "2daffa54ff185739" ; "xxh64")]
+    #[test_case(
+        HashAlgorithm::Sha256,
+        "v2-c3449cd99d19554da590a1d8a779f7c583577738f0bc8af1956d5f74bd51194a"
+        ; "sha256"
+    )]
+    fn test_hash_is_stable(algorithm: HashAlgorithm, expected: &str) {
+        assert_eq!(algorithm.hash(b"turbo"), expected);
+    }
+
+    #[test]
+    fn test_algorithms_differ() {
+        assert_ne!(
+            HashAlgorithm::Xxh64.hash(b"turbo"),
+            HashAlgorithm::Sha256.hash(b"turbo")
+        );
+        assert_ne!(
+            HashAlgorithm::Xxh64.version(),
+            HashAlgorithm::Sha256.version()
+        );
+    }
+}
@@ -4,10 +4,12 @@
 //! data-types. This is managed using capnproto for deterministic hashing across
 //! languages and platforms.
 
+mod algorithm;
 mod traits;
 
 use std::collections::HashMap;
 
+pub use algorithm::HashAlgorithm;
 use capnp::message::{Builder, HeapAllocator};
 pub use traits::TurboHash;
 use turborepo_env::EnvironmentVariablePairs;
@@ -23,7 +25,8 @@ mod proto_capnp {
     impl From<EnvMode> for global_hashable::EnvMode {
         fn from(value: EnvMode) -> Self {
             match value {
-                EnvMode::Loose => global_hashable::EnvMode::Loose,
+                // Warning doesn't change what's passed through, so it hashes like loose
+                EnvMode::Loose | EnvMode::LooseWarn => global_hashable::EnvMode::Loose,
                 EnvMode::Strict => global_hashable::EnvMode::Strict,
             }
         }
@@ -32,7 +35,7 @@ mod proto_capnp {
     impl From<EnvMode> for task_hashable::EnvMode {
         fn from(value: EnvMode) -> Self {
             match value {
-                EnvMode::Loose => task_hashable::EnvMode::Loose,
+                EnvMode::Loose | EnvMode::LooseWarn => task_hashable::EnvMode::Loose,
                 EnvMode::Strict => task_hashable::EnvMode::Strict,
             }
         }
@@ -52,6 +55,8 @@ pub struct TaskHashable<'a> {
     pub(crate) task: &'a str,
     pub(crate) outputs: TaskOutputs,
     pub(crate) pass_through_args: &'a [String],
+    // The package.json script that will be run for the task
+    pub(crate) command: Option<&'a str>,
 
     // env
     pub(crate) env: &'a [String],
@@ -208,6 +213,9 @@ impl From<TaskHashable<'_>> for Builder<HeapAllocator> {
         }
 
         builder.set_task(task_hashable.task);
+        if let Some(command) = task_hashable.command {
+            builder.set_command(command);
+        }
         builder.set_env_mode(task_hashable.env_mode.into());
 
         {
@@ -356,7 +364,7 @@ impl From<GlobalHashable<'_>> for Builder<HeapAllocator> {
         }
 
         builder.set_env_mode(match hashable.env_mode {
-            EnvMode::Loose => proto_capnp::global_hashable::EnvMode::Loose,
+            EnvMode::Loose | EnvMode::LooseWarn => proto_capnp::global_hashable::EnvMode::Loose,
             EnvMode::Strict => proto_capnp::global_hashable::EnvMode::Strict,
         });
 
@@ -403,6 +411,7 @@ mod test {
                 exclusions: vec!["exclusions".to_string()],
             },
             pass_through_args: &["pass_thru_args".to_string()],
+            command: None,
             env: &["env".to_string()],
             resolved_env_vars: vec![],
             pass_through_env: &["pass_thru_env".to_string()],
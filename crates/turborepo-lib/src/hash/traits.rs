@@ -1,11 +1,18 @@
-use std::hash::Hasher;
-
 use capnp::message::{Allocator, Builder};
 
+use super::HashAlgorithm;
+
 pub trait Sealed<A> {}
 
 pub trait TurboHash<A>: Sealed<A> {
-    fn hash(self) -> String;
+    fn hash(self) -> String
+    where
+        Self: Sized,
+    {
+        self.hash_with(HashAlgorithm::default())
+    }
+
+    fn hash_with(self, algorithm: HashAlgorithm) -> String;
 }
 
 impl<T, A> Sealed<A> for T
@@ -20,7 +27,7 @@ where
     T: Into<Builder<A>>,
     A: Allocator,
 {
-    fn hash(self) -> String {
+    fn hash_with(self, algorithm: HashAlgorithm) -> String {
         let message = self.into();
 
         debug_assert_eq!(
@@ -31,10 +38,6 @@ where
 
         let buf = message.get_segments_for_output()[0];
 
-        let mut hasher = twox_hash::XxHash64::with_seed(0);
-        hasher.write(buf);
-        let out = hasher.finish();
-
-        hex::encode(out.to_be_bytes())
+        algorithm.hash(buf)
     }
 }
@@ -36,15 +36,17 @@ mod task_graph;
 mod task_hash;
 mod tracing;
 mod turbo_json;
+mod turboignore;
 
 pub use crate::{
     child::spawn_child,
     cli::Args,
+    commands::run::execute_run,
     daemon::{
         DaemonClient, DaemonConnector, DaemonConnectorError, DaemonError, Paths as DaemonPaths,
     },
     panic_handler::panic_handler,
-    run::package_discovery::DaemonPackageDiscovery,
+    run::{package_discovery::DaemonPackageDiscovery, Error as RunError, RunResult, TaskOutcome},
 };
 
 pub fn get_version() -> &'static str {
@@ -56,8 +58,8 @@ pub fn get_version() -> &'static str {
         .trim_end()
 }
 
-pub fn main() -> Result<i32, shim::Error> {
-    shim::run()
+pub fn main() -> i32 {
+    shim::run().unwrap_or_else(|err| shim::report(err, &mut std::io::stderr()))
 }
 
 #[cfg(all(feature = "native-tls", feature = "rustls-tls"))]
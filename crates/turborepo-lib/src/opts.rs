@@ -1,10 +1,18 @@
-use std::{backtrace, backtrace::Backtrace};
+use std::{
+    backtrace,
+    backtrace::Backtrace,
+    collections::HashSet,
+    fs::File,
+    io::{self, Read},
+};
 
 use camino::Utf8PathBuf;
+use serde::{Serialize, Serializer};
 use thiserror::Error;
 use turbopath::AnchoredSystemPathBuf;
 use turborepo_api_client::APIAuth;
 use turborepo_cache::{CacheOpts, RemoteCacheOpts};
+use wax::Glob;
 
 use crate::{
     cli::{
@@ -12,7 +20,11 @@ use crate::{
     },
     commands::CommandBase,
     config::ConfigurationOptions,
-    run::task_id::TaskId,
+    hash::HashAlgorithm,
+    run::{
+        scope::{read_changed_files, ChangedFilesError},
+        task_id::TaskId,
+    },
     turbo_json::UIMode,
 };
 
@@ -36,9 +48,20 @@ pub enum Error {
     Path(#[from] turbopath::PathError),
     #[error(transparent)]
     Config(#[from] crate::config::Error),
+    #[error("unable to open changed files list {0}: {1}")]
+    ChangedFilesSource(String, #[source] io::Error),
+    #[error(transparent)]
+    ChangedFiles(#[from] ChangedFilesError),
+    #[error("invalid task glob for --force {glob}: {source}")]
+    ForceGlob {
+        glob: String,
+        #[source]
+        source: Box<wax::BuildError>,
+    },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Opts {
     pub cache_opts: CacheOpts,
     pub run_opts: RunOpts,
@@ -66,6 +89,10 @@ impl Opts {
             cmd.push_str(" --continue");
         }
 
+        if let Some(max_failures) = self.run_opts.max_failures {
+            cmd.push_str(&format!(" --max-failures={max_failures}"));
+        }
+
         if let Some(dry) = self.run_opts.dry_run {
             match dry {
                 DryRunMode::Json => cmd.push_str(" --dry=json"),
@@ -107,9 +134,9 @@ impl Opts {
             api_auth: &api_auth,
         };
         let run_opts = RunOpts::try_from(run_and_execution_args)?;
-        let cache_opts = CacheOpts::from(run_and_execution_args);
+        let cache_opts = CacheOpts::try_from(run_and_execution_args)?;
         let scope_opts = ScopeOpts::try_from(run_and_execution_args)?;
-        let runcache_opts = RunCacheOpts::from(run_and_execution_args);
+        let runcache_opts = RunCacheOpts::try_from(run_and_execution_args)?;
 
         Ok(Self {
             run_opts,
@@ -128,27 +155,60 @@ struct OptsInputs<'a> {
     api_auth: &'a Option<APIAuth>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct RunCacheOpts {
     pub(crate) skip_reads: bool,
+    // Tasks matching this glob skip reads even if `skip_reads` isn't set
+    #[serde(serialize_with = "serialize_glob")]
+    pub(crate) force_tasks: Option<Glob<'static>>,
     pub(crate) skip_writes: bool,
     pub(crate) task_output_logs_override: Option<OutputLogsMode>,
+    pub(crate) strip_log_colors: bool,
+}
+
+fn serialize_glob<S: Serializer>(
+    glob: &Option<Glob<'static>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    glob.as_ref()
+        .map(|glob| glob.to_string())
+        .serialize(serializer)
 }
 
-impl<'a> From<OptsInputs<'a>> for RunCacheOpts {
-    fn from(inputs: OptsInputs<'a>) -> Self {
-        RunCacheOpts {
+impl<'a> TryFrom<OptsInputs<'a>> for RunCacheOpts {
+    type Error = self::Error;
+
+    fn try_from(inputs: OptsInputs<'a>) -> Result<Self, Self::Error> {
+        let force_tasks = inputs
+            .execution_args
+            .force_tasks()
+            .map(|glob| {
+                wax::cached_glob(glob).map_err(|source| Error::ForceGlob {
+                    glob: glob.to_string(),
+                    source: Box::new(source),
+                })
+            })
+            .transpose()?;
+
+        Ok(RunCacheOpts {
             skip_reads: inputs.config.force(),
+            force_tasks,
             skip_writes: inputs.run_args.no_cache,
             task_output_logs_override: inputs.execution_args.output_logs,
-        }
+            strip_log_colors: inputs.execution_args.no_color_logs,
+        })
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct RunOpts {
     pub(crate) tasks: Vec<String>,
     pub(crate) concurrency: u32,
+    // Number of threads used to hash files, defaults to rayon's global pool
+    pub(crate) hash_workers: Option<usize>,
+    pub(crate) hash_algorithm: HashAlgorithm,
     pub(crate) parallel: bool,
     pub(crate) env_mode: EnvMode,
     pub(crate) cache_dir: Utf8PathBuf,
@@ -156,6 +216,10 @@ pub struct RunOpts {
     pub(crate) framework_inference: bool,
     pub profile: Option<String>,
     pub(crate) continue_on_error: bool,
+    // Number of failed tasks after which the run stops, even with `--continue`
+    pub(crate) max_failures: Option<usize>,
+    // Whether persistent tasks are restarted when their inputs change
+    pub(crate) restart_on_change: bool,
     pub(crate) pass_through_args: Vec<String>,
     pub(crate) only: bool,
     pub(crate) dry_run: Option<DryRunMode>,
@@ -165,7 +229,15 @@ pub struct RunOpts {
     pub log_prefix: ResolvedLogPrefix,
     pub log_order: ResolvedLogOrder,
     pub summarize: bool,
+    // Only print the hashes of the run instead of running tasks
+    pub(crate) summarize_only: bool,
+    // Print these options instead of running tasks
+    #[serde(skip)]
+    pub(crate) print_opts: bool,
+    // File to write a CSV of task timings to
+    pub(crate) csv_output: Option<Utf8PathBuf>,
     pub(crate) experimental_space_id: Option<String>,
+    pub(crate) anonymize_spaces: bool,
     pub is_github_actions: bool,
     pub ui_mode: UIMode,
 }
@@ -185,19 +257,22 @@ impl RunOpts {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub enum GraphOpts {
     Stdout,
     File(String),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ResolvedLogOrder {
     Stream,
     Grouped,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ResolvedLogPrefix {
     Task,
     None,
@@ -250,16 +325,28 @@ impl<'a> TryFrom<OptsInputs<'a>> for RunOpts {
             log_prefix,
             log_order,
             summarize: inputs.config.run_summary(),
+            summarize_only: inputs.run_args.summarize_only,
+            print_opts: inputs.run_args.print_opts,
+            csv_output: inputs.run_args.csv_output.clone(),
             experimental_space_id: inputs
                 .run_args
                 .experimental_space_id
                 .clone()
                 .or(inputs.config.spaces_id().map(|s| s.to_owned())),
+            anonymize_spaces: inputs.run_args.anonymize_spaces,
             framework_inference: inputs.execution_args.framework_inference,
             concurrency,
+            hash_workers: inputs.run_args.hash_workers.map(|workers| workers as usize),
             parallel: inputs.run_args.parallel,
             profile: inputs.run_args.profile.clone(),
-            continue_on_error: inputs.execution_args.continue_execution,
+            // Stopping after a number of failures only makes sense if we continue until then
+            continue_on_error: inputs.execution_args.continue_execution
+                || inputs.execution_args.max_failures.is_some(),
+            max_failures: inputs
+                .execution_args
+                .max_failures
+                .map(|max_failures| max_failures as usize),
+            restart_on_change: inputs.run_args.restart_on_change,
             pass_through_args: inputs.execution_args.pass_through_args.clone(),
             only: inputs.execution_args.only,
             daemon: inputs.config.daemon(),
@@ -267,6 +354,7 @@ impl<'a> TryFrom<OptsInputs<'a>> for RunOpts {
             graph,
             dry_run: inputs.run_args.dry_run,
             env_mode: inputs.config.env_mode(),
+            hash_algorithm: inputs.config.hash_algorithm(),
             cache_dir: inputs.config.cache_dir().into(),
             is_github_actions,
             ui_mode: inputs.config.ui(),
@@ -305,12 +393,14 @@ impl From<LogPrefix> for ResolvedLogPrefix {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ScopeOpts {
     pub pkg_inference_root: Option<AnchoredSystemPathBuf>,
     pub global_deps: Vec<String>,
     pub filter_patterns: Vec<String>,
     pub affected_range: Option<(Option<String>, Option<String>)>,
+    pub changed_files: Option<HashSet<AnchoredSystemPathBuf>>,
 }
 
 impl<'a> TryFrom<OptsInputs<'a>> for ScopeOpts {
@@ -333,18 +423,40 @@ impl<'a> TryFrom<OptsInputs<'a>> for ScopeOpts {
             )
         });
 
+        let changed_files = inputs
+            .execution_args
+            .changed_files_from
+            .as_deref()
+            .map(|source| {
+                let reader: Box<dyn Read> = if source == "-" {
+                    Box::new(io::stdin())
+                } else {
+                    Box::new(
+                        File::open(source)
+                            .map_err(|e| Error::ChangedFilesSource(source.to_string(), e))?,
+                    )
+                };
+                Ok::<_, Error>(read_changed_files(reader)?)
+            })
+            .transpose()?;
+
         Ok(Self {
             global_deps: inputs.execution_args.global_deps.clone(),
             pkg_inference_root,
             affected_range,
             filter_patterns: inputs.execution_args.filter.clone(),
+            changed_files,
         })
     }
 }
 
-impl<'a> From<OptsInputs<'a>> for CacheOpts {
-    fn from(inputs: OptsInputs<'a>) -> Self {
-        let is_linked = turborepo_api_client::is_linked(inputs.api_auth);
+impl<'a> TryFrom<OptsInputs<'a>> for CacheOpts {
+    type Error = self::Error;
+
+    fn try_from(inputs: OptsInputs<'a>) -> Result<Self, Self::Error> {
+        let s3 = inputs.config.s3()?;
+        // An S3 bucket doesn't need to be linked to Vercel
+        let is_linked = turborepo_api_client::is_linked(inputs.api_auth) || s3.is_some();
         let skip_remote = if !is_linked {
             true
         } else if let Some(enabled) = inputs.config.enabled {
@@ -366,14 +478,17 @@ impl<'a> From<OptsInputs<'a>> for CacheOpts {
             signature,
         ));
 
-        CacheOpts {
+        Ok(CacheOpts {
             cache_dir: inputs.config.cache_dir().into(),
+            cache_read_dirs: inputs.config.cache_read_dirs().to_vec(),
+            cache_max_size: inputs.config.cache_max_size(),
             skip_filesystem: inputs.config.remote_only(),
             remote_cache_read_only: inputs.config.remote_cache_read_only(),
             workers: inputs.run_args.cache_workers,
             skip_remote,
             remote_cache_opts,
-        }
+            s3,
+        })
     }
 }
 
@@ -389,16 +504,29 @@ impl ScopeOpts {
     pub fn get_filters(&self) -> Vec<String> {
         self.filter_patterns.clone()
     }
+
+    /// An explicit list of changed files stands in for a git range, so it is
+    /// treated as `--affected` with the range left unspecified.
+    pub fn get_affected_range(&self) -> Option<(Option<String>, Option<String>)> {
+        match self.changed_files {
+            Some(_) => Some((None, None)),
+            None => self.affected_range.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use clap::Parser;
     use test_case::test_case;
+    use turbopath::AbsoluteSystemPathBuf;
     use turborepo_cache::CacheOpts;
+    use turborepo_ui::ColorConfig;
 
     use super::RunOpts;
     use crate::{
-        cli::DryRunMode,
+        cli::{Args, DryRunMode},
+        commands::CommandBase,
         opts::{Opts, RunCacheOpts, ScopeOpts},
         turbo_json::UIMode,
     };
@@ -411,6 +539,7 @@ mod test {
         pass_through_args: Vec<String>,
         parallel: bool,
         continue_on_error: bool,
+        max_failures: Option<usize>,
         dry_run: Option<DryRunMode>,
         affected: Option<(String, String)>,
     }
@@ -457,6 +586,15 @@ mod test {
         },
         "turbo run build --filter=my-app --parallel --continue"
     )]
+    #[test_case    (
+        TestCaseOpts {
+            tasks: vec!["build".to_string()],
+            continue_on_error: true,
+            max_failures: Some(2),
+            ..Default::default()
+        },
+        "turbo run build --continue --max-failures=2"
+    )]
     #[test_case    (
         TestCaseOpts {
             filter_patterns: vec!["my-app".to_string()],
@@ -496,12 +634,16 @@ mod test {
         let run_opts = RunOpts {
             tasks: opts_input.tasks,
             concurrency: 10,
+            hash_workers: None,
+            hash_algorithm: crate::hash::HashAlgorithm::default(),
             parallel: opts_input.parallel,
             env_mode: crate::cli::EnvMode::Loose,
             cache_dir: camino::Utf8PathBuf::new(),
             framework_inference: true,
             profile: None,
             continue_on_error: opts_input.continue_on_error,
+            max_failures: opts_input.max_failures,
+            restart_on_change: false,
             pass_through_args: opts_input.pass_through_args,
             only: opts_input.only,
             dry_run: opts_input.dry_run,
@@ -511,7 +653,11 @@ mod test {
             log_prefix: crate::opts::ResolvedLogPrefix::Task,
             log_order: crate::opts::ResolvedLogOrder::Stream,
             summarize: false,
+            summarize_only: false,
+            print_opts: false,
+            csv_output: None,
             experimental_space_id: None,
+            anonymize_spaces: false,
             is_github_actions: false,
             daemon: None,
         };
@@ -524,6 +670,7 @@ mod test {
             affected_range: opts_input
                 .affected
                 .map(|(base, head)| (Some(base), Some(head))),
+            changed_files: None,
         };
         let opts = Opts {
             run_opts,
@@ -534,4 +681,27 @@ mod test {
         let synthesized = opts.synthesize_command();
         assert_eq!(synthesized, expected);
     }
+
+    #[test]
+    fn test_serialized_opts_reflect_cli_overrides() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let user_config_dir = tempfile::tempdir().unwrap();
+        let repo_root = AbsoluteSystemPathBuf::try_from(repo_dir.path()).unwrap();
+        let user_config_path =
+            AbsoluteSystemPathBuf::try_from(user_config_dir.path().join("config.json")).unwrap();
+
+        let args =
+            Args::try_parse_from(["turbo", "run", "build", "--env-mode=loose", "--print-opts"])
+                .unwrap();
+        let base = CommandBase::new(args, repo_root, "", ColorConfig::new(false))
+            .with_override_global_config_path(user_config_path);
+        let opts = Opts::new(&base).unwrap();
+        assert!(opts.run_opts.print_opts);
+
+        let serialized = serde_json::to_value(&opts).unwrap();
+        // The config default is strict
+        assert_eq!(serialized["runOpts"]["envMode"], "loose");
+        assert_eq!(serialized["runOpts"]["tasks"], serde_json::json!(["build"]));
+        assert!(serialized["runOpts"].get("printOpts").is_none());
+    }
 }
@@ -33,7 +33,10 @@ use tokio::{
 };
 use tracing::{debug, trace};
 
-use super::{Command, PtySize};
+use super::{
+    memory::{MemorySampler, PeakMemory},
+    Command, PtySize,
+};
 
 #[derive(Debug)]
 pub enum ChildState {
@@ -401,6 +404,7 @@ pub struct Child {
     stdin: Arc<Mutex<Option<ChildInput>>>,
     output: Arc<Mutex<Option<ChildOutput>>>,
     label: String,
+    peak_memory: PeakMemory,
 }
 
 #[derive(Clone, Debug)]
@@ -490,6 +494,7 @@ impl Child {
             stdin: Arc::new(Mutex::new(stdin)),
             output: Arc::new(Mutex::new(output)),
             label,
+            peak_memory: PeakMemory::default(),
         })
     }
 
@@ -562,10 +567,24 @@ impl Child {
         code
     }
 
-    fn pid(&self) -> Option<u32> {
+    pub fn pid(&self) -> Option<u32> {
         self.pid
     }
 
+    /// The most memory the process and its descendants were seen using, in
+    /// bytes. Memory is sampled periodically so short spikes can be missed,
+    /// and it's `None` unless the process is tracked by a sampler.
+    pub fn peak_memory(&self) -> Option<u64> {
+        self.peak_memory.get()
+    }
+
+    /// Starts sampling the memory of the process with `sampler`.
+    pub(super) fn track_memory(&mut self, sampler: &MemorySampler) {
+        if let Some(pid) = self.pid {
+            self.peak_memory = sampler.track(pid, self.exit_channel.clone());
+        }
+    }
+
     fn stdin_inner(&mut self) -> Option<ChildInput> {
         self.stdin.lock().unwrap().take()
     }
@@ -818,7 +837,7 @@ impl ChildStateManager {
 
 #[cfg(test)]
 mod test {
-    use std::{assert_matches::assert_matches, time::Duration};
+    use std::{assert_matches, time::Duration};
 
     use futures::{stream::FuturesUnordered, StreamExt};
     use test_case::test_case;
@@ -829,6 +848,7 @@ mod test {
     use super::{Child, ChildInput, ChildOutput, ChildState, Command};
     use crate::process::{
         child::{ChildExit, ShutdownStyle},
+        memory::MemorySampler,
         PtySize,
     };
 
@@ -863,6 +883,55 @@ mod test {
         assert_matches!(&*state, ChildState::Exited(ChildExit::Killed));
     }
 
+    // Memory is only sampled on platforms that sysinfo supports
+    #[cfg(any(target_os = "linux", target_os = "macos", windows))]
+    #[tokio::test]
+    async fn test_peak_memory() {
+        let mut cmd = Command::new("node");
+        cmd.args([
+            "-e",
+            "const buffer = Buffer.alloc(64 * 1024 * 1024, 1); setTimeout(() => buffer, 1500);",
+        ]);
+        // Both children are sampled by the same sampler
+        let sampler = MemorySampler::default();
+        let mut children = Vec::new();
+        for _ in 0..2 {
+            let mut child = Child::spawn(cmd.clone(), ShutdownStyle::Kill, None).unwrap();
+            child.track_memory(&sampler);
+            children.push(child);
+        }
+        let mut untracked = Child::spawn(cmd, ShutdownStyle::Kill, None).unwrap();
+
+        for child in &mut children {
+            assert_matches!(child.wait().await, Some(ChildExit::Finished(Some(0))));
+            let peak_memory = child.peak_memory().expect("memory should be sampled");
+            assert!(peak_memory > 64 * 1024 * 1024, "peak was {peak_memory}");
+        }
+        assert_matches!(untracked.wait().await, Some(ChildExit::Finished(Some(0))));
+        assert_eq!(untracked.peak_memory(), None);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos", windows))]
+    #[tokio::test]
+    async fn test_peak_memory_of_short_child() {
+        let sampler = MemorySampler::default();
+        let mut long = Command::new("node");
+        long.args(["-e", "setTimeout(() => {}, 1500);"]);
+        let mut long = Child::spawn(long, ShutdownStyle::Kill, None).unwrap();
+        long.track_memory(&sampler);
+
+        // Start tracking while the sampler is waiting for its next sample
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let mut short = Command::new("node");
+        short.args(["-e", "setTimeout(() => {}, 200);"]);
+        let mut short = Child::spawn(short, ShutdownStyle::Kill, None).unwrap();
+        short.track_memory(&sampler);
+
+        assert_matches!(short.wait().await, Some(ChildExit::Finished(Some(0))));
+        assert_matches!(short.peak_memory(), Some(_));
+        long.stop().await;
+    }
+
     #[test_case(false)]
     #[test_case(TEST_PTY)]
     #[tracing_test::traced_test]
@@ -0,0 +1,147 @@
+//! `memory`
+//!
+//! Samples the memory used by child processes while they run so that the
+//! peak can be reported once they exit. Package managers run scripts in
+//! processes of their own, so the memory of every descendant of a child is
+//! counted.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use sysinfo::{Pid, PidExt, ProcessExt, ProcessRefreshKind, System, SystemExt};
+use tokio::sync::watch;
+
+use super::ChildExit;
+
+// Refreshing reads every process on the system, sampling more often than this
+// takes a noticeable amount of CPU away from the tasks
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Samples the memory of every tracked process from a single thread. The
+/// thread only runs while there are processes left to sample.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySampler {
+    state: Arc<Mutex<SamplerState>>,
+    tracked_changed: Arc<Condvar>,
+}
+
+#[derive(Debug, Default)]
+struct SamplerState {
+    tracked: Vec<Tracked>,
+    is_sampling: bool,
+    // Set when a process was tracked after the last sample was taken
+    has_unsampled: bool,
+}
+
+#[derive(Debug)]
+struct Tracked {
+    pid: Pid,
+    peak: PeakMemory,
+    exit: watch::Receiver<Option<ChildExit>>,
+}
+
+impl MemorySampler {
+    /// Samples the memory of the process tree rooted at `pid` until `exit`
+    /// reports that the process is done.
+    pub fn track(&self, pid: u32, exit: watch::Receiver<Option<ChildExit>>) -> PeakMemory {
+        let peak = PeakMemory::default();
+        if !System::IS_SUPPORTED {
+            return peak;
+        }
+
+        let mut state = self.state.lock().expect("memory sampler mutex poisoned");
+        state.tracked.push(Tracked {
+            pid: Pid::from_u32(pid),
+            peak: peak.clone(),
+            exit,
+        });
+        state.has_unsampled = true;
+        self.tracked_changed.notify_one();
+        if !state.is_sampling {
+            state.is_sampling = true;
+            let sampler = self.clone();
+            thread::spawn(move || sampler.sample());
+        }
+        peak
+    }
+
+    fn sample(&self) {
+        let mut system = System::new();
+        loop {
+            let tracked = {
+                let mut state = self.state.lock().expect("memory sampler mutex poisoned");
+                // Processes are done once the exit is sent or the channel is closed
+                state
+                    .tracked
+                    .retain(|tracked| matches!(tracked.exit.has_changed(), Ok(false)));
+                if state.tracked.is_empty() {
+                    state.is_sampling = false;
+                    return;
+                }
+                state.has_unsampled = false;
+                state
+                    .tracked
+                    .iter()
+                    .map(|tracked| (tracked.pid, tracked.peak.clone()))
+                    .collect::<Vec<_>>()
+            };
+
+            system.refresh_processes_specifics(ProcessRefreshKind::new());
+            for (pid, peak) in tracked {
+                peak.0
+                    .fetch_max(tree_memory(&system, pid), Ordering::Relaxed);
+            }
+
+            // Newly tracked processes are sampled right away, a short-lived one
+            // could otherwise exit before the next sample
+            let state = self.state.lock().expect("memory sampler mutex poisoned");
+            drop(
+                self.tracked_changed
+                    .wait_timeout_while(state, SAMPLE_INTERVAL, |state| !state.has_unsampled)
+                    .expect("memory sampler mutex poisoned"),
+            );
+        }
+    }
+}
+
+/// The peak resident set size of a child process and its descendants.
+#[derive(Debug, Clone, Default)]
+pub struct PeakMemory(Arc<AtomicU64>);
+
+impl PeakMemory {
+    /// The highest memory usage seen in bytes, `None` if memory couldn't be
+    /// sampled on this platform.
+    pub fn get(&self) -> Option<u64> {
+        match self.0.load(Ordering::Relaxed) {
+            0 => None,
+            peak => Some(peak),
+        }
+    }
+}
+
+fn tree_memory(system: &System, root: Pid) -> u64 {
+    let processes = system.processes();
+    let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    for (pid, process) in processes {
+        if let Some(parent) = process.parent() {
+            children.entry(parent).or_default().push(*pid);
+        }
+    }
+
+    let mut memory = 0;
+    let mut pending = vec![root];
+    while let Some(pid) = pending.pop() {
+        if let Some(process) = processes.get(&pid) {
+            memory += process.memory();
+        }
+        pending.extend(children.get(&pid).into_iter().flatten());
+    }
+    memory
+}
@@ -11,6 +11,7 @@
 
 mod child;
 mod command;
+mod memory;
 
 use std::{
     io,
@@ -20,6 +21,7 @@ use std::{
 
 pub use command::Command;
 use futures::Future;
+use memory::MemorySampler;
 use tokio::task::JoinSet;
 use tracing::{debug, trace};
 
@@ -33,6 +35,8 @@ pub use self::child::{Child, ChildExit};
 pub struct ProcessManager {
     state: Arc<Mutex<ProcessManagerInner>>,
     use_pty: bool,
+    // Only set if the memory of children is reported
+    memory_sampler: Option<MemorySampler>,
 }
 
 #[derive(Debug)]
@@ -58,9 +62,18 @@ impl ProcessManager {
                 size: None,
             })),
             use_pty,
+            memory_sampler: None,
         }
     }
 
+    /// Samples the memory of every child so that its peak can be reported.
+    /// Sampling reads every process on the system, so this is only done if
+    /// the peaks are used.
+    pub fn with_memory_sampling(mut self) -> Self {
+        self.memory_sampler = Some(MemorySampler::default());
+        self
+    }
+
     /// Construct a process manager and infer if pty should be used
     pub fn infer() -> Self {
         // Only use PTY if we're not on windows and we're currently hooked up to a
@@ -108,12 +121,15 @@ impl ProcessManager {
             return None;
         }
         let pty_size = self.use_pty.then(|| lock.pty_size()).flatten();
-        let child = child::Child::spawn(
+        let mut child = child::Child::spawn(
             command,
             child::ShutdownStyle::Graceful(stop_timeout),
             pty_size,
         );
-        if let Ok(child) = &child {
+        if let Ok(child) = &mut child {
+            if let Some(memory_sampler) = &self.memory_sampler {
+                child.track_memory(memory_sampler);
+            }
             lock.children.push(child.clone());
         }
         trace!("releasing lock for spawning {label}");
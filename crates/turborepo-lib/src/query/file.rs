@@ -1,20 +1,77 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+};
 
 use async_graphql::Object;
+use globwalk::{ValidatedGlob, WalkType};
 use itertools::Itertools;
 use turbo_trace::Tracer;
-use turbopath::AbsoluteSystemPathBuf;
+use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
 
 use crate::{query::Error, run::Run};
 
+// Files that are traced to find out which files import a given file
+const SOURCE_FILES: &str = "**/*.{js,jsx,mjs,cjs,ts,tsx,mts,cts}";
+const IGNORED_SOURCE_FILES: &str = "**/node_modules/**";
+
+/// Maps files to the files that import them. Building it requires tracing
+/// every source file in the repository, so it's built on first use and shared
+/// between all files of a query.
+#[derive(Default)]
+pub struct DependentsIndex(OnceLock<HashMap<AbsoluteSystemPathBuf, Vec<AbsoluteSystemPathBuf>>>);
+
+impl DependentsIndex {
+    fn get(
+        &self,
+        repo_root: &AbsoluteSystemPath,
+    ) -> Result<&HashMap<AbsoluteSystemPathBuf, Vec<AbsoluteSystemPathBuf>>, Error> {
+        self.0.get_or_try_init(|| {
+            let source_files = globwalk::globwalk(
+                repo_root,
+                &[ValidatedGlob::from_str(SOURCE_FILES)?],
+                &[ValidatedGlob::from_str(IGNORED_SOURCE_FILES)?],
+                WalkType::Files,
+            )?;
+            let tracer = Tracer::new(
+                repo_root.to_owned(),
+                source_files.into_iter().collect(),
+                None,
+            )?;
+
+            // Errors are ignored here, a file that can't be parsed or has an
+            // unresolvable import shouldn't keep us from finding the dependents
+            // of every other file
+            let mut dependents: HashMap<_, Vec<_>> = HashMap::new();
+            for (file, imports) in tracer.trace().imports {
+                for import in imports {
+                    dependents.entry(import).or_default().push(file.clone());
+                }
+            }
+
+            Ok(dependents)
+        })
+    }
+}
+
 pub struct File {
     run: Arc<Run>,
+    dependents_index: Arc<DependentsIndex>,
     path: AbsoluteSystemPathBuf,
 }
 
 impl File {
-    pub fn new(run: Arc<Run>, path: AbsoluteSystemPathBuf) -> Self {
-        Self { run, path }
+    pub fn new(
+        run: Arc<Run>,
+        dependents_index: Arc<DependentsIndex>,
+        path: AbsoluteSystemPathBuf,
+    ) -> Self {
+        Self {
+            run,
+            dependents_index,
+            path,
+        }
     }
 }
 
@@ -54,7 +111,26 @@ impl File {
             .into_iter()
             // Filter out the file we're looking at
             .filter(|file| file != &self.path)
-            .map(|path| File::new(self.run.clone(), path))
+            .map(|path| File::new(self.run.clone(), self.dependents_index.clone(), path))
+            .sorted_by(|a, b| a.path.cmp(&b.path))
+            .collect())
+    }
+
+    /// The files that import this file directly
+    async fn dependents(&self) -> Result<Vec<File>, Error> {
+        let dependents = self.dependents_index.get(self.run.repo_root())?;
+
+        Ok(dependents
+            .get(&self.path)
+            .into_iter()
+            .flatten()
+            .map(|path| {
+                File::new(
+                    self.run.clone(),
+                    self.dependents_index.clone(),
+                    path.clone(),
+                )
+            })
             .sorted_by(|a, b| a.path.cmp(&b.path))
             .collect())
     }
@@ -19,7 +19,10 @@ use turborepo_repository::{change_mapper::AllPackageChangeReason, package_graph:
 
 use crate::{
     get_version,
-    query::{file::File, task::RepositoryTask},
+    query::{
+        file::{DependentsIndex, File},
+        task::RepositoryTask,
+    },
     run::{builder::RunBuilder, Run},
     signal::SignalHandler,
 };
@@ -32,8 +35,14 @@ pub enum Error {
     NoSignalHandler,
     #[error("file `{0}` not found")]
     FileNotFound(String),
+    #[error("failed to find source files: {0}")]
+    Walk(#[from] globwalk::WalkError),
+    #[error("invalid glob: {0}")]
+    Glob(#[from] globwalk::GlobError),
     #[error("failed to start GraphQL server")]
     Server(#[from] io::Error),
+    #[error("cursor `{0}` does not match any item")]
+    InvalidCursor(String),
     #[error("package not found: {0}")]
     PackageNotFound(PackageName),
     #[error("failed to serialize result: {0}")]
@@ -53,11 +62,15 @@ pub enum Error {
 
 pub struct RepositoryQuery {
     run: Arc<Run>,
+    dependents_index: Arc<DependentsIndex>,
 }
 
 impl RepositoryQuery {
     pub fn new(run: Arc<Run>) -> Self {
-        Self { run }
+        Self {
+            run,
+            dependents_index: Arc::default(),
+        }
     }
 }
 
@@ -67,14 +80,63 @@ impl RepositoryQuery {
 #[graphql(concrete(name = "ChangedPackages", params(ChangedPackage)))]
 pub struct Array<T: OutputType> {
     items: Vec<T>,
+    /// Number of items across all pages
     length: usize,
+    /// Number of items in this page
+    page_length: usize,
+    /// Cursor of the last item, pass it as `after` to get the next page
+    end_cursor: Option<String>,
+    has_next_page: bool,
+}
+
+impl<T: OutputType> Array<T> {
+    /// Returns at most `first` of the sorted `items` that come after the item
+    /// with the `after` cursor.
+    fn paginate(
+        items: Vec<T>,
+        cursor: impl Fn(&T) -> String,
+        first: Option<usize>,
+        after: Option<String>,
+    ) -> Result<Self, Error> {
+        let start = match after {
+            Some(after) => {
+                items
+                    .iter()
+                    .position(|item| cursor(item) == after)
+                    .ok_or(Error::InvalidCursor(after))?
+                    + 1
+            }
+            None => 0,
+        };
+        let end = first.map_or(items.len(), |first| {
+            start.saturating_add(first).min(items.len())
+        });
+        let has_next_page = end < items.len();
+        let length = items.len();
+
+        let items: Vec<_> = items.into_iter().take(end).skip(start).collect();
+        let end_cursor = items.last().map(&cursor);
+        Ok(Self {
+            length,
+            page_length: items.len(),
+            items,
+            end_cursor,
+            has_next_page,
+        })
+    }
 }
 
 impl<T: OutputType> FromIterator<T> for Array<T> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let items: Vec<_> = iter.into_iter().collect();
         let length = items.len();
-        Self { items, length }
+        Self {
+            items,
+            length,
+            page_length: length,
+            end_cursor: None,
+            has_next_page: false,
+        }
     }
 }
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
@@ -483,11 +545,13 @@ impl RepositoryQuery {
         base: Option<String>,
         head: Option<String>,
         filter: Option<PackagePredicate>,
+        first: Option<usize>,
+        after: Option<String>,
     ) -> Result<Array<ChangedPackage>, Error> {
         let mut opts = self.run.opts().clone();
         opts.scope_opts.affected_range = Some((base, head));
 
-        Ok(RunBuilder::calculate_filtered_packages(
+        let packages: Vec<_> = RunBuilder::calculate_filtered_packages(
             self.run.repo_root(),
             &opts,
             self.run.pkg_dep_graph(),
@@ -504,7 +568,14 @@ impl RepositoryQuery {
         })
         .filter(|package| filter.as_ref().map_or(true, |f| f.check(&package.package)))
         .sorted_by(|a, b| a.package.name.cmp(&b.package.name))
-        .collect())
+        .collect();
+
+        Array::paginate(
+            packages,
+            |package| package.package.name.to_string(),
+            first,
+            after,
+        )
     }
     /// Gets a single package by name
     async fn package(&self, name: String) -> Result<Package, Error> {
@@ -526,25 +597,22 @@ impl RepositoryQuery {
             return Err(Error::FileNotFound(abs_path.to_string()));
         }
 
-        Ok(File::new(self.run.clone(), abs_path))
+        Ok(File::new(
+            self.run.clone(),
+            self.dependents_index.clone(),
+            abs_path,
+        ))
     }
 
-    /// Gets a list of packages that match the given filter
-    async fn packages(&self, filter: Option<PackagePredicate>) -> Result<Array<Package>, Error> {
-        let Some(filter) = filter else {
-            return Ok(self
-                .run
-                .pkg_dep_graph()
-                .packages()
-                .map(|(name, _)| Package {
-                    run: self.run.clone(),
-                    name: name.clone(),
-                })
-                .sorted_by(|a, b| a.name.cmp(&b.name))
-                .collect());
-        };
-
-        Ok(self
+    /// Gets a list of packages that match the given filter. Use `first` and
+    /// `after` to page through the packages.
+    async fn packages(
+        &self,
+        filter: Option<PackagePredicate>,
+        first: Option<usize>,
+        after: Option<String>,
+    ) -> Result<Array<Package>, Error> {
+        let packages: Vec<_> = self
             .run
             .pkg_dep_graph()
             .packages()
@@ -552,9 +620,11 @@ impl RepositoryQuery {
                 run: self.run.clone(),
                 name: name.clone(),
             })
-            .filter(|pkg| filter.check(pkg))
+            .filter(|pkg| filter.as_ref().map_or(true, |f| f.check(pkg)))
             .sorted_by(|a, b| a.name.cmp(&b.name))
-            .collect())
+            .collect();
+
+        Array::paginate(packages, |package| package.name.to_string(), first, after)
     }
 }
 
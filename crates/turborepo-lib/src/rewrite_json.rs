@@ -23,7 +23,6 @@ enum GenerateType {
 struct Range {
     pub start: usize,
     pub end: usize,
-    pub replacement_char: String,
 }
 
 /**
@@ -51,13 +50,13 @@ pub fn set_path(
     let (closest_path, closest_node) = get_closest_node(&root, path, current_path);
 
     // Pull the token metadata off of the token.
-    let (property_count, range): (usize, jsonc_parser::common::Range) = match closest_node {
-        jsonc_parser::ast::Value::Object(literal) => (literal.properties.len(), literal.range),
-        jsonc_parser::ast::Value::StringLit(literal) => (0, literal.range),
-        jsonc_parser::ast::Value::NumberLit(literal) => (0, literal.range),
-        jsonc_parser::ast::Value::BooleanLit(literal) => (0, literal.range),
-        jsonc_parser::ast::Value::Array(literal) => (0, literal.range),
-        jsonc_parser::ast::Value::NullKeyword(literal) => (0, literal.range),
+    let range: jsonc_parser::common::Range = match closest_node {
+        jsonc_parser::ast::Value::Object(literal) => literal.range,
+        jsonc_parser::ast::Value::StringLit(literal) => literal.range,
+        jsonc_parser::ast::Value::NumberLit(literal) => literal.range,
+        jsonc_parser::ast::Value::BooleanLit(literal) => literal.range,
+        jsonc_parser::ast::Value::Array(literal) => literal.range,
+        jsonc_parser::ast::Value::NullKeyword(literal) => literal.range,
     };
 
     // Figure out what we should be generating:
@@ -69,21 +68,33 @@ pub fn set_path(
         GenerateType::Member
     };
 
-    // Identify the token replacement metadata: start, end, and possible trailing
-    // join character
-    let (start, end, separator) = match generate_type {
-        GenerateType::Object => {
-            let start = range.start;
-            let end = range.end;
-            let separator = "";
-            (start, end, separator)
-        }
-        GenerateType::Member => {
-            let start = range.start + 1;
-            let end = range.start + 1;
-            let separator = if property_count > 0 { "," } else { "" };
-            (start, end, separator)
+    // Identify the token replacement metadata: start, end, and possible leading
+    // join characters
+    let (start, end, separator) = match (&generate_type, closest_node) {
+        (GenerateType::Member, jsonc_parser::ast::Value::Object(literal))
+            if !literal.properties.is_empty() =>
+        {
+            // Append after the last member, reusing the whitespace that precedes the
+            // first member so that pretty-printed documents stay pretty-printed.
+            let first_property = &literal.properties[0];
+            let last_property = &literal.properties[literal.properties.len() - 1];
+            let leading =
+                &json_document_string[literal.range.start + 1..first_property.range.start];
+            let separator = match leading.rfind('\n') {
+                Some(newline) => {
+                    let indentation: String = leading[newline + 1..]
+                        .chars()
+                        .take_while(|c| c.is_whitespace())
+                        .collect();
+                    format!(",\n{indentation}")
+                }
+                None if leading.starts_with(char::is_whitespace) => ", ".to_owned(),
+                None => ",".to_owned(),
+            };
+            (last_property.range.end, last_property.range.end, separator)
         }
+        (GenerateType::Member, _) => (range.start + 1, range.start + 1, String::new()),
+        (GenerateType::Object, _) => (range.start, range.end, String::new()),
     };
 
     // Generate the serialized JSON to insert into the document.
@@ -91,7 +102,7 @@ pub fn set_path(
     let missing_path_segments = &path[closest_path.len()..];
     let computed_object = match generate_type {
         GenerateType::Object => generate_object(missing_path_segments, json_value),
-        GenerateType::Member => generate_member(missing_path_segments, json_value, separator),
+        GenerateType::Member => generate_member(missing_path_segments, json_value, &separator),
     };
 
     // Generate a new document!
@@ -189,12 +200,12 @@ fn generate_object(path_segments: &[&str], value: &str) -> String {
 
 /**
  * Given path segments, generate a JSON object member with an optional
- * trailing separator.
+ * leading separator.
  */
 fn generate_member(path_segments: &[&str], value: &str, separator: &str) -> String {
     let (key, remainder) = path_segments.split_first().unwrap();
     let object = generate_object(remainder, value);
-    format!("\"{key}\":{object}{separator}")
+    format!("{separator}\"{key}\":{object}")
 }
 
 /**
@@ -211,42 +222,19 @@ pub fn unset_path(
 
     // The key path can appear multiple times. This a vec that contains each time it
     // occurs.
-    let path_ranges = find_all_paths(&root, path, match_case_sensitive);
+    let mut path_ranges = find_all_paths(&root, path, match_case_sensitive);
 
     if path_ranges.is_empty() {
         return Ok(None);
     }
 
-    // We mutate this as we go.
+    // We mutate this as we go, so we work from the end of the document back
+    // to the start to keep the remaining ranges valid.
     let mut output: String = json_document_string.to_owned();
-
-    // We could either join overlapping ranges, or just carry it over.
-    // This elects to carry it over.
-    let mut last_start_position = None;
-
-    // We iterate in reverse since we're mutating the string.
-    path_ranges.iter().rev().for_each(|range| {
-        let end = match last_start_position {
-            Some(last_start_position) => {
-                if range.end > last_start_position {
-                    last_start_position
-                } else {
-                    range.end
-                }
-            }
-            None => range.end,
-        };
-
-        let is_overlapping = end != range.end;
-        let replacement_char = if is_overlapping {
-            ""
-        } else {
-            &range.replacement_char
-        };
-
-        output.replace_range(range.start..end, replacement_char);
-        last_start_position = Some(range.start);
-    });
+    path_ranges.sort_by_key(|range| range.start);
+    for range in path_ranges.iter().rev() {
+        output.replace_range(range.start..range.end, "");
+    }
 
     Ok(Some(output))
 }
@@ -269,12 +257,12 @@ fn find_all_paths<'a>(
 
     // We can only find paths on objects.
     if let jsonc_parser::ast::Value::Object(obj) = current_node {
-        // We need a reference to the previous and next property to identify if we're
-        // looking at the first or last node which need special handling.
-        let mut properties_iterator = obj.properties.iter().peekable();
+        let properties = &obj.properties;
+
+        // Indices of the properties in this object that should be removed.
+        let mut removals: Vec<usize> = vec![];
 
-        let mut previous_property: Option<&jsonc_parser::ast::ObjectProp<'_>> = None;
-        while let Some(property) = properties_iterator.next() {
+        for (index, property) in properties.iter().enumerate() {
             let current_property_name = property.name.as_str();
 
             let should_rewrite = if match_case_sensitive {
@@ -286,42 +274,7 @@ fn find_all_paths<'a>(
             if should_rewrite {
                 // target_path == 1? We've arrived at a node to remove.
                 if target_path.len() == 1 {
-                    let next_property = properties_iterator.peek();
-
-                    // We calculate the range based off the adjacent nodes.
-                    // This is required to ensure that we capture things like commas.
-
-                    // If this property follows another property, remove starting from the end of
-                    // that property.
-                    let start = if let Some(previous_property) = previous_property {
-                        previous_property.range.end
-                    } else {
-                        property.range.start
-                    };
-
-                    // If this property precedes another property, remove all the way to the start
-                    // of that property.
-                    let end = if let Some(next_property) = next_property {
-                        next_property.range.start
-                    } else {
-                        property.range.end
-                    };
-
-                    // The comma will _always_ be in the range. If we're the first or last, we want
-                    // to remove it. If we are somewhere in the middle, we need
-                    // to insert a comma to replace the comma we ate.
-                    let replacement_char = if previous_property.is_none() || next_property.is_none()
-                    {
-                        ""
-                    } else {
-                        ","
-                    };
-
-                    ranges.push(Range {
-                        start,
-                        end,
-                        replacement_char: replacement_char.to_owned(),
-                    });
+                    removals.push(index);
                 } else {
                     // We must recurse.
                     let next_current_node = &property.value;
@@ -332,7 +285,32 @@ fn find_all_paths<'a>(
                     ranges.append(&mut children_ranges);
                 }
             }
-            previous_property = Some(property);
+        }
+
+        // Adjacent properties are removed as a single range so that we only have
+        // to account for the separators around them once.
+        for run in removals.chunk_by(|previous, next| next - previous == 1) {
+            let first = run[0];
+            let last = run[run.len() - 1];
+
+            // We calculate the range based off the adjacent nodes. This is required to
+            // ensure that we capture the comma while leaving the indentation of the
+            // surrounding properties untouched.
+            let (start, end) = if first > 0 {
+                // If this follows another property, remove starting from the end of that
+                // property. The comma after the removed run now separates the previous
+                // property from whatever follows.
+                (properties[first - 1].range.end, properties[last].range.end)
+            } else if let Some(next_property) = properties.get(last + 1) {
+                // If this is the first property, remove all the way to the start of the
+                // next property.
+                (properties[first].range.start, next_property.range.start)
+            } else {
+                // Otherwise we're removing every property in the object.
+                (properties[first].range.start, properties[last].range.end)
+            };
+
+            ranges.push(Range { start, end });
         }
     }
 
@@ -375,11 +353,11 @@ mod test {
         ),
         populated_object: (
             "{ \"other\": \"thing\" }",
-            "{\"parent\":{\"child\":\"Junior\"}, \"other\": \"thing\" }"
+            "{ \"other\": \"thing\", \"parent\":{\"child\":\"Junior\"} }"
         ),
         trailing_comma: (
             "{ \"trailing\": \"comma\", }",
-            "{\"parent\":{\"child\":\"Junior\"}, \"trailing\": \"comma\", }"
+            "{ \"trailing\": \"comma\", \"parent\":{\"child\":\"Junior\"}, }"
         ),
         existing_primitive: (
             "{ \"parent\": \"thing\" }",
@@ -413,7 +391,7 @@ mod test {
         adjacent_nodes: (
             r#"{ "before": {}, "experimentalSpaces": { "id": "one" }, "experimentalSpaces": { "id": "two" }, "after": {} }"#,
             &["experimentalSpaces"],
-            Some("{ \"before\": {},\"after\": {} }")
+            Some("{ \"before\": {}, \"after\": {} }")
         ),
         adjacent_nodes_trailing_comma: (
             r#"{ "before": {}, "experimentalSpaces": { "id": "one" }, "experimentalSpaces": { "id": "two" }, }"#,
@@ -421,10 +399,15 @@ mod test {
             // If it had a trailing comma to start, it may continue to have one.
             Some("{ \"before\": {}, }")
         ),
+        leading_adjacent_nodes: (
+            r#"{ "experimentalSpaces": { "id": "one" }, "experimentalSpaces": { "id": "two" }, "after": {} }"#,
+            &["experimentalSpaces"],
+            Some("{ \"after\": {} }")
+        ),
         parent_node: (
             r#"{ "before": {}, "experimentalSpaces": { "id": "one" }, "middle": {}, "experimentalSpaces": { "id": "two" }, "after": {} }"#,
             &["experimentalSpaces"],
-            Some("{ \"before\": {},\"middle\": {},\"after\": {} }")
+            Some("{ \"before\": {}, \"middle\": {}, \"after\": {} }")
         ),
         empty_path: (
             r#"{ "before": {}, "experimentalSpaces": { "id": "one" }, "experimentalSpaces": { "id": "two" }, "after": {} }"#,
@@ -432,4 +415,53 @@ mod test {
             None
         ),
     }
+
+    const PRETTY_TURBO_JSON: &str = r#"{
+  "$schema": "https://turbo.build/schema.json",
+  // Linked to the team's space.
+  "experimentalSpaces": {
+    "id": "one"
+  },
+  "tasks": {
+    "build": { "outputs": ["dist/**"] }
+  }
+}
+"#;
+
+    #[test]
+    fn test_set_preserves_formatting() {
+        let output = set_path(PRETTY_TURBO_JSON, &["experimentalSpaces", "id"], "\"two\"").unwrap();
+        assert_eq!(output, PRETTY_TURBO_JSON.replace("\"one\"", "\"two\""));
+    }
+
+    #[test]
+    fn test_set_new_member_matches_indentation() {
+        let output = set_path(
+            "{\n  \"tasks\": {}\n}\n",
+            &["experimentalSpaces", "id"],
+            "\"one\"",
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            "{\n  \"tasks\": {},\n  \"experimentalSpaces\":{\"id\":\"one\"}\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_unset_preserves_formatting() {
+        let output = unset_path(PRETTY_TURBO_JSON, &["experimentalSpaces"], true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            output,
+            r#"{
+  "$schema": "https://turbo.build/schema.json",
+  "tasks": {
+    "build": { "outputs": ["dist/**"] }
+  }
+}
+"#
+        );
+    }
 }
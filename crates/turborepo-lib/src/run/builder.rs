@@ -2,11 +2,11 @@ use std::{
     collections::{HashMap, HashSet},
     io::{ErrorKind, IsTerminal},
     sync::Arc,
-    time::SystemTime,
+    time::{Duration, SystemTime},
 };
 
 use chrono::Local;
-use tracing::debug;
+use tracing::{debug, warn};
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
 use turborepo_analytics::{start_analytics, AnalyticsHandle, AnalyticsSender};
 use turborepo_api_client::{APIAuth, APIClient};
@@ -30,7 +30,6 @@ use turborepo_ui::{ColorConfig, ColorSelector};
 #[cfg(feature = "daemon-package-discovery")]
 use {
     crate::run::package_discovery::DaemonPackageDiscovery,
-    std::time::Duration,
     turborepo_repository::discovery::{
         Error as DiscoveryError, FallbackPackageDiscovery, LocalPackageDiscoveryBuilder,
         PackageDiscoveryBuilder,
@@ -38,18 +37,27 @@ use {
 };
 
 use crate::{
-    cli::DryRunMode,
+    cli::{DryRunMode, LogFormat},
     commands::CommandBase,
     engine::{Engine, EngineBuilder},
     opts::Opts,
     process::ProcessManager,
-    run::{scope, task_access::TaskAccess, task_id::TaskName, Error, Run, RunCache},
+    run::{
+        scope::{self, target_selector::TargetSelector},
+        task_access::TaskAccess,
+        task_id::TaskName,
+        Error, Run, RunCache,
+    },
     shim::TurboState,
     signal::{SignalHandler, SignalSubscriber},
     turbo_json::{TurboJson, TurboJsonLoader, UIMode},
     DaemonConnector,
 };
 
+// A daemon that takes longer than this to answer a ping is likely to slow the
+// run down rather than speed it up, so we run without it
+const MAX_DAEMON_LATENCY: Duration = Duration::from_millis(200);
+
 pub struct RunBuilder {
     processes: ProcessManager,
     opts: Opts,
@@ -67,6 +75,7 @@ pub struct RunBuilder {
     should_print_prelude_override: Option<bool>,
     allow_missing_package_manager: bool,
     allow_no_turbo_json: bool,
+    environment: Option<String>,
     // If true, we will add all tasks to the graph, even if they are not specified
     add_all_tasks: bool,
 }
@@ -81,15 +90,23 @@ impl RunBuilder {
         let allow_missing_package_manager = config.allow_no_package_manager();
 
         let version = base.version();
-        let processes = ProcessManager::new(
+        let mut processes = ProcessManager::new(
             // We currently only use a pty if the following are met:
             // - we're attached to a tty
             atty::is(atty::Stream::Stdout) &&
             // - if we're on windows, we're using the UI
             (!cfg!(windows) || matches!(opts.run_opts.ui_mode, UIMode::Tui)),
         );
+        // Peak memory is only reported in the run summary
+        if opts.run_opts.summarize {
+            processes = processes.with_memory_sampling();
+        }
         let root_turbo_json_path = config.root_turbo_json_path(&base.repo_root);
         let allow_no_turbo_json = config.allow_no_turbo_json();
+        let environment = config.environment().map(str::to_owned);
+        // The prelude is only meant for humans, so leave it out of JSON logs
+        let should_print_prelude_override =
+            matches!(base.args().log_format, Some(LogFormat::Json)).then_some(false);
 
         let CommandBase {
             repo_root,
@@ -107,10 +124,11 @@ impl RunBuilder {
             api_auth,
             analytics_sender: None,
             entrypoint_packages: None,
-            should_print_prelude_override: None,
+            should_print_prelude_override,
             allow_missing_package_manager,
             root_turbo_json_path,
             allow_no_turbo_json,
+            environment,
             add_all_tasks: false,
         })
     }
@@ -120,6 +138,10 @@ impl RunBuilder {
         self
     }
 
+    pub fn opts(&self) -> &Opts {
+        &self.opts
+    }
+
     pub fn hide_prelude(mut self) -> Self {
         self.should_print_prelude_override = Some(false);
         self
@@ -181,6 +203,41 @@ impl RunBuilder {
         Ok(filtered_pkgs)
     }
 
+    // Filtering by changes, e.g. `--affected` on a branch without changes, is
+    // expected to select nothing at times. Any other empty selection is most
+    // likely a mistake.
+    fn check_filtered_packages(
+        opts: &Opts,
+        pkg_dep_graph: &PackageGraph,
+        filtered_pkgs: &HashMap<PackageName, PackageInclusionReason>,
+    ) -> Result<(), Error> {
+        if !filtered_pkgs.is_empty() || opts.run_opts.single_package {
+            return Ok(());
+        }
+
+        let has_packages = pkg_dep_graph
+            .packages()
+            .any(|(name, _)| matches!(name, PackageName::Other(_)));
+        if !has_packages {
+            return Err(Error::NoPackages);
+        }
+
+        let filters = opts.scope_opts.get_filters();
+        let filters_by_changes = opts.scope_opts.get_affected_range().is_some()
+            || filters.iter().any(|filter| {
+                filter
+                    .parse::<TargetSelector>()
+                    .map_or(false, |selector| selector.git_range.is_some())
+            });
+        if filters.is_empty() || filters_by_changes {
+            return Ok(());
+        }
+
+        Err(Error::NoPackagesMatched {
+            filters: filters.join(", "),
+        })
+    }
+
     // Starts analytics and returns handle. This is not included in the main `build`
     // function because we don't want the handle stored in the `Run` struct.
     pub fn start_analytics(&self) -> (Option<AnalyticsSender>, Option<AnalyticsHandle>) {
@@ -261,11 +318,35 @@ impl RunBuilder {
                 let connector =
                     DaemonConnector::new(can_start_server, can_kill_server, &self.repo_root);
                 match (connector.connect().await, self.opts.run_opts.daemon) {
-                    (Ok(client), _) => {
+                    (Ok(client), Some(true)) => {
                         run_telemetry.track_daemon_init(DaemonInitStatus::Started);
                         debug!("running in daemon mode");
                         Some(client)
                     }
+                    // A daemon that was just started is still warming up, so
+                    // its first responses say little about how it will perform
+                    (Ok(client), None) if client.started_daemon() => {
+                        run_telemetry.track_daemon_init(DaemonInitStatus::Started);
+                        debug!("running in daemon mode, daemon was just started");
+                        Some(client)
+                    }
+                    (Ok(mut client), None) => match client.ping().await {
+                        Ok(latency) if latency <= MAX_DAEMON_LATENCY => {
+                            run_telemetry.track_daemon_init(DaemonInitStatus::Started);
+                            debug!("running in daemon mode, daemon responded in {latency:?}");
+                            Some(client)
+                        }
+                        Ok(latency) => {
+                            run_telemetry.track_daemon_init(DaemonInitStatus::Failed);
+                            debug!("daemon took {latency:?} to respond, not using it");
+                            None
+                        }
+                        Err(e) => {
+                            run_telemetry.track_daemon_init(DaemonInitStatus::Failed);
+                            debug!("failed to ping daemon {e}");
+                            None
+                        }
+                    },
                     (Err(e), Some(true)) => {
                         run_telemetry.track_daemon_init(DaemonInitStatus::Failed);
                         debug!("failed to connect to daemon when forced {e}, exiting");
@@ -399,7 +480,8 @@ impl RunBuilder {
                 self.root_turbo_json_path.clone(),
                 pkg_dep_graph.packages(),
             )
-        };
+        }
+        .with_environment(self.environment.clone());
 
         let root_turbo_json = turbo_json_loader.load(&PackageName::Root)?.clone();
 
@@ -412,6 +494,7 @@ impl RunBuilder {
             &scm,
             &root_turbo_json,
         )?;
+        Self::check_filtered_packages(&self.opts, &pkg_dep_graph, &filtered_pkgs)?;
 
         let env_at_execution_start = EnvironmentVariableMap::infer();
         let mut engine = self.build_engine(
@@ -431,6 +514,10 @@ impl RunBuilder {
             )?;
         }
 
+        for overlap in engine.output_overlaps(&pkg_dep_graph) {
+            warn!("{overlap}");
+        }
+
         let color_selector = ColorSelector::default();
 
         let run_cache = Arc::new(RunCache::new(
@@ -444,7 +531,9 @@ impl RunBuilder {
         ));
 
         let should_print_prelude = self.should_print_prelude_override.unwrap_or_else(|| {
-            self.opts.run_opts.dry_run.is_none() && self.opts.run_opts.graph.is_none()
+            self.opts.run_opts.dry_run.is_none()
+                && self.opts.run_opts.graph.is_none()
+                && !self.opts.run_opts.summarize_only
         });
 
         Ok(Run {
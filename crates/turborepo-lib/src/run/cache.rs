@@ -15,6 +15,7 @@ use turborepo_repository::package_graph::PackageInfo;
 use turborepo_scm::SCM;
 use turborepo_telemetry::events::{task::PackageTaskEventBuilder, TrackedErrors};
 use turborepo_ui::{color, tui::event::CacheResult, ColorConfig, ColorSelector, LogWriter, GREY};
+use wax::{Glob, Program};
 
 use crate::{
     cli::OutputLogsMode,
@@ -50,11 +51,13 @@ pub struct RunCache {
     cache: AsyncCache,
     warnings: Arc<Mutex<Vec<String>>>,
     reads_disabled: bool,
+    force_tasks: Option<Glob<'static>>,
     writes_disabled: bool,
     repo_root: AbsoluteSystemPathBuf,
     color_selector: ColorSelector,
     daemon_client: Option<DaemonClient<DaemonConnector>>,
     ui: ColorConfig,
+    strip_log_colors: bool,
 }
 
 /// Trait used to output cache information to user
@@ -84,11 +87,13 @@ impl RunCache {
             cache,
             warnings: Default::default(),
             reads_disabled: opts.skip_reads,
+            force_tasks: opts.force_tasks.clone(),
             writes_disabled: opts.skip_writes,
             repo_root: repo_root.to_owned(),
             color_selector,
             daemon_client,
             ui,
+            strip_log_colors: opts.strip_log_colors,
         }
     }
 
@@ -107,12 +112,11 @@ impl RunCache {
         let repo_relative_globs =
             task_definition.repo_relative_hashable_outputs(&task_id, workspace_info.package_path());
 
-        let mut task_output_logs = task_definition.output_logs;
-        if let Some(task_output_logs_override) = self.task_output_logs {
-            task_output_logs = task_output_logs_override;
-        }
+        let task_output_logs = resolve_output_logs(task_definition, self.task_output_logs);
 
         let caching_disabled = !task_definition.cache;
+        let reads_disabled = self.reads_disabled || is_forced(self.force_tasks.as_ref(), &task_id);
+        let writes_disabled = self.writes_disabled || task_definition.cache_readonly;
 
         TaskCache {
             expanded_outputs: Vec::new(),
@@ -122,6 +126,8 @@ impl RunCache {
             task_id,
             task_output_logs,
             caching_disabled,
+            reads_disabled,
+            writes_disabled,
             log_file_path,
             daemon_client: self.daemon_client.clone(),
             ui: self.ui,
@@ -129,6 +135,12 @@ impl RunCache {
         }
     }
 
+    /// Waits for all pending cache writes to land without shutting down the
+    /// cache.
+    pub async fn flush(&self) -> Result<(), CacheError> {
+        self.cache.flush().await
+    }
+
     pub async fn shutdown_cache(
         &self,
     ) -> Result<(Arc<Mutex<UploadMap>>, oneshot::Receiver<()>), CacheError> {
@@ -142,6 +154,23 @@ impl RunCache {
     }
 }
 
+/// A task's `outputLogs` from turbo.json is used unless `--output-logs` was
+/// passed, which applies to every task in the run.
+fn resolve_output_logs(
+    task_definition: &TaskDefinition,
+    output_logs_override: Option<OutputLogsMode>,
+) -> OutputLogsMode {
+    output_logs_override.unwrap_or(task_definition.output_logs)
+}
+
+/// `--force` can be limited to tasks by name, e.g. `build`, or by id, e.g.
+/// `web#build`.
+fn is_forced(force_tasks: Option<&Glob>, task_id: &TaskId) -> bool {
+    force_tasks.map_or(false, |glob| {
+        glob.is_match(task_id.task()) || glob.is_match(task_id.to_string().as_str())
+    })
+}
+
 pub struct TaskCache {
     expanded_outputs: Vec<AnchoredSystemPathBuf>,
     run_cache: Arc<RunCache>,
@@ -149,6 +178,8 @@ pub struct TaskCache {
     hash: String,
     task_output_logs: OutputLogsMode,
     caching_disabled: bool,
+    reads_disabled: bool,
+    writes_disabled: bool,
     log_file_path: AbsoluteSystemPathBuf,
     daemon_client: Option<DaemonClient<DaemonConnector>>,
     ui: ColorConfig,
@@ -188,7 +219,16 @@ impl TaskCache {
     pub fn output_writer<W: Write>(&self, writer: W) -> Result<LogWriter<W>, Error> {
         let mut log_writer = LogWriter::default();
 
-        if self.caching_disabled || self.run_cache.writes_disabled {
+        if self.run_cache.strip_log_colors {
+            log_writer.strip_ansi_from_log_file();
+            // Follow turbo's own color detection, which turns colors off when
+            // stdout isn't a terminal
+            if self.run_cache.ui.should_strip_ansi {
+                log_writer.strip_ansi_from_writer();
+            }
+        }
+
+        if self.caching_disabled || self.writes_disabled {
             log_writer.with_writer(writer);
             return Ok(log_writer);
         }
@@ -214,7 +254,7 @@ impl TaskCache {
         terminal_output: &mut impl CacheOutput,
         telemetry: &PackageTaskEventBuilder,
     ) -> Result<Option<CacheHitMetadata>, Error> {
-        if self.caching_disabled || self.run_cache.reads_disabled {
+        if self.caching_disabled || self.reads_disabled {
             if !matches!(
                 self.task_output_logs,
                 OutputLogsMode::None | OutputLogsMode::ErrorsOnly
@@ -355,7 +395,7 @@ impl TaskCache {
         duration: Duration,
         telemetry: &PackageTaskEventBuilder,
     ) -> Result<(), Error> {
-        if self.caching_disabled || self.run_cache.writes_disabled {
+        if self.caching_disabled || self.writes_disabled {
             return Ok(());
         }
 
@@ -518,3 +558,47 @@ fn fallible_write(mut writer: impl Write, message: &str) {
         error!("cannot write to logs: {:?}", err);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+    use wax::Glob;
+
+    use super::{is_forced, resolve_output_logs};
+    use crate::{cli::OutputLogsMode, run::task_id::TaskId, task_graph::TaskDefinition};
+
+    #[test_case(OutputLogsMode::Full, None, OutputLogsMode::Full ; "default")]
+    #[test_case(OutputLogsMode::HashOnly, None, OutputLogsMode::HashOnly ; "task overrides default")]
+    #[test_case(OutputLogsMode::HashOnly, Some(OutputLogsMode::Full), OutputLogsMode::Full ; "flag overrides task")]
+    #[test_case(OutputLogsMode::Full, Some(OutputLogsMode::ErrorsOnly), OutputLogsMode::ErrorsOnly ; "flag overrides default")]
+    fn test_resolve_output_logs(
+        task_output_logs: OutputLogsMode,
+        output_logs_override: Option<OutputLogsMode>,
+        expected: OutputLogsMode,
+    ) {
+        let task_definition = TaskDefinition {
+            output_logs: task_output_logs,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_output_logs(&task_definition, output_logs_override),
+            expected
+        );
+    }
+
+    #[test_case("build", "web#build", true ; "task name")]
+    #[test_case("build", "web#lint", false ; "other task")]
+    #[test_case("web#build", "web#build", true ; "task id")]
+    #[test_case("web#build", "docs#build", false ; "other package")]
+    #[test_case("build*", "web#build:types", true ; "glob")]
+    fn test_is_forced(force_tasks: &str, task_id: &str, expected: bool) {
+        let glob = Glob::new(force_tasks).unwrap();
+        let task_id = TaskId::try_from(task_id).unwrap();
+        assert_eq!(is_forced(Some(&glob), &task_id), expected);
+    }
+
+    #[test]
+    fn test_nothing_forced() {
+        assert!(!is_forced(None, &TaskId::new("web", "build")));
+    }
+}
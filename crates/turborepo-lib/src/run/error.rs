@@ -52,6 +52,8 @@ pub enum Error {
     #[error(transparent)]
     #[diagnostic(transparent)]
     Visitor(#[from] task_graph::VisitorError),
+    #[error("failed to serialize hashes: {0}")]
+    Serde(#[from] serde_json::Error),
     #[error("error registering signal handler: {0}")]
     SignalHandler(std::io::Error),
     #[error(transparent)]
@@ -60,4 +62,13 @@ pub enum Error {
     UI(#[from] turborepo_ui::Error),
     #[error(transparent)]
     Tui(#[from] tui::Error),
+    #[error("turbo was run from inside a task and --no-nested was passed")]
+    NestedInvocation,
+    #[error("No packages found in the workspace")]
+    #[diagnostic(help(
+        "check that the workspaces configured for your package manager match your packages"
+    ))]
+    NoPackages,
+    #[error("No packages found matching {filters}")]
+    NoPackagesMatched { filters: String },
 }
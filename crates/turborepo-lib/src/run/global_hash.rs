@@ -19,7 +19,7 @@ use turborepo_scm::SCM;
 
 use crate::{
     cli::EnvMode,
-    hash::{GlobalHashable, TurboHash},
+    hash::{GlobalHashable, HashAlgorithm, TurboHash},
 };
 
 static DEFAULT_ENV_VARS: [&str; 1] = ["VERCEL_ANALYTICS_ID"];
@@ -182,7 +182,7 @@ fn collect_global_deps(
 }
 
 impl<'a> GlobalHashableInputs<'a> {
-    pub fn calculate_global_hash(&self) -> String {
+    pub fn calculate_global_hash(&self, algorithm: HashAlgorithm) -> String {
         let global_hashable = GlobalHashable {
             global_cache_key: self.global_cache_key,
             global_file_hash_map: &self.global_file_hash_map,
@@ -200,12 +200,14 @@ impl<'a> GlobalHashableInputs<'a> {
             framework_inference: self.framework_inference,
         };
 
-        global_hashable.hash()
+        global_hashable.hash_with(algorithm)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use turbopath::AbsoluteSystemPathBuf;
     use turborepo_env::EnvironmentVariableMap;
     use turborepo_lockfiles::Lockfile;
@@ -213,7 +215,7 @@ mod tests {
     use turborepo_scm::SCM;
 
     use super::get_global_hash_inputs;
-    use crate::{cli::EnvMode, run::global_hash::collect_global_deps};
+    use crate::{cli::EnvMode, hash::HashAlgorithm, run::global_hash::collect_global_deps};
 
     #[test]
     fn test_absolute_path() {
@@ -293,4 +295,54 @@ mod tests {
         // should not yield the root folder itself, src, or empty-folder
         assert_eq!(results.len(), 3, "{:?}", results);
     }
+
+    #[test]
+    fn test_negated_global_deps_are_not_hashed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = AbsoluteSystemPathBuf::try_from(tmp.path())
+            .unwrap()
+            .to_realpath()
+            .unwrap();
+        root.join_component("package.json")
+            .create_with_contents("{}")
+            .unwrap();
+        let config = root.join_components(&["config", "app.yaml"]);
+        config.ensure_dir().unwrap();
+        config.create_with_contents("port: 3000").unwrap();
+        let readme = root.join_components(&["config", "README.md"]);
+        readme.create_with_contents("# Config").unwrap();
+
+        let global_file_dependencies =
+            vec!["config/**/*.{yaml,md}".to_string(), "!**/*.md".to_string()];
+        let lockfile: Option<&dyn Lockfile> = None;
+        let global_hash = || {
+            get_global_hash_inputs(
+                None,
+                None,
+                &PackageInfo::default(),
+                &root,
+                &PackageManager::Berry,
+                lockfile,
+                &global_file_dependencies,
+                &EnvironmentVariableMap::default(),
+                &[],
+                None,
+                EnvMode::Strict,
+                false,
+                &SCM::new(&root),
+            )
+            .unwrap()
+            .calculate_global_hash(HashAlgorithm::default())
+        };
+
+        let results =
+            collect_global_deps(&PackageManager::Berry, &root, &global_file_dependencies).unwrap();
+        assert_eq!(results, HashSet::from([config.clone()]));
+
+        let original = global_hash();
+        readme.create_with_contents("# Config docs").unwrap();
+        assert_eq!(global_hash(), original);
+        config.create_with_contents("port: 4000").unwrap();
+        assert_ne!(global_hash(), original);
+    }
 }
@@ -102,6 +102,24 @@ const HTML_PREFIX: &str = r#"
   <script src="https://cdn.jsdelivr.net/npm/viz.js@2.1.2-pre.1/viz.js"></script>
   <script src="https://cdn.jsdelivr.net/npm/viz.js@2.1.2-pre.1/full.render.js"></script>
   <script>
+    // Clicking a task fades out everything that isn't connected to it,
+    // clicking anywhere else shows the whole graph again.
+    function makeInteractive(svg) {
+      const elements = svg.querySelectorAll("g.node, g.edge");
+      const highlight = (name) => elements.forEach((el) => {
+        const title = el.querySelector("title").textContent;
+        const related = name === null || title === name || title.split("->").includes(name);
+        el.style.opacity = related ? 1 : 0.2;
+      });
+      svg.querySelectorAll("g.node").forEach((node) => {
+        node.style.cursor = "pointer";
+        node.addEventListener("click", (event) => {
+          event.stopPropagation();
+          highlight(node.querySelector("title").textContent);
+        });
+      });
+      svg.addEventListener("click", () => highlight(null));
+    }
 "#;
 const HTML_SUFFIX: &str = r#"
   </script>
@@ -116,23 +134,33 @@ fn render_html(
 ) -> Result<(), Error> {
     let mut opts = OpenOptions::new();
     opts.truncate(true).create(true).write(true);
-    let mut file = filename
+    let file = filename
         .open_with_options(opts)
         .map_err(Error::GraphOutput)?;
+    write_html(file, engine, single_package)
+}
+
+fn write_html<W: io::Write>(
+    mut writer: W,
+    engine: &Engine,
+    single_package: bool,
+) -> Result<(), Error> {
     let mut graph_buffer = Vec::new();
     render_dot_graph(&mut graph_buffer, engine, single_package)?;
     let graph_string = String::from_utf8(graph_buffer).expect("graph rendering should be UTF-8");
 
-    file.write_all(HTML_PREFIX.as_bytes())
+    writer
+        .write_all(HTML_PREFIX.as_bytes())
         .map_err(Error::GraphOutput)?;
     write!(
-        &mut file,
+        &mut writer,
         "const s = `{graph_string}`.replace(/\\_\\_\\_ROOT\\_\\_\\_/g, \
-         \"Root\").replace(/\\[root\\]/g, \"\");new Viz().renderSVGElement(s).then(el => \
-         document.body.appendChild(el)).catch(e => console.error(e));"
+         \"Root\").replace(/\\[root\\]/g, \"\");new Viz().renderSVGElement(s).then(el => {{ \
+         document.body.appendChild(el); makeInteractive(el); }}).catch(e => console.error(e));"
     )
     .map_err(Error::GraphOutput)?;
-    file.write_all(HTML_SUFFIX.as_bytes())
+    writer
+        .write_all(HTML_SUFFIX.as_bytes())
         .map_err(Error::GraphOutput)?;
     Ok(())
 }
@@ -164,3 +192,28 @@ fn filename_and_extension(
         Ok((jpg_graph_file, extension))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::write_html;
+    use crate::{engine::Engine, run::task_id::TaskId};
+
+    #[test]
+    fn test_html_contains_tasks() {
+        let mut engine = Engine::new();
+        for task_id in [TaskId::new("web", "build"), TaskId::new("ui", "build")] {
+            engine.get_index(&task_id);
+            engine.connect_to_root(&task_id);
+        }
+        let engine = engine.seal();
+
+        let mut html = Vec::new();
+        write_html(&mut html, &engine, false).unwrap();
+        let html = String::from_utf8(html).unwrap();
+
+        assert!(html.starts_with("\n<!DOCTYPE html>"));
+        assert!(html.contains(r#""[root] web#build" -> "[root] ___ROOT___""#));
+        assert!(html.contains(r#""[root] ui#build" -> "[root] ___ROOT___""#));
+        assert!(html.contains("makeInteractive(el)"));
+    }
+}
@@ -0,0 +1,69 @@
+use std::env;
+
+use tracing::warn;
+
+use super::Error;
+
+/// Set on every task turbo runs to the number of turbo runs the task is
+/// nested in. A turbo started from a task reads it to find out how deeply it
+/// is nested.
+pub const INVOCATION_DEPTH_ENV: &str = "TURBO_INVOCATION_DEPTH";
+// A single level of nesting is a common pattern, anything deeper is most
+// likely a script that ends up invoking itself
+const MAX_INVOCATION_DEPTH: u32 = 1;
+
+/// The number of turbo runs that the current process is nested in
+pub fn current() -> u32 {
+    parse(env::var(INVOCATION_DEPTH_ENV).ok().as_deref())
+}
+
+fn parse(value: Option<&str>) -> u32 {
+    value.and_then(|depth| depth.parse().ok()).unwrap_or(0)
+}
+
+/// Warns if turbo is nested deeper than expected. If `no_nested` is set, any
+/// nesting is an error.
+pub fn check(depth: u32, no_nested: bool) -> Result<(), Error> {
+    if no_nested && depth > 0 {
+        return Err(Error::NestedInvocation);
+    }
+    if depth > MAX_INVOCATION_DEPTH {
+        warn!(
+            "turbo is running inside of {depth} other turbo runs, a task might be invoking turbo \
+             recursively"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::{check, parse};
+    use crate::run::Error;
+
+    #[test_case(None, 0 ; "unset")]
+    #[test_case(Some("2"), 2 ; "nested")]
+    #[test_case(Some("banana"), 0 ; "invalid")]
+    fn test_parse(value: Option<&str>, expected: u32) {
+        assert_eq!(parse(value), expected);
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check(0, true).is_ok());
+        assert!(check(1, false).is_ok());
+        assert!(check(3, false).is_ok());
+        assert!(matches!(check(1, true), Err(Error::NestedInvocation)));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_deep_nesting_warns() {
+        check(1, false).unwrap();
+        assert!(!logs_contain("recursively"));
+        check(2, false).unwrap();
+        assert!(logs_contain("inside of 2 other turbo runs"));
+    }
+}
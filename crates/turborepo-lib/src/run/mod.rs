@@ -5,6 +5,7 @@ mod cache;
 mod error;
 pub(crate) mod global_hash;
 mod graph_visualizer;
+pub(crate) mod invocation_depth;
 pub(crate) mod package_discovery;
 pub(crate) mod scope;
 pub(crate) mod summary;
@@ -23,6 +24,8 @@ use std::{
 pub use cache::{CacheOutput, ConfigCache, Error as CacheError, RunCache, TaskCache};
 use chrono::{DateTime, Local};
 use rayon::iter::ParallelBridge;
+use serde::Serialize;
+pub use summary::{RunResult, TaskOutcome};
 use tokio::{select, task::JoinHandle};
 use tracing::{debug, instrument};
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
@@ -33,8 +36,8 @@ use turborepo_repository::package_graph::{PackageGraph, PackageName, PackageNode
 use turborepo_scm::SCM;
 use turborepo_telemetry::events::generic::GenericEventBuilder;
 use turborepo_ui::{
-    cprint, cprintln, sender::UISender, tui, tui::TuiSender, wui::sender::WebUISender, ColorConfig,
-    BOLD_GREY, GREY,
+    compact, compact::CompactSender, cprint, cprintln, sender::UISender, tui, tui::TuiSender,
+    wui::sender::WebUISender, ColorConfig, BOLD_GREY, GREY,
 };
 
 pub use crate::run::error::Error;
@@ -43,10 +46,16 @@ use crate::{
     engine::Engine,
     opts::Opts,
     process::ProcessManager,
-    run::{global_hash::get_global_hash_inputs, summary::RunTracker, task_access::TaskAccess},
+    run::{
+        global_hash::{get_global_hash_inputs, GlobalHashableInputs},
+        summary::RunTracker,
+        task_access::TaskAccess,
+    },
     signal::SignalHandler,
     task_graph::Visitor,
-    task_hash::{get_external_deps_hash, get_internal_deps_hash, PackageInputsHashes},
+    task_hash::{
+        get_external_deps_hash, get_internal_deps_hash, PackageInputsHashes, TaskHashTrackerState,
+    },
     turbo_json::{TurboJson, UIMode},
     DaemonClient, DaemonConnector,
 };
@@ -79,6 +88,7 @@ type UIResult<T> = Result<Option<(T, JoinHandle<Result<(), turborepo_ui::Error>>
 
 type WuiResult = UIResult<WebUISender>;
 type TuiResult = UIResult<TuiSender>;
+type CompactResult = UIResult<CompactSender>;
 
 impl Run {
     fn has_persistent_tasks(&self) -> bool {
@@ -213,6 +223,7 @@ impl Run {
     pub fn should_start_ui(&self) -> Result<bool, Error> {
         Ok(self.opts.run_opts.ui_mode.use_tui()
             && self.opts.run_opts.dry_run.is_none()
+            && !self.opts.run_opts.summarize_only
             && tui::terminal_big_enough()?)
     }
 
@@ -230,8 +241,28 @@ impl Run {
             UIMode::Web => self
                 .start_web_ui()
                 .map(|res| res.map(|(sender, handle)| (UISender::Wui(sender), handle))),
+            UIMode::Compact => self
+                .start_compact_ui()
+                .map(|res| res.map(|(sender, handle)| (UISender::Compact(sender), handle))),
         }
     }
+
+    fn start_compact_ui(&self) -> CompactResult {
+        if self.opts.run_opts.dry_run.is_some()
+            || self.opts.run_opts.summarize_only
+            || self
+                .engine
+                .tasks_with_command(&self.pkg_dep_graph)
+                .is_empty()
+        {
+            return Ok(None);
+        }
+
+        let (sender, receiver) = CompactSender::new();
+        let handle = tokio::task::spawn_blocking(move || compact::run_compact(receiver));
+
+        Ok(Some((sender, handle)))
+    }
     fn start_web_ui(self: &Arc<Self>) -> WuiResult {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
@@ -266,6 +297,26 @@ impl Run {
     }
 
     pub async fn run(&self, ui_sender: Option<UISender>, is_watch: bool) -> Result<i32, Error> {
+        let result = self.execute(ui_sender, is_watch, false).await?;
+        Ok(result.exit_code)
+    }
+
+    /// Executes the run and returns the exit code along with the outcome of
+    /// each task and the run summary.
+    pub async fn run_with_result(
+        &self,
+        ui_sender: Option<UISender>,
+        is_watch: bool,
+    ) -> Result<RunResult, Error> {
+        self.execute(ui_sender, is_watch, true).await
+    }
+
+    async fn execute(
+        &self,
+        ui_sender: Option<UISender>,
+        is_watch: bool,
+        include_summary: bool,
+    ) -> Result<RunResult, Error> {
         let skip_cache_writes = self.opts.runcache_opts.skip_writes;
         if let Some(subscriber) = self.signal_handler.subscribe() {
             let run_cache = self.run_cache.clone();
@@ -350,11 +401,121 @@ impl Run {
                 // as the repo root.
                 &self.repo_root,
             )?;
-            return Ok(0);
+            return Ok(RunResult::default());
+        }
+
+        if self.opts.run_opts.summarize_only {
+            let (global_hash, task_hashes) = self.get_hashes().await?;
+            let summary = HashSummary {
+                global_hash,
+                tasks: task_hashes
+                    .task_hashes()
+                    .iter()
+                    .map(|(task_id, hash)| (task_id.to_string(), hash.clone()))
+                    .collect(),
+            };
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+            return Ok(RunResult::default());
+        }
+
+        let package_inputs_hashes = self.calculate_package_inputs_hashes()?;
+        let (root_external_dependencies_hash, root_internal_dependencies_hash) =
+            self.root_dependencies_hashes()?;
+        let global_hash_inputs = self.global_hash_inputs(
+            root_external_dependencies_hash.as_deref(),
+            root_internal_dependencies_hash.as_deref(),
+        )?;
+        let global_hash =
+            global_hash_inputs.calculate_global_hash(self.opts.run_opts.hash_algorithm);
+        let global_env = self.global_env(&global_hash_inputs)?;
+
+        let mut visitor = self.visitor(
+            package_inputs_hashes,
+            &global_hash,
+            global_env,
+            ui_sender,
+            is_watch,
+        );
+
+        if self.opts.run_opts.dry_run.is_some() {
+            visitor.dry_run();
+        }
+        if include_summary {
+            visitor.include_summary();
+        }
+
+        // we look for this log line to mark the start of the run
+        // in benchmarks, so please don't remove it
+        debug!("running visitor");
+
+        let errors = visitor
+            .visit(self.engine.clone(), &self.run_telemetry)
+            .await?;
+
+        // Watch mode keeps the process alive between runs, so make sure this
+        // run's outputs are in the cache before the next run looks for them.
+        if is_watch && !skip_cache_writes {
+            self.run_cache.flush().await.ok();
         }
 
+        let exit_code = errors
+            .iter()
+            .filter_map(|err| err.exit_code())
+            .max()
+            // We hit some error, it shouldn't be exit code 0
+            .unwrap_or(if errors.is_empty() { 0 } else { 1 });
+
+        let error_prefix = if self.opts.run_opts.is_github_actions {
+            "::error::"
+        } else {
+            ""
+        };
+        for err in &errors {
+            writeln!(std::io::stderr(), "{error_prefix}{err}").ok();
+        }
+
+        let result = visitor
+            .finish(
+                exit_code,
+                &self.filtered_pkgs,
+                global_hash_inputs,
+                &self.engine,
+                &self.env_at_execution_start,
+                self.opts.scope_opts.pkg_inference_root.as_deref(),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Computes the global hash and the hash of every task in the run without
+    /// executing any tasks or checking the cache.
+    pub async fn get_hashes(&self) -> Result<(String, TaskHashTrackerState), Error> {
+        let package_inputs_hashes = self.calculate_package_inputs_hashes()?;
+        let (root_external_dependencies_hash, root_internal_dependencies_hash) =
+            self.root_dependencies_hashes()?;
+        let global_hash_inputs = self.global_hash_inputs(
+            root_external_dependencies_hash.as_deref(),
+            root_internal_dependencies_hash.as_deref(),
+        )?;
+        let global_hash =
+            global_hash_inputs.calculate_global_hash(self.opts.run_opts.hash_algorithm);
+        let global_env = self.global_env(&global_hash_inputs)?;
+
+        let mut visitor =
+            self.visitor(package_inputs_hashes, &global_hash, global_env, None, false);
+        visitor.hash_only();
+        visitor
+            .visit(self.engine.clone(), &self.run_telemetry)
+            .await?;
+        let task_hashes = visitor.into_task_hash_tracker();
+
+        Ok((global_hash, task_hashes))
+    }
+
+    fn calculate_package_inputs_hashes(&self) -> Result<PackageInputsHashes, Error> {
         let workspaces = self.pkg_dep_graph.packages().collect();
-        let package_inputs_hashes = PackageInputsHashes::calculate_file_hashes(
+        Ok(PackageInputsHashes::calculate_file_hashes(
             &self.scm,
             self.engine.tasks().par_bridge(),
             workspaces,
@@ -362,8 +523,13 @@ impl Run {
             &self.repo_root,
             &self.run_telemetry,
             &self.daemon,
-        )?;
+            self.opts.run_opts.hash_workers,
+        )?)
+    }
 
+    // Returns the hashes of the root's external and internal dependencies, these
+    // are only used in monorepos
+    fn root_dependencies_hashes(&self) -> Result<(Option<String>, Option<String>), Error> {
         let root_workspace = self
             .pkg_dep_graph
             .package_info(&PackageName::Root)
@@ -385,45 +551,70 @@ impl Run {
             })
             .transpose()?;
 
-        let global_hash_inputs = {
-            let env_mode = self.opts.run_opts.env_mode;
-            let pass_through_env = match env_mode {
-                EnvMode::Loose => {
-                    // Remove the passthroughs from hash consideration if we're explicitly loose.
-                    None
-                }
-                EnvMode::Strict => self.root_turbo_json.global_pass_through_env.as_deref(),
-            };
+        Ok((
+            root_external_dependencies_hash,
+            root_internal_dependencies_hash,
+        ))
+    }
 
-            get_global_hash_inputs(
-                root_external_dependencies_hash.as_deref(),
-                root_internal_dependencies_hash.as_deref(),
-                root_workspace,
-                &self.repo_root,
-                self.pkg_dep_graph.package_manager(),
-                self.pkg_dep_graph.lockfile(),
-                &self.root_turbo_json.global_deps,
-                &self.env_at_execution_start,
-                &self.root_turbo_json.global_env,
-                pass_through_env,
-                env_mode,
-                self.opts.run_opts.framework_inference,
-                &self.scm,
-            )?
-        };
-        let global_hash = global_hash_inputs.calculate_global_hash();
-
-        let global_env = {
-            let mut env = self
-                .env_at_execution_start
-                .from_wildcards(global_hash_inputs.pass_through_env.unwrap_or_default())
-                .map_err(Error::Env)?;
-            if let Some(resolved_global) = &global_hash_inputs.resolved_env_vars {
-                env.union(&resolved_global.all);
+    fn global_hash_inputs<'a>(
+        &'a self,
+        root_external_dependencies_hash: Option<&'a str>,
+        root_internal_dependencies_hash: Option<&'a str>,
+    ) -> Result<GlobalHashableInputs<'a>, Error> {
+        let root_workspace = self
+            .pkg_dep_graph
+            .package_info(&PackageName::Root)
+            .expect("must have root workspace");
+
+        let env_mode = self.opts.run_opts.env_mode;
+        let pass_through_env = match env_mode {
+            EnvMode::Loose | EnvMode::LooseWarn => {
+                // Remove the passthroughs from hash consideration if we're explicitly loose.
+                None
             }
-            env
+            EnvMode::Strict => self.root_turbo_json.global_pass_through_env.as_deref(),
         };
 
+        Ok(get_global_hash_inputs(
+            root_external_dependencies_hash,
+            root_internal_dependencies_hash,
+            root_workspace,
+            &self.repo_root,
+            self.pkg_dep_graph.package_manager(),
+            self.pkg_dep_graph.lockfile(),
+            &self.root_turbo_json.global_deps,
+            &self.env_at_execution_start,
+            &self.root_turbo_json.global_env,
+            pass_through_env,
+            env_mode,
+            self.opts.run_opts.framework_inference,
+            &self.scm,
+        )?)
+    }
+
+    fn global_env(
+        &self,
+        global_hash_inputs: &GlobalHashableInputs,
+    ) -> Result<EnvironmentVariableMap, Error> {
+        let mut env = self
+            .env_at_execution_start
+            .from_wildcards(global_hash_inputs.pass_through_env.unwrap_or_default())
+            .map_err(Error::Env)?;
+        if let Some(resolved_global) = &global_hash_inputs.resolved_env_vars {
+            env.union(&resolved_global.all);
+        }
+        Ok(env)
+    }
+
+    fn visitor<'a>(
+        &'a self,
+        package_inputs_hashes: PackageInputsHashes,
+        global_hash: &'a str,
+        global_env: EnvironmentVariableMap,
+        ui_sender: Option<UISender>,
+        is_watch: bool,
+    ) -> Visitor<'a> {
         let run_tracker = RunTracker::new(
             self.start_at,
             self.opts.synthesize_command(),
@@ -432,21 +623,23 @@ impl Run {
             &self.repo_root,
             self.version,
             self.opts.run_opts.experimental_space_id.clone(),
+            self.opts.run_opts.anonymize_spaces,
             self.api_client.clone(),
             self.api_auth.clone(),
             Vendor::get_user(),
             &self.scm,
         );
 
-        let mut visitor = Visitor::new(
+        Visitor::new(
             self.pkg_dep_graph.clone(),
             self.run_cache.clone(),
             run_tracker,
             &self.task_access,
             &self.opts.run_opts,
             package_inputs_hashes,
+            &self.scm,
             &self.env_at_execution_start,
-            &global_hash,
+            global_hash,
             self.opts.run_opts.env_mode,
             self.color_config,
             self.processes.clone(),
@@ -454,51 +647,18 @@ impl Run {
             global_env,
             ui_sender,
             is_watch,
-        );
-
-        if self.opts.run_opts.dry_run.is_some() {
-            visitor.dry_run();
-        }
-
-        // we look for this log line to mark the start of the run
-        // in benchmarks, so please don't remove it
-        debug!("running visitor");
-
-        let errors = visitor
-            .visit(self.engine.clone(), &self.run_telemetry)
-            .await?;
-
-        let exit_code = errors
-            .iter()
-            .filter_map(|err| err.exit_code())
-            .max()
-            // We hit some error, it shouldn't be exit code 0
-            .unwrap_or(if errors.is_empty() { 0 } else { 1 });
-
-        let error_prefix = if self.opts.run_opts.is_github_actions {
-            "::error::"
-        } else {
-            ""
-        };
-        for err in &errors {
-            writeln!(std::io::stderr(), "{error_prefix}{err}").ok();
-        }
-
-        visitor
-            .finish(
-                exit_code,
-                &self.filtered_pkgs,
-                global_hash_inputs,
-                &self.engine,
-                &self.env_at_execution_start,
-                self.opts.scope_opts.pkg_inference_root.as_deref(),
-            )
-            .await?;
-
-        Ok(exit_code)
+        )
     }
 }
 
+/// The hashes printed by `turbo run --summarize-only`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HashSummary {
+    global_hash: String,
+    tasks: BTreeMap<String, String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct RunStopper {
     manager: ProcessManager,
@@ -1,7 +1,10 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
 
 use tracing::debug;
-use turbopath::{AbsoluteSystemPath, AnchoredSystemPathBuf};
+use turbopath::{AbsoluteSystemPath, AnchoredSystemPathBuf, PathError, RelativeUnixPathBuf};
 use turborepo_repository::{
     change_mapper::{
         AllPackageChangeReason, ChangeMapper, DefaultPackageChangeMapper, Error,
@@ -30,6 +33,7 @@ pub struct ScopeChangeDetector<'a> {
     change_mapper: ChangeMapper<'a, GlobalDepsPackageChangeMapper<'a>>,
     scm: &'a SCM,
     pkg_graph: &'a PackageGraph,
+    changed_files: Option<&'a HashSet<AnchoredSystemPathBuf>>,
 }
 
 impl<'a> ScopeChangeDetector<'a> {
@@ -48,9 +52,17 @@ impl<'a> ScopeChangeDetector<'a> {
             change_mapper,
             scm,
             pkg_graph,
+            changed_files: None,
         })
     }
 
+    /// Use a precomputed set of changed files instead of asking the SCM.
+    /// Any git range passed to `changed_packages` is ignored.
+    pub fn with_changed_files(mut self, changed_files: &'a HashSet<AnchoredSystemPathBuf>) -> Self {
+        self.changed_files = Some(changed_files);
+        self
+    }
+
     /// Gets the lockfile content from SCM if it has changed.
     /// Does *not* error if cannot get content, instead just
     /// returns an empty lockfile change
@@ -73,6 +85,11 @@ impl<'a> ScopeChangeDetector<'a> {
             return None;
         }
 
+        // Without a git range we have no previous lockfile to compare against
+        if self.changed_files.is_some() {
+            return Some(LockfileChange::Empty);
+        }
+
         let lockfile_path = self
             .pkg_graph
             .package_manager()
@@ -95,14 +112,18 @@ impl<'a> GitChangeDetector for ScopeChangeDetector<'a> {
         allow_unknown_objects: bool,
         merge_base: bool,
     ) -> Result<HashMap<PackageName, PackageInclusionReason>, ResolutionError> {
-        let changed_files = match self.scm.changed_files(
-            self.turbo_root,
-            from_ref,
-            to_ref,
-            include_uncommitted,
-            allow_unknown_objects,
-            merge_base,
-        )? {
+        let scm_changed_files = match self.changed_files {
+            Some(changed_files) => Ok(changed_files.clone()),
+            None => self.scm.changed_files(
+                self.turbo_root,
+                from_ref,
+                to_ref,
+                include_uncommitted,
+                allow_unknown_objects,
+                merge_base,
+            )?,
+        };
+        let changed_files = match scm_changed_files {
             Err(InvalidRange { from_ref, to_ref }) => {
                 debug!("all packages changed");
                 return Ok(self
@@ -162,3 +183,27 @@ impl<'a> GitChangeDetector for ScopeChangeDetector<'a> {
         }
     }
 }
+
+/// Reads a newline-delimited list of changed files. Each path is expected to
+/// be relative to the repository root and use `/` as a separator, which
+/// matches the output of `git diff --name-only`. Blank lines are ignored.
+pub fn read_changed_files(
+    mut reader: impl io::Read,
+) -> Result<HashSet<AnchoredSystemPathBuf>, ChangedFilesError> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| Ok(RelativeUnixPathBuf::new(line)?.to_anchored_system_path_buf()))
+        .collect()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChangedFilesError {
+    #[error("failed to read changed files: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid changed file path: {0}")]
+    Path(#[from] PathError),
+}
@@ -122,8 +122,11 @@ impl<'a> FilterResolver<'a, ScopeChangeDetector<'a>> {
             .map(|s| s.as_str())
             .chain(root_turbo_json.global_deps.iter().map(|s| s.as_str()));
 
-        let change_detector =
+        let mut change_detector =
             ScopeChangeDetector::new(turbo_root, scm, pkg_graph, global_deps, vec![])?;
+        if let Some(changed_files) = &opts.changed_files {
+            change_detector = change_detector.with_changed_files(changed_files);
+        }
 
         Ok(Self::new_with_change_detector(
             pkg_graph,
@@ -723,7 +726,9 @@ mod test {
 
     use super::{FilterResolver, PackageInference, TargetSelector};
     use crate::run::scope::{
-        change_detector::GitChangeDetector, target_selector::GitRange, ResolutionError,
+        change_detector::{read_changed_files, GitChangeDetector, ScopeChangeDetector},
+        target_selector::GitRange,
+        ResolutionError,
     };
 
     fn get_name(name: &str) -> (Option<&str>, &str) {
@@ -1347,6 +1352,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_changed_files_from_list() {
+        let (_tempdir, resolver) = make_project(
+            &[("packages/app", "packages/lib")],
+            &["packages/other"],
+            None,
+            TestChangeDetector::new(&[]),
+        );
+
+        let file_list = "packages/lib/src/index.ts\n\npackages/lib/README.md\n";
+        let changed_files = read_changed_files(file_list.as_bytes()).unwrap();
+        let change_detector = ScopeChangeDetector::new(
+            resolver.turbo_root,
+            resolver.scm,
+            resolver.pkg_graph,
+            std::iter::empty(),
+            vec![],
+        )
+        .unwrap()
+        .with_changed_files(&changed_files);
+        let resolver = FilterResolver::new_with_change_detector(
+            resolver.pkg_graph,
+            resolver.turbo_root,
+            None,
+            resolver.scm,
+            change_detector,
+        );
+
+        let (packages, is_all_packages) = resolver.resolve(&Some((None, None)), &[]).unwrap();
+        assert!(!is_all_packages);
+        assert_eq!(
+            packages.into_keys().collect::<HashSet<_>>(),
+            ["app", "lib"].into_iter().map(PackageName::from).collect()
+        );
+    }
+
     struct TestChangeDetector<'a>(
         HashMap<(&'a str, Option<&'a str>), HashMap<PackageName, PackageInclusionReason>>,
     );
@@ -5,6 +5,7 @@ pub mod target_selector;
 
 use std::collections::HashMap;
 
+pub use change_detector::{read_changed_files, ChangedFilesError};
 use filter::{FilterResolver, PackageInference};
 use turbopath::AbsoluteSystemPath;
 use turborepo_repository::{
@@ -36,5 +37,5 @@ pub fn resolve_packages(
         scm,
         root_turbo_json,
     )?
-    .resolve(&opts.affected_range, &opts.get_filters())
+    .resolve(&opts.get_affected_range(), &opts.get_filters())
 }
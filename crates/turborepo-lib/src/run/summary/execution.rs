@@ -38,6 +38,8 @@ pub struct ExecutionSummary<'a> {
     cached: usize,
     // number of tasks that started
     attempted: usize,
+    // percentage of started tasks that had a cache hit, rounded to the nearest whole number
+    cache_hit_rate: u32,
     pub(crate) start_time: i64,
     pub(crate) end_time: i64,
     #[serde(skip)]
@@ -61,6 +63,7 @@ impl<'a> ExecutionSummary<'a> {
             failed: state.failed,
             cached: state.cached,
             attempted: state.attempted,
+            cache_hit_rate: cache_hit_rate(state.cached, state.attempted),
             // We're either at some path in the repo, or at the root, which is an empty path
             repo_path: package_inference_root.unwrap_or_else(|| AnchoredSystemPath::empty()),
             start_time: start_time.timestamp_millis(),
@@ -99,11 +102,10 @@ impl<'a> ExecutionSummary<'a> {
             (
                 "Cached",
                 format!(
-                    "{}, {} total",
-                    color!(ui, BOLD, "{} cached", self.cached),
-                    self.attempted
-                )
-                .to_string(),
+                    "{} ({}% hit rate)",
+                    color!(ui, BOLD, "{}/{} tasks cached", self.cached, self.attempted),
+                    self.cache_hit_rate
+                ),
             ),
             (
                 "Time",
@@ -166,6 +168,13 @@ impl<'a> ExecutionSummary<'a> {
     }
 }
 
+fn cache_hit_rate(cached: usize, attempted: usize) -> u32 {
+    if attempted == 0 {
+        return 0;
+    }
+    (cached as f64 / attempted as f64 * 100.0).round() as u32
+}
+
 /// The final states of all task executions
 #[derive(Debug, Default, Clone)]
 pub struct SummaryState {
@@ -200,6 +209,7 @@ pub struct TaskTracker<T> {
     sender: mpsc::Sender<Message>,
     started_at: T,
     task_id: TaskId<'static>,
+    peak_memory: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -227,13 +237,20 @@ pub struct TaskExecutionSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     pub exit_code: Option<i32>,
+    /// The peak resident set size of the task's processes in bytes, only
+    /// present if the task ran on a platform where memory can be sampled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_memory: Option<u64>,
+    /// Set if the task exited with its `noopExitCode`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub noop: bool,
 }
 
 impl TaskExecutionSummary {
     pub fn is_failure(&self) -> bool {
         // We consider None as a failure as it indicates the task failed to start
         // or was killed in a manner where we didn't collect an exit code.
-        !matches!(self.exit_code, Some(0))
+        !self.noop && !matches!(self.exit_code, Some(0))
     }
 }
 
@@ -269,6 +286,7 @@ impl ExecutionTracker {
             sender: self.sender.clone(),
             task_id,
             started_at: (),
+            peak_memory: None,
         }
     }
 
@@ -307,6 +325,7 @@ impl TaskTracker<()> {
             sender,
             started_at,
             task_id,
+            peak_memory: None,
         }
     }
 
@@ -334,11 +353,50 @@ impl TaskTracker<chrono::DateTime<Local>> {
     // internal turbo error
     pub fn cancel(self) {}
 
+    pub fn with_peak_memory(mut self, peak_memory: Option<u64>) -> Self {
+        self.peak_memory = peak_memory;
+        self
+    }
+
+    /// The task was stopped before it finished because nobody was reading its
+    /// output anymore. It's kept in the summary without counting towards
+    /// successful or failed tasks.
+    pub async fn stopped(self) {
+        let Self {
+            sender,
+            started_at,
+            task_id,
+            peak_memory,
+        } = self;
+
+        let ended_at = Local::now();
+        let execution = TaskExecutionSummary {
+            start_time: started_at.timestamp_millis(),
+            end_time: ended_at.timestamp_millis(),
+            exit_code: None,
+            error: None,
+            peak_memory,
+            noop: false,
+        };
+
+        sender
+            .send(TrackerMessage {
+                event: Event::Canceled,
+                state: Some(TaskState {
+                    task_id,
+                    execution: Some(execution),
+                }),
+            })
+            .await
+            .expect("summary state thread finished");
+    }
+
     pub async fn cached(self) -> TaskExecutionSummary {
         let Self {
             sender,
             started_at,
             task_id,
+            ..
         } = self;
 
         let ended_at = Local::now();
@@ -348,6 +406,8 @@ impl TaskTracker<chrono::DateTime<Local>> {
             // Go synthesizes a zero exit code on cache hits
             exit_code: Some(0),
             error: None,
+            peak_memory: None,
+            noop: false,
         };
 
         let state = TaskState {
@@ -365,10 +425,20 @@ impl TaskTracker<chrono::DateTime<Local>> {
     }
 
     pub async fn build_succeeded(self, exit_code: i32) -> TaskExecutionSummary {
+        self.built(exit_code, false).await
+    }
+
+    /// The task exited with its noop exit code
+    pub async fn build_noop(self, exit_code: i32) -> TaskExecutionSummary {
+        self.built(exit_code, true).await
+    }
+
+    async fn built(self, exit_code: i32, noop: bool) -> TaskExecutionSummary {
         let Self {
             sender,
             started_at,
             task_id,
+            peak_memory,
         } = self;
 
         let ended_at = Local::now();
@@ -377,6 +447,8 @@ impl TaskTracker<chrono::DateTime<Local>> {
             end_time: ended_at.timestamp_millis(),
             exit_code: Some(exit_code),
             error: None,
+            peak_memory,
+            noop,
         };
 
         let state = TaskState {
@@ -402,6 +474,7 @@ impl TaskTracker<chrono::DateTime<Local>> {
             sender,
             started_at,
             task_id,
+            peak_memory,
         } = self;
 
         let ended_at = Local::now();
@@ -410,6 +483,8 @@ impl TaskTracker<chrono::DateTime<Local>> {
             end_time: ended_at.timestamp_millis(),
             exit_code,
             error: Some(error.to_string()),
+            peak_memory,
+            noop: false,
         };
 
         let state = TaskState {
@@ -493,6 +568,23 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_stopped_task() {
+        let summary = ExecutionTracker::new();
+        let foo = TaskId::new("foo", "build");
+        let tracker = summary.task_tracker(foo.clone()).start().await;
+        tracker.stopped().await;
+
+        let state = summary.finish().await.unwrap();
+        assert_eq!(state.attempted, 1);
+        assert_eq!(state.failed, 0);
+        assert_eq!(state.success, 0);
+        assert_eq!(state.tasks.len(), 1);
+        let execution = state.tasks[0].execution.as_ref().unwrap();
+        assert_eq!(state.tasks[0].task_id, foo);
+        assert_eq!(execution.exit_code, None);
+    }
+
     #[tokio::test]
     async fn test_timing() {
         let summary = ExecutionTracker::new();
@@ -523,22 +615,83 @@ mod test {
             start_time: 123,
             end_time: 234,
             exit_code: Some(0),
-            error: None
+            error: None,
+            peak_memory: None,
+            noop: false,
         },
         json!({ "startTime": 123, "endTime": 234, "exitCode": 0 })
         ; "success"
     )]
+    #[test_case(
+        TaskExecutionSummary {
+            start_time: 123,
+            end_time: 234,
+            exit_code: Some(0),
+            error: None,
+            peak_memory: Some(52428800),
+            noop: false,
+        },
+        json!({ "startTime": 123, "endTime": 234, "exitCode": 0, "peakMemory": 52428800 })
+        ; "peak memory"
+    )]
     #[test_case(
         TaskExecutionSummary {
             start_time: 123,
             end_time: 234,
             exit_code: Some(1),
             error: Some("cannot find anything".into()),
+            peak_memory: None,
+            noop: false,
         },
         json!({ "startTime": 123, "endTime": 234, "exitCode": 1, "error": "cannot find anything" })
         ; "failure"
     )]
+    #[test_case(
+        TaskExecutionSummary {
+            start_time: 123,
+            end_time: 234,
+            exit_code: Some(78),
+            error: None,
+            peak_memory: None,
+            noop: true,
+        },
+        json!({ "startTime": 123, "endTime": 234, "exitCode": 78, "noop": true })
+        ; "noop"
+    )]
     fn test_serialization(value: impl serde::Serialize, expected: serde_json::Value) {
         assert_eq!(serde_json::to_value(value).unwrap(), expected);
     }
+
+    #[test_case(Some(0), false, false ; "success")]
+    #[test_case(Some(78), false, true ; "failure")]
+    #[test_case(Some(78), true, false ; "noop")]
+    #[test_case(None, false, true ; "no exit code")]
+    fn test_is_failure(exit_code: Option<i32>, noop: bool, expected: bool) {
+        let summary = TaskExecutionSummary {
+            start_time: 123,
+            end_time: 234,
+            exit_code,
+            error: None,
+            peak_memory: None,
+            noop,
+        };
+        assert_eq!(summary.is_failure(), expected);
+    }
+
+    #[test_case(0, 0, 0 ; "no tasks")]
+    #[test_case(1, 4, 25 ; "some hits")]
+    #[test_case(2, 3, 67 ; "rounds to nearest")]
+    #[test_case(3, 3, 100 ; "full turbo")]
+    fn test_cache_hit_rate(cached: usize, attempted: usize, expected: u32) {
+        let state = SummaryState {
+            attempted,
+            cached,
+            success: attempted - cached,
+            ..Default::default()
+        };
+        let now = Local::now();
+        let summary = ExecutionSummary::new("turbo run build".into(), state, None, 0, now, now);
+        let value = serde_json::to_value(summary).unwrap();
+        assert_eq!(value["cacheHitRate"], json!(expected));
+    }
 }
@@ -9,9 +9,10 @@ mod execution;
 mod global_hash;
 mod scm;
 mod spaces;
+mod spaces_queue;
 mod task;
 mod task_factory;
-use std::{collections::HashSet, io, io::Write};
+use std::{borrow::Cow, collections::HashSet, io, io::Write};
 
 use chrono::{DateTime, Local};
 pub use duration::TurboDuration;
@@ -20,6 +21,7 @@ pub use global_hash::GlobalHashSummary;
 use itertools::Itertools;
 use serde::Serialize;
 pub use spaces::{SpacesTaskClient, SpacesTaskInformation};
+pub use spaces_queue::SpacesQueue;
 use svix_ksuid::{Ksuid, KsuidLike};
 use tabwriter::TabWriter;
 use thiserror::Error;
@@ -76,6 +78,20 @@ pub enum Error {
 // of env vars (unknown run summary versions will be ignored on the server)
 const RUN_SUMMARY_SCHEMA_VERSION: &str = "1";
 
+/// Version of the JSON printed by `turbo run --dry=json`. This must be bumped
+/// whenever a field is removed, renamed, or changes type so tooling parsing the
+/// output can detect it. Adding fields doesn't require a bump.
+const DRY_RUN_JSON_SCHEMA_VERSION: &str = "1";
+
+// Wraps a summary with the schema version when printing a dry run
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunJson<T> {
+    schema_version: &'static str,
+    #[serde(flatten)]
+    summary: T,
+}
+
 #[derive(Debug)]
 enum RunType {
     Real,
@@ -97,6 +113,7 @@ pub struct RunSummary<'a> {
     packages: Vec<&'a PackageName>,
     env_mode: EnvMode,
     framework_inference: bool,
+    hash_version: u32,
     tasks: Vec<TaskSummary>,
     user: String,
     scm: SCMState,
@@ -105,11 +122,33 @@ pub struct RunSummary<'a> {
     #[serde(skip)]
     should_save: bool,
     #[serde(skip)]
+    csv_path: Option<AbsoluteSystemPathBuf>,
+    #[serde(skip)]
     run_type: RunType,
     #[serde(skip)]
     spaces_client_handle: Option<SpacesClientHandle>,
 }
 
+/// The outcome of a run, for consumers that drive turbo directly instead of
+/// going through the CLI.
+#[derive(Debug, Default)]
+pub struct RunResult {
+    pub exit_code: i32,
+    pub tasks: Vec<TaskOutcome>,
+    /// The run summary in the same shape that `--summarize` writes to disk.
+    /// Only serialized for `Run::run_with_result`.
+    pub summary: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskOutcome {
+    pub task_id: String,
+    pub hash: String,
+    pub cache_hit: bool,
+    /// Missing for dry runs and for tasks that never reported an exit code
+    pub exit_code: Option<i32>,
+}
+
 /// We use this to track the run, so it's constructed before the run.
 #[derive(Debug)]
 pub struct RunTracker {
@@ -132,6 +171,7 @@ impl RunTracker {
         repo_root: &AbsoluteSystemPath,
         version: &'static str,
         spaces_id: Option<String>,
+        anonymize_spaces: bool,
         spaces_api_client: APIClient,
         api_auth: Option<APIAuth>,
         user: String,
@@ -142,6 +182,9 @@ impl RunTracker {
         let spaces_client_handle =
             SpacesClient::new(spaces_id.clone(), spaces_api_client, api_auth).and_then(
                 |spaces_client| {
+                    let spaces_client = spaces_client
+                        .with_anonymized_names(anonymize_spaces)
+                        .with_queue(SpacesQueue::new(repo_root));
                     let payload = CreateSpaceRunPayload::new(
                         started_at,
                         synthesized_command.clone(),
@@ -189,6 +232,10 @@ impl RunTracker {
     ) -> Result<RunSummary<'a>, Error> {
         let single_package = run_opts.single_package;
         let should_save = run_opts.summarize;
+        let csv_path = run_opts
+            .csv_output
+            .as_ref()
+            .map(|path| AbsoluteSystemPathBuf::from_unknown(repo_root, path));
 
         let run_type = match run_opts.dry_run {
             None => RunType::Real,
@@ -221,6 +268,7 @@ impl RunTracker {
             execution: Some(execution_summary),
             env_mode: global_env_mode,
             framework_inference: run_opts.framework_inference,
+            hash_version: run_opts.hash_algorithm.version(),
             tasks,
             global_hash_summary,
             scm: self.scm,
@@ -228,6 +276,7 @@ impl RunTracker {
             monorepo: !single_package,
             repo_root,
             should_save,
+            csv_path,
             run_type,
             spaces_client_handle: self.spaces_client_handle,
         })
@@ -259,7 +308,9 @@ impl RunTracker {
         hash_tracker: TaskHashTracker,
         env_at_execution_start: &'a EnvironmentVariableMap,
         is_watch: bool,
-    ) -> Result<(), Error> {
+        include_summary: bool,
+        stdout_closed: bool,
+    ) -> Result<RunResult, Error> {
         let end_time = Local::now();
 
         let task_factory = TaskSummaryFactory::new(
@@ -286,7 +337,15 @@ impl RunTracker {
             .await?;
 
         run_summary
-            .finish(end_time, exit_code, pkg_dep_graph, ui, is_watch)
+            .finish(
+                end_time,
+                exit_code,
+                pkg_dep_graph,
+                ui,
+                is_watch,
+                include_summary,
+                stdout_closed,
+            )
             .await
     }
 
@@ -323,6 +382,7 @@ struct SinglePackageRunSummary<'a> {
     global_hash_summary: &'a GlobalHashSummary<'a>,
     env_mode: EnvMode,
     framework_inference: bool,
+    hash_version: u32,
     tasks: Vec<SinglePackageTaskSummary>,
     user: &'a str,
     pub scm: &'a SCMState,
@@ -346,6 +406,7 @@ impl<'a> From<&'a RunSummary<'a>> for SinglePackageRunSummary<'a> {
             global_hash_summary: &run_summary.global_hash_summary,
             env_mode: run_summary.env_mode,
             framework_inference: run_summary.framework_inference,
+            hash_version: run_summary.hash_version,
             tasks,
             user: &run_summary.user,
             scm: &run_summary.scm,
@@ -362,9 +423,12 @@ impl<'a> RunSummary<'a> {
         pkg_dep_graph: &PackageGraph,
         ui: ColorConfig,
         is_watch: bool,
-    ) -> Result<(), Error> {
+        include_summary: bool,
+        stdout_closed: bool,
+    ) -> Result<RunResult, Error> {
         if matches!(self.run_type, RunType::DryJson | RunType::DryText) {
-            return self.close_dry_run(pkg_dep_graph, ui);
+            self.close_dry_run(pkg_dep_graph, ui)?;
+            return self.into_result(exit_code, include_summary);
         }
 
         if self.should_save {
@@ -373,7 +437,13 @@ impl<'a> RunSummary<'a> {
             }
         }
 
-        if !is_watch {
+        if let Some(csv_path) = &self.csv_path {
+            if let Err(err) = self.save_csv(csv_path) {
+                warn!("Error writing task timings to {}: {}", csv_path, err)
+            }
+        }
+
+        if !is_watch && !stdout_closed {
             if let Some(execution) = &self.execution {
                 let path = self.get_path();
                 let failed_tasks = self.get_failed_tasks();
@@ -382,11 +452,36 @@ impl<'a> RunSummary<'a> {
         }
 
         if let Some(spaces_client_handle) = self.spaces_client_handle.take() {
-            self.send_to_space(spaces_client_handle, end_time, exit_code)
+            self.send_to_space(spaces_client_handle, end_time, exit_code, stdout_closed)
                 .await;
         }
 
-        Ok(())
+        self.into_result(exit_code, include_summary)
+    }
+
+    fn into_result(self, exit_code: i32, include_summary: bool) -> Result<RunResult, Error> {
+        let summary = include_summary
+            .then(|| serde_json::to_value(&self))
+            .transpose()?;
+        let tasks = self
+            .tasks
+            .into_iter()
+            .map(|task| TaskOutcome {
+                task_id: task.task_id.to_string(),
+                hash: task.shared.hash,
+                cache_hit: task.shared.cache.is_hit(),
+                exit_code: task
+                    .shared
+                    .execution
+                    .and_then(|execution| execution.exit_code),
+            })
+            .collect();
+
+        Ok(RunResult {
+            exit_code,
+            tasks,
+            summary,
+        })
     }
 
     #[tracing::instrument(skip_all)]
@@ -395,6 +490,7 @@ impl<'a> RunSummary<'a> {
         spaces_client_handle: SpacesClientHandle,
         ended_at: DateTime<Local>,
         exit_code: i32,
+        stdout_closed: bool,
     ) {
         let spinner = tokio::spawn(async {
             tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
@@ -413,7 +509,7 @@ impl<'a> RunSummary<'a> {
 
         Self::print_errors(&result.errors);
 
-        if let Some(run) = result.run {
+        if let Some(run) = result.run.filter(|_| !stdout_closed) {
             println!("Run: {}\n", run.url);
         }
     }
@@ -731,16 +827,13 @@ impl<'a> RunSummary<'a> {
     fn format_json(&mut self) -> Result<String, Error> {
         self.normalize();
 
-        let mut rendered_json = if self.monorepo {
-            serde_json::to_string_pretty(&self)
+        if self.monorepo {
+            render_json(&self.run_type, &*self)
         } else {
             // Deref coercion used to get an immutable reference from the mutable reference.
             let monorepo_rsm = SinglePackageRunSummary::from(&*self);
-            serde_json::to_string_pretty(&monorepo_rsm)
-        }?;
-        // Go produces an extra newline at the end of the JSON
-        rendered_json.push('\n');
-        Ok(rendered_json)
+            render_json(&self.run_type, monorepo_rsm)
+        }
     }
 
     fn normalize(&mut self) {
@@ -791,4 +884,124 @@ impl<'a> RunSummary<'a> {
 
         Ok(summary_path.create_with_contents(json)?)
     }
+
+    fn save_csv(&self, path: &AbsoluteSystemPath) -> Result<(), Error> {
+        let rows = self.tasks.iter().map(|task| CsvRow {
+            task: &task.task,
+            package: &task.package,
+            duration_ms: task
+                .shared
+                .execution
+                .as_ref()
+                .map(|execution| execution.end_time - execution.start_time),
+            cache_hit: task.shared.cache.is_hit(),
+        });
+        let csv = render_csv(rows);
+
+        path.ensure_dir()?;
+        Ok(path.create_with_contents(csv)?)
+    }
+}
+
+struct CsvRow<'a> {
+    task: &'a str,
+    package: &'a str,
+    // Missing if the task wasn't executed
+    duration_ms: Option<i64>,
+    cache_hit: bool,
+}
+
+const CSV_HEADER: &str = "task,package,duration_ms,cache_status";
+
+fn render_csv<'a>(rows: impl IntoIterator<Item = CsvRow<'a>>) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+    for row in rows {
+        let duration = row
+            .duration_ms
+            .map(|duration| duration.to_string())
+            .unwrap_or_default();
+        let cache_status = if row.cache_hit { "HIT" } else { "MISS" };
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(row.task),
+            csv_field(row.package),
+            duration,
+            cache_status
+        ));
+    }
+    csv
+}
+
+// Quotes a field if it contains characters that are meaningful in CSV
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+fn render_json(run_type: &RunType, summary: impl Serialize) -> Result<String, Error> {
+    let mut rendered_json = if matches!(run_type, RunType::DryJson) {
+        serde_json::to_string_pretty(&DryRunJson {
+            schema_version: DRY_RUN_JSON_SCHEMA_VERSION,
+            summary,
+        })
+    } else {
+        serde_json::to_string_pretty(&summary)
+    }?;
+    // Go produces an extra newline at the end of the JSON
+    rendered_json.push('\n');
+    Ok(rendered_json)
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_dry_run_json_has_schema_version() {
+        let summary = json!({ "tasks": [], "turboVersion": "2.0.0" });
+
+        let rendered: serde_json::Value =
+            serde_json::from_str(&render_json(&RunType::DryJson, &summary).unwrap()).unwrap();
+        assert_eq!(rendered["schemaVersion"], DRY_RUN_JSON_SCHEMA_VERSION);
+        assert_eq!(rendered["turboVersion"], "2.0.0");
+
+        // Run summaries written to disk are versioned separately
+        let rendered: serde_json::Value =
+            serde_json::from_str(&render_json(&RunType::Real, &summary).unwrap()).unwrap();
+        assert!(rendered.get("schemaVersion").is_none());
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let rows = [
+            CsvRow {
+                task: "build",
+                package: "web",
+                duration_ms: Some(1532),
+                cache_hit: false,
+            },
+            CsvRow {
+                task: "lint",
+                package: "@scope/ui,legacy",
+                duration_ms: Some(12),
+                cache_hit: true,
+            },
+        ];
+
+        let csv = render_csv(rows);
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "task,package,duration_ms,cache_status",
+                "build,web,1532,MISS",
+                "lint,\"@scope/ui,legacy\",12,HIT",
+            ]
+        );
+    }
 }
@@ -9,6 +9,7 @@ use chrono::{DateTime, Local};
 use futures::{stream::FuturesUnordered, StreamExt};
 use itertools::Itertools;
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 use tokio::{sync::mpsc::Sender, task::JoinHandle};
 use tracing::debug;
 use turborepo_api_client::{
@@ -18,10 +19,16 @@ use turborepo_api_client::{
 use turborepo_cache::CacheHitMetadata;
 use turborepo_vercel_api::SpaceRun;
 
-use super::execution::TaskExecutionSummary;
+use super::{
+    execution::TaskExecutionSummary,
+    spaces_queue::{QueuedSpaceRun, SpacesQueue},
+};
 use crate::{
     engine::TaskNode,
-    run::{summary::Error, task_id::TaskId},
+    run::{
+        summary::Error,
+        task_id::{TaskId, TASK_DELIMITER},
+    },
 };
 
 // There's a 4.5 MB limit on serverless requests, we limit ourselves to a
@@ -34,6 +41,8 @@ pub struct SpacesClient {
     api_client: APIClient,
     api_auth: APIAuth,
     request_timeout: Duration,
+    anonymize: bool,
+    queue: Option<SpacesQueue>,
 }
 
 /// Once the client is done, we return any errors
@@ -50,12 +59,14 @@ pub struct SpacesClientResult {
 pub struct SpacesClientHandle {
     handle: JoinHandle<Result<SpacesClientResult, Error>>,
     tx: Sender<SpaceRequest>,
+    anonymize: bool,
 }
 
 /// A spaces client with functionality limited to sending task information
 /// This client should only live while processing a task
 pub struct SpacesTaskClient {
     tx: Sender<SpaceRequest>,
+    anonymize: bool,
 }
 
 /// Information required to construct a SpacesTaskSummary
@@ -81,6 +92,7 @@ impl SpacesClientHandle {
     pub fn task_client(&self) -> SpacesTaskClient {
         SpacesTaskClient {
             tx: self.tx.clone(),
+            anonymize: self.anonymize,
         }
     }
 
@@ -126,12 +138,15 @@ impl SpacesTaskClient {
     }
 
     pub async fn finish_task<'a>(&self, info: SpacesTaskInformation<'a>) -> Result<(), Error> {
-        let summary = SpaceTaskSummary::from(info);
+        let mut summary = SpaceTaskSummary::from(info);
+        if self.anonymize {
+            anonymize_task_summary(&mut summary);
+        }
         self.send_task(summary).await
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(tag = "status", rename_all = "lowercase")]
 pub enum SpaceRequest {
     FinishedRun { end_time: i64, exit_code: i32 },
@@ -158,21 +173,67 @@ impl SpacesClient {
             api_client,
             api_auth,
             request_timeout: Duration::from_secs(10),
+            anonymize: false,
+            queue: None,
         })
     }
 
+    /// Requests that fail are written to `queue` instead of being dropped.
+    /// Queued runs are retried once a run is sent successfully.
+    pub fn with_queue(mut self, queue: SpacesQueue) -> Self {
+        self.queue = Some(queue);
+        self
+    }
+
+    fn enqueue(&self, queued: &QueuedSpaceRun, errors: &mut Vec<Error>) {
+        if let Some(queue) = &self.queue {
+            if let Err(e) = queue.enqueue(queued) {
+                errors.push(e);
+            }
+        }
+    }
+
+    async fn drain_queue(&self) {
+        let Some(queue) = &self.queue else {
+            return;
+        };
+        match queue.drain(&self.api_client, &self.api_auth).await {
+            Ok(0) => {}
+            Ok(flushed) => debug!("sent {flushed} queued space runs"),
+            Err(e) => debug!("error sending queued space runs: {}", e),
+        }
+    }
+
+    /// Replace package and task names with hashed tokens in everything sent
+    /// to spaces. The local run summary is unaffected.
+    pub fn with_anonymized_names(mut self, anonymize: bool) -> Self {
+        self.anonymize = anonymize;
+        self
+    }
+
     pub fn start(
         self,
-        create_run_payload: CreateSpaceRunPayload,
+        mut create_run_payload: CreateSpaceRunPayload,
     ) -> Result<SpacesClientHandle, Error> {
+        let anonymize = self.anonymize;
+        if anonymize {
+            anonymize_run_payload(&mut create_run_payload);
+        }
         let (tx, mut rx) = tokio::sync::mpsc::channel(100);
         let handle = tokio::spawn(async move {
             let mut errors = Vec::new();
-            let run = match self.create_run(create_run_payload).await {
+            let run = match self.create_run(create_run_payload.clone()).await {
                 Ok(run) => run,
                 Err(e) => {
                     debug!("error creating space run: {}", e);
                     errors.push(e);
+                    // Hold on to the rest of the run so it can be sent later
+                    let mut queued =
+                        QueuedSpaceRun::create(self.space_id.clone(), create_run_payload);
+                    while let Some(req) = rx.recv().await {
+                        queued.push(req);
+                    }
+                    self.enqueue(&queued, &mut errors);
                     return Ok(SpacesClientResult { errors, run: None });
                 }
             };
@@ -181,32 +242,46 @@ impl SpacesClient {
 
             let mut requests = FuturesUnordered::new();
             while let Some(req) = rx.recv().await {
-                let request = match req {
+                let request = match &req {
                     SpaceRequest::FinishedRun {
                         end_time,
                         exit_code,
-                    } => self.finish_run_handler(&run, end_time, exit_code),
+                    } => self.finish_run_handler(&run, *end_time, *exit_code),
                     SpaceRequest::FinishedTask { summary } => {
-                        self.finish_task_handler(*summary, &run)
+                        self.finish_task_handler(summary.as_ref().clone(), &run)
                     }
                 };
-                requests.push(request)
+                requests.push(async move { (req, request.await) })
             }
 
-            while let Some(response) = requests.next().await {
+            let mut queued = QueuedSpaceRun::created(self.space_id.clone(), run.id.clone());
+            while let Some((req, response)) = requests.next().await {
                 let response = response.expect("spaces request panicked");
                 if let Err(e) = response {
                     errors.push(e);
+                    queued.push(req);
                 }
             }
 
+            if queued.is_empty() {
+                // Spaces is reachable, so this is a good time to send any runs
+                // that failed to upload previously
+                self.drain_queue().await;
+            } else {
+                self.enqueue(&queued, &mut errors);
+            }
+
             Ok(SpacesClientResult {
                 errors,
                 run: Some(run),
             })
         });
 
-        Ok(SpacesClientHandle { handle, tx })
+        Ok(SpacesClientHandle {
+            handle,
+            tx,
+            anonymize,
+        })
     }
 
     async fn create_run(&self, payload: CreateSpaceRunPayload) -> Result<SpaceRun, Error> {
@@ -324,6 +399,49 @@ impl<'a> From<SpacesTaskInformation<'a>> for SpaceTaskSummary {
     }
 }
 
+/// Produces a stable token for a package or task name.
+/// The token only depends on the name so the mapping can be recomputed
+/// locally from the run summary.
+fn anonymize_name(name: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(name.as_bytes());
+    hex::encode(&hasher.finalize()[..8])
+}
+
+fn anonymize_task_id(task_id: &str) -> String {
+    match task_id.split_once(TASK_DELIMITER) {
+        Some((package, task)) => format!(
+            "{}{TASK_DELIMITER}{}",
+            anonymize_name(package),
+            anonymize_name(task)
+        ),
+        None => anonymize_name(task_id),
+    }
+}
+
+fn anonymize_task_summary(summary: &mut SpaceTaskSummary) {
+    summary.key = anonymize_task_id(&summary.key);
+    summary.name = anonymize_name(&summary.name);
+    summary.workspace = anonymize_name(&summary.workspace);
+    for dependency in summary
+        .dependencies
+        .iter_mut()
+        .chain(summary.dependents.iter_mut())
+    {
+        *dependency = anonymize_task_id(dependency);
+    }
+    // Task output can contain anything, including the names we're hiding
+    summary.logs.clear();
+}
+
+fn anonymize_run_payload(payload: &mut CreateSpaceRunPayload) {
+    // The command contains task names and filters so we don't send it at all
+    payload.command = "turbo run".to_string();
+    if !payload.package_inference_root.is_empty() {
+        payload.package_inference_root = anonymize_name(&payload.package_inference_root);
+    }
+}
+
 fn trim_logs(logs: &[u8], limit: usize) -> String {
     // Go JSON encoding automatically did a lossy conversion for us when
     // encoding Golang strings into JSON.
@@ -363,8 +481,15 @@ mod tests {
         EXPECTED_TEAM_SLUG, EXPECTED_TOKEN,
     };
 
-    use super::trim_logs;
-    use crate::run::summary::spaces::SpacesClient;
+    use super::{trim_logs, SpacesTaskInformation};
+    use crate::run::{
+        summary::{
+            execution::TaskExecutionSummary,
+            spaces::{anonymize_name, anonymize_task_summary, SpacesClient},
+            spaces_queue::SpacesQueue,
+        },
+        task_id::TaskId,
+    };
 
     #[test_case(vec![] ; "empty")]
     #[test_case(vec![SpaceTaskSummary::default()] ; "one task summary")]
@@ -425,6 +550,130 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_failed_upload_is_queued() -> Result<()> {
+        let tmp = tempfile::tempdir()?;
+        let repo_root = turbopath::AbsoluteSystemPathBuf::try_from(tmp.path())?;
+        let queue = SpacesQueue::new(&repo_root);
+        let port = port_scanner::request_open_port().unwrap();
+        let api_client = || {
+            APIClient::new(
+                format!("http://localhost:{}", port),
+                Some(Duration::from_secs(2)),
+                None,
+                "",
+                true,
+            )
+        };
+        let api_auth = APIAuth {
+            token: EXPECTED_TOKEN.to_string(),
+            team_id: Some(EXPECTED_TEAM_ID.to_string()),
+            team_slug: Some(EXPECTED_TEAM_SLUG.to_string()),
+        };
+
+        // Nothing is listening on the port yet so every request fails
+        let spaces_client = SpacesClient::new(
+            Some(EXPECTED_SPACE_ID.to_string()),
+            api_client()?,
+            Some(api_auth.clone()),
+        )
+        .unwrap()
+        .with_queue(queue.clone());
+        let spaces_client_handle = spaces_client.start(CreateSpaceRunPayload::new(
+            Local::now(),
+            "turbo run build".to_string(),
+            None,
+            None,
+            None,
+            "".to_string(),
+            "rauchg".to_string(),
+        ))?;
+        spaces_client_handle
+            .task_client()
+            .send_task(SpaceTaskSummary {
+                key: "web#build".to_string(),
+                ..Default::default()
+            })
+            .await?;
+        spaces_client_handle.finish_run(0, Local::now()).await?;
+        let spaces_client_result = spaces_client_handle.close().await;
+
+        assert!(spaces_client_result.run.is_none());
+        assert!(!spaces_client_result.errors.is_empty());
+        let queued = queue.entries()?;
+        assert_eq!(queued.len(), 1);
+        assert!(queued[0].read_to_string()?.contains("web#build"));
+
+        // A queue that another process is draining is left alone
+        {
+            let mut lock = pidlock::Pidlock::new(
+                repo_root
+                    .join_components(&[".turbo", "spaces-queue", ".lock"])
+                    .into(),
+            );
+            lock.acquire()?;
+            assert_eq!(queue.drain(&api_client()?, &api_auth).await?, 0);
+            assert_eq!(queue.entries()?.len(), 1);
+        }
+
+        // Once the server is up the queued run gets sent and removed
+        let handle = tokio::spawn(start_test_server(port));
+        // Draining doesn't retry, so wait until the server accepts connections
+        while tokio::net::TcpStream::connect(("localhost", port))
+            .await
+            .is_err()
+        {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(queue.drain(&api_client()?, &api_auth).await?, 1);
+        assert!(queue.entries()?.is_empty());
+
+        handle.abort();
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymized_task_summary() {
+        let task_id = TaskId::new("web", "build").into_owned();
+        let dependency = crate::engine::TaskNode::Task(TaskId::new("ui", "build").into_owned());
+        let info = SpacesTaskInformation {
+            task_id: task_id.clone(),
+            execution_summary: TaskExecutionSummary {
+                start_time: 0,
+                end_time: 1,
+                error: None,
+                exit_code: Some(0),
+                peak_memory: None,
+                noop: false,
+            },
+            logs: b"building web".to_vec(),
+            hash: "abc123".to_string(),
+            cache_status: None,
+            dependencies: Some([&dependency].into_iter().collect()),
+            dependents: None,
+        };
+        let mut summary = SpaceTaskSummary::from(info);
+        anonymize_task_summary(&mut summary);
+
+        let (web, ui, build) = (
+            anonymize_name("web"),
+            anonymize_name("ui"),
+            anonymize_name("build"),
+        );
+        assert_eq!(summary.key, format!("{web}#{build}"));
+        assert_eq!(summary.workspace, web);
+        assert_eq!(summary.name, build);
+        assert_eq!(summary.dependencies, vec![format!("{ui}#{build}")]);
+        assert_eq!(summary.hash, "abc123");
+        assert!(summary.logs.is_empty());
+
+        let payload = serde_json::to_string(&summary).unwrap();
+        assert!(!payload.contains("web"));
+        assert!(!payload.contains("\"ui"));
+        // The task id used for the local run summary keeps the real names
+        assert_eq!(task_id.to_string(), "web#build");
+    }
+
     #[test_case(b"abcdef", 4, "cdef" ; "trims from the front of the logs")]
     #[test_case(b"abcdef", 6, "abcdef" ; "doesn't trim when logs are under limit")]
     #[test_case(&[240, 159, 146, 150, b'o', b'k'], 4, "ok" ; "doesn't cut in between utf8 chars")]
@@ -0,0 +1,187 @@
+//! On-disk queue for spaces uploads that failed, e.g. because the network was
+//! unavailable at the end of a run. Queued runs are retried the next time a
+//! run is sent to spaces or via `turbo flush-summaries`.
+
+use std::{collections::VecDeque, io};
+
+use serde::{Deserialize, Serialize};
+use svix_ksuid::{Ksuid, KsuidLike};
+use tracing::debug;
+use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
+use turborepo_api_client::{
+    spaces::{CreateSpaceRunPayload, SpaceTaskSummary},
+    APIAuth, APIClient,
+};
+
+use super::{spaces::SpaceRequest, Error};
+
+/// The requests for a single run that haven't made it to spaces yet
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedSpaceRun {
+    space_id: String,
+    run: QueuedRun,
+    tasks: VecDeque<SpaceTaskSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    finish_run: Option<FinishedRun>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum QueuedRun {
+    /// The run was never created on the server
+    Create(Box<CreateSpaceRunPayload>),
+    /// The run exists on the server with the given id
+    Created(String),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FinishedRun {
+    end_time: i64,
+    exit_code: i32,
+}
+
+impl QueuedSpaceRun {
+    pub fn create(space_id: String, payload: CreateSpaceRunPayload) -> Self {
+        Self {
+            space_id,
+            run: QueuedRun::Create(Box::new(payload)),
+            tasks: VecDeque::with_capacity(0),
+            finish_run: None,
+        }
+    }
+
+    pub fn created(space_id: String, run_id: String) -> Self {
+        Self {
+            space_id,
+            run: QueuedRun::Created(run_id),
+            tasks: VecDeque::with_capacity(0),
+            finish_run: None,
+        }
+    }
+
+    pub fn push(&mut self, request: SpaceRequest) {
+        match request {
+            SpaceRequest::FinishedTask { summary } => self.tasks.push_back(*summary),
+            SpaceRequest::FinishedRun {
+                end_time,
+                exit_code,
+            } => {
+                self.finish_run = Some(FinishedRun {
+                    end_time,
+                    exit_code,
+                })
+            }
+        }
+    }
+
+    /// Returns true if there is nothing left to send
+    pub fn is_empty(&self) -> bool {
+        matches!(self.run, QueuedRun::Created(_))
+            && self.tasks.is_empty()
+            && self.finish_run.is_none()
+    }
+
+    /// Sends the queued requests in order. On failure the requests that were
+    /// successfully sent are removed so only the remainder gets retried.
+    async fn upload(&mut self, api_client: &APIClient, api_auth: &APIAuth) -> Result<(), Error> {
+        let run_id = match &self.run {
+            QueuedRun::Created(run_id) => run_id.clone(),
+            QueuedRun::Create(payload) => {
+                let run = api_client
+                    .create_space_run(&self.space_id, api_auth, (**payload).clone())
+                    .await?;
+                self.run = QueuedRun::Created(run.id.clone());
+                run.id
+            }
+        };
+
+        while let Some(task) = self.tasks.front() {
+            api_client
+                .create_task_summary(&self.space_id, &run_id, api_auth, task.clone())
+                .await?;
+            self.tasks.pop_front();
+        }
+
+        if let Some(FinishedRun {
+            end_time,
+            exit_code,
+        }) = self.finish_run
+        {
+            api_client
+                .finish_space_run(&self.space_id, &run_id, api_auth, end_time, exit_code)
+                .await?;
+            self.finish_run = None;
+        }
+
+        Ok(())
+    }
+}
+
+/// A directory of queued runs, one JSON file per run
+#[derive(Debug, Clone)]
+pub struct SpacesQueue {
+    dir: AbsoluteSystemPathBuf,
+}
+
+impl SpacesQueue {
+    pub fn new(repo_root: &AbsoluteSystemPath) -> Self {
+        Self {
+            dir: repo_root.join_components(&[".turbo", "spaces-queue"]),
+        }
+    }
+
+    pub fn enqueue(&self, run: &QueuedSpaceRun) -> Result<(), Error> {
+        let path = self
+            .dir
+            .join_component(&format!("{}.json", Ksuid::new(None, None)));
+        path.ensure_dir()?;
+        path.create_with_contents(serde_json::to_string(run)?)?;
+        debug!("queued spaces run at {path}");
+        Ok(())
+    }
+
+    /// Paths of all queued runs, oldest first
+    pub fn entries(&self) -> Result<Vec<AbsoluteSystemPathBuf>, Error> {
+        let dir = match std::fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut entries = dir
+            .filter_map(|entry| AbsoluteSystemPathBuf::try_from(entry.ok()?.path()).ok())
+            .filter(|path| path.extension() == Some("json"))
+            .collect::<Vec<_>>();
+        // Ksuids sort by creation time
+        entries.sort();
+        Ok(entries)
+    }
+
+    /// Attempts to upload every queued run, returning how many were flushed.
+    /// We stop at the first failure as it most likely means the network is
+    /// still unavailable; that run is written back with any progress made.
+    ///
+    /// The queue is locked while draining so concurrent runs don't upload the
+    /// same requests twice. If another process holds the lock we leave the
+    /// queue to it.
+    pub async fn drain(&self, api_client: &APIClient, api_auth: &APIAuth) -> Result<usize, Error> {
+        let mut lock = pidlock::Pidlock::new(self.dir.join_component(".lock").into());
+        if let Err(err) = lock.acquire() {
+            debug!("not draining spaces queue, failed to lock it: {err}");
+            return Ok(0);
+        }
+
+        let mut flushed = 0;
+        for path in self.entries()? {
+            let mut run: QueuedSpaceRun = serde_json::from_str(&path.read_to_string()?)?;
+            if let Err(err) = run.upload(api_client, api_auth).await {
+                path.create_with_contents(serde_json::to_string(&run)?)?;
+                return Err(err);
+            }
+            path.remove_file()?;
+            flushed += 1;
+        }
+        Ok(flushed)
+    }
+}
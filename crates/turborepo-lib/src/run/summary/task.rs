@@ -97,6 +97,7 @@ pub struct TaskEnvConfiguration {
 pub struct TaskSummaryTaskDefinition {
     outputs: Vec<String>,
     cache: bool,
+    cache_readonly: bool,
     depends_on: Vec<String>,
     inputs: Vec<String>,
     output_logs: OutputLogsMode,
@@ -105,6 +106,12 @@ pub struct TaskSummaryTaskDefinition {
     pass_through_env: Option<Vec<String>>,
     interactive: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    ready_output: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    concurrency_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    noop_exit_code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     env_mode: Option<EnvMode>,
 }
 
@@ -119,6 +126,10 @@ pub struct TaskEnvVarSummary {
 }
 
 impl TaskCacheSummary {
+    pub fn is_hit(&self) -> bool {
+        matches!(self.status, CacheStatus::Hit)
+    }
+
     pub fn cache_miss() -> Self {
         Self {
             local: false,
@@ -273,6 +284,7 @@ impl From<TaskDefinition> for TaskSummaryTaskDefinition {
                     exclusions,
                 },
             cache,
+            cache_readonly,
             mut env,
             pass_through_env,
             topological_dependencies,
@@ -281,6 +293,9 @@ impl From<TaskDefinition> for TaskSummaryTaskDefinition {
             output_logs,
             persistent,
             interactive,
+            ready_output,
+            concurrency_group,
+            noop_exit_code,
             env_mode,
         } = value;
 
@@ -309,6 +324,7 @@ impl From<TaskDefinition> for TaskSummaryTaskDefinition {
         Self {
             outputs,
             cache,
+            cache_readonly,
             depends_on,
             inputs,
             output_logs,
@@ -316,6 +332,9 @@ impl From<TaskDefinition> for TaskSummaryTaskDefinition {
             interactive,
             env,
             pass_through_env,
+            ready_output,
+            concurrency_group,
+            noop_exit_code,
             env_mode,
         }
     }
@@ -363,11 +382,13 @@ mod test {
         TaskSummaryTaskDefinition {
             outputs: vec!["foo".into()],
             cache: true,
+            cache_readonly: true,
             ..Default::default()
         },
         json!({
             "outputs": ["foo"],
             "cache": true,
+            "cacheReadonly": true,
             "dependsOn": [],
             "inputs": [],
             "outputLogs": "full",
@@ -378,6 +399,67 @@ mod test {
         })
         ; "resolved task definition"
     )]
+    #[test_case(
+        TaskSummaryTaskDefinition {
+            persistent: true,
+            ready_output: Some("ready on port".into()),
+            ..Default::default()
+        },
+        json!({
+            "outputs": [],
+            "cache": false,
+            "cacheReadonly": false,
+            "dependsOn": [],
+            "inputs": [],
+            "outputLogs": "full",
+            "persistent": true,
+            "interactive": false,
+            "env": [],
+            "passThroughEnv": null,
+            "readyOutput": "ready on port",
+        })
+        ; "persistent task with ready output"
+    )]
+    #[test_case(
+        TaskSummaryTaskDefinition {
+            concurrency_group: Some("db".into()),
+            ..Default::default()
+        },
+        json!({
+            "outputs": [],
+            "cache": false,
+            "cacheReadonly": false,
+            "dependsOn": [],
+            "inputs": [],
+            "outputLogs": "full",
+            "persistent": false,
+            "interactive": false,
+            "env": [],
+            "passThroughEnv": null,
+            "concurrencyGroup": "db",
+        })
+        ; "task in a concurrency group"
+    )]
+    #[test_case(
+        TaskSummaryTaskDefinition {
+            noop_exit_code: Some(78),
+            ..Default::default()
+        },
+        json!({
+            "outputs": [],
+            "cache": false,
+            "cacheReadonly": false,
+            "dependsOn": [],
+            "inputs": [],
+            "outputLogs": "full",
+            "persistent": false,
+            "interactive": false,
+            "env": [],
+            "passThroughEnv": null,
+            "noopExitCode": 78,
+        })
+        ; "task with a noop exit code"
+    )]
     fn test_serialization(value: impl serde::Serialize, expected: serde_json::Value) {
         assert_eq!(serde_json::to_value(value).unwrap(), expected);
     }
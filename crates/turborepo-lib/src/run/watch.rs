@@ -14,7 +14,7 @@ use turborepo_telemetry::events::command::CommandEventBuilder;
 use turborepo_ui::sender::UISender;
 
 use crate::{
-    cli::{Command, RunArgs},
+    cli::{Command, LogFormat, RunArgs},
     commands::{self, CommandBase},
     daemon::{proto, DaemonConnectorError, DaemonError},
     get_version, opts,
@@ -165,7 +165,7 @@ impl WatchClient {
 
         let mut events = client.package_changes().await?;
 
-        if !self.run.has_tui() {
+        if !self.run.has_tui() && !matches!(self.base.args().log_format, Some(LogFormat::Json)) {
             self.run.print_run_prelude();
         }
 
@@ -3,12 +3,13 @@ mod local_turbo_state;
 mod parser;
 mod turbo_state;
 
-use std::{backtrace::Backtrace, env, process, process::Stdio, time::Duration};
+use std::{backtrace::Backtrace, env, io, process, process::Stdio, time::Duration};
 
 use dunce::canonicalize as fs_canonicalize;
 use local_turbo_config::LocalTurboConfig;
-use local_turbo_state::{turbo_version_has_shim, LocalTurboState};
-use miette::{Diagnostic, SourceSpan};
+use local_turbo_state::turbo_version_has_shim;
+pub(crate) use local_turbo_state::LocalTurboState;
+use miette::{Diagnostic, Report, SourceSpan};
 use parser::{MultipleCwd, ShimArgs};
 use thiserror::Error;
 use tiny_gradient::{GradientStr, RGB};
@@ -280,6 +281,14 @@ fn try_check_for_updates(args: &ShimArgs, current_version: &str) {
     }
 }
 
+/// Renders `error` to `out` through miette, returning the code turbo exits
+/// with.
+pub fn report(error: Error, out: &mut impl io::Write) -> i32 {
+    // If this fails there's nowhere left to report the error to
+    writeln!(out, "{:?}", Report::new(error)).ok();
+    1
+}
+
 pub fn run() -> Result<i32, Error> {
     let args = ShimArgs::parse()?;
     let color_config = args.color_config();
@@ -294,7 +303,8 @@ pub fn run() -> Result<i32, Error> {
             )
         }));
     }
-    let subscriber = TurboSubscriber::new_with_verbosity(args.verbosity, &color_config);
+    let subscriber =
+        TurboSubscriber::new_with_verbosity(args.verbosity, args.log_format, &color_config);
 
     debug!("Global turbo version: {}", get_version());
 
@@ -311,12 +321,14 @@ pub fn run() -> Result<i32, Error> {
     if is_turbo_binary_path_set() {
         let repo_state = RepoState::infer(&args.cwd)?;
         debug!("Repository Root: {}", repo_state.root);
+        repo_state.check_package_manager_version(args.strict_package_manager)?;
         return Ok(cli::run(Some(repo_state), &subscriber, color_config)?);
     }
 
     match RepoState::infer(&args.cwd) {
         Ok(repo_state) => {
             debug!("Repository Root: {}", repo_state.root);
+            repo_state.check_package_manager_version(args.strict_package_manager)?;
             run_correct_turbo(repo_state, args, &subscriber, color_config)
         }
         Err(err) => {
@@ -328,3 +340,59 @@ pub fn run() -> Result<i32, Error> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use miette::{GraphicalReportHandler, GraphicalTheme};
+
+    use super::{report, Error};
+    use crate::{cli, config};
+
+    fn render(error: &Error) -> String {
+        let mut rendered = String::new();
+        GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor())
+            .render_report(&mut rendered, error)
+            .unwrap();
+        rendered
+    }
+
+    #[test]
+    fn test_renders_error_source() {
+        let error = Error::LocalTurboProcess(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No such file or directory",
+        ));
+        assert_eq!(
+            render(&error),
+            "  × failed to execute local turbo process\n  ╰─▶ No such file or directory\n"
+        );
+    }
+
+    #[test]
+    fn test_report_returns_exit_code() {
+        let error = Error::LocalTurboProcess(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No such file or directory",
+        ));
+        let mut out = Vec::new();
+        assert_eq!(report(error, &mut out), 1);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("failed to execute local turbo process"));
+        assert!(out.contains("No such file or directory"));
+    }
+
+    #[test]
+    fn test_renders_nested_error_through_transparent_variants() {
+        let error = Error::Cli(cli::Error::Config(config::Error::UnknownEnvironment {
+            name: "local".to_string(),
+            available: "ci".to_string(),
+        }));
+        assert_eq!(
+            render(&error),
+            "  × environment `local` isn't defined in turbo.json, available environments: ci\n"
+        );
+    }
+}
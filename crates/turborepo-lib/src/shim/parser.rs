@@ -1,11 +1,13 @@
 use std::{backtrace::Backtrace, env};
 
+use clap::ValueEnum;
 use itertools::Itertools;
 use miette::{Diagnostic, SourceSpan};
 use turbopath::AbsoluteSystemPathBuf;
 use turborepo_ui::ColorConfig;
 
 use super::Error;
+use crate::cli::LogFormat;
 
 // all arguments that result in a stdout that much be directly parsable and
 // should not be paired with additional output (from the update notifier for
@@ -47,7 +49,9 @@ pub struct ShimArgs {
     pub invocation_dir: AbsoluteSystemPathBuf,
     pub skip_infer: bool,
     pub verbosity: usize,
+    pub log_format: LogFormat,
     pub force_update_check: bool,
+    pub strict_package_manager: bool,
     pub remaining_turbo_args: Vec<String>,
     pub forwarded_args: Vec<String>,
     pub color: bool,
@@ -61,7 +65,10 @@ impl ShimArgs {
         let mut skip_infer = false;
         let mut found_verbosity_flag = false;
         let mut verbosity = 0;
+        let mut found_log_format_flag = false;
+        let mut log_format = LogFormat::default();
         let mut force_update_check = false;
+        let mut strict_package_manager = false;
         let mut remaining_turbo_args = Vec::new();
         let mut forwarded_args = Vec::new();
         let mut is_forwarded_args = false;
@@ -77,6 +84,10 @@ impl ShimArgs {
                 skip_infer = true;
             } else if arg == "--check-for-update" {
                 force_update_check = true;
+            } else if arg == "--strict-package-manager" {
+                // Only the shim checks the package manager, so older local turbos
+                // never see this flag
+                strict_package_manager = true;
             } else if arg == "--" {
                 // If we've hit `--` we've reached the args forwarded to tasks.
                 is_forwarded_args = true;
@@ -94,6 +105,21 @@ impl ShimArgs {
 
                 verbosity = verbosity_count.parse::<usize>().unwrap_or(0);
                 remaining_turbo_args.push(arg);
+            } else if arg == "--log-format" {
+                // If we see `--log-format` we expect the next arg to be the format.
+                remaining_turbo_args.push(arg);
+                found_log_format_flag = true
+            } else if arg.starts_with("--log-format=") || found_log_format_flag {
+                let format = if found_log_format_flag {
+                    found_log_format_flag = false;
+                    &arg
+                } else {
+                    arg.strip_prefix("--log-format=").unwrap_or_default()
+                };
+
+                // Invalid values are left for clap to report
+                log_format = LogFormat::from_str(format, true).unwrap_or_default();
+                remaining_turbo_args.push(arg);
             } else if arg == "-v" || arg.starts_with("-vv") {
                 verbosity = arg[1..].len();
                 remaining_turbo_args.push(arg);
@@ -157,7 +183,9 @@ impl ShimArgs {
             invocation_dir,
             skip_infer,
             verbosity,
+            log_format,
             force_update_check,
+            strict_package_manager,
             remaining_turbo_args,
             forwarded_args,
             color,
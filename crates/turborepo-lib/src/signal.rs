@@ -128,7 +128,7 @@ impl HandlerState {
 
 #[cfg(test)]
 mod test {
-    use std::{assert_matches::assert_matches, time::Duration};
+    use std::{assert_matches, time::Duration};
 
     use super::*;
 
@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Serializes tasks that share a `concurrencyGroup`. Each group gets a
+/// semaphore with a single permit so at most one of its tasks runs at a time,
+/// regardless of whether they depend on each other.
+#[derive(Debug, Default)]
+pub(crate) struct ConcurrencyGroups {
+    groups: HashMap<String, Arc<Semaphore>>,
+}
+
+impl ConcurrencyGroups {
+    /// Returns a future that resolves once the task can run. The returned
+    /// permit must be held until the task finishes.
+    pub fn acquire(
+        &mut self,
+        group: &str,
+    ) -> impl std::future::Future<Output = OwnedSemaphorePermit> {
+        let semaphore = self
+            .groups
+            .entry(group.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(1)))
+            .clone();
+        async move {
+            semaphore
+                .acquire_owned()
+                .await
+                .expect("concurrency group semaphore is never closed")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        future::Future,
+        time::{Duration, Instant},
+    };
+
+    use tokio::sync::OwnedSemaphorePermit;
+
+    use super::ConcurrencyGroups;
+
+    async fn run_migration(
+        permit: impl Future<Output = OwnedSemaphorePermit>,
+    ) -> (Instant, Instant) {
+        let _permit = permit.await;
+        let start = Instant::now();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        (start, Instant::now())
+    }
+
+    #[tokio::test]
+    async fn test_tasks_in_group_do_not_overlap() {
+        let mut groups = ConcurrencyGroups::default();
+
+        let web_migrate = tokio::spawn(run_migration(groups.acquire("db")));
+        let docs_migrate = tokio::spawn(run_migration(groups.acquire("db")));
+
+        let (web_start, web_end) = web_migrate.await.unwrap();
+        let (docs_start, docs_end) = docs_migrate.await.unwrap();
+        assert!(
+            web_end <= docs_start || docs_end <= web_start,
+            "migrate tasks overlapped"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_different_groups_run_concurrently() {
+        let mut groups = ConcurrencyGroups::default();
+
+        let _db = groups.acquire("db").await;
+        let _cache = tokio::time::timeout(Duration::from_secs(1), groups.acquire("cache"))
+            .await
+            .expect("other groups should not be blocked");
+    }
+}
@@ -0,0 +1,308 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use futures::StreamExt;
+use globwatch::{ConfigError, Event, GlobWatcher, StopSource, WatchConfig, WatchError};
+use notify::{EventKind, RecommendedWatcher};
+use thiserror::Error;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::debug;
+use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, PathError};
+use turborepo_scm::package_deps::INPUT_INCLUDE_DEFAULT_FILES;
+use wax::{Glob, Program};
+
+use crate::process::{Child, ChildExit, Command, ProcessManager};
+
+// Files that tools write to while a task is running, these never restart a task
+const IGNORED: &[&str] = &["node_modules/**", ".turbo/**"];
+// Changes that happen this soon after the first change only cause one restart
+const DEBOUNCE: Duration = Duration::from_millis(100);
+// Events buffered for each task before it's considered to have missed some
+const EVENT_BUFFER: usize = 1024;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("failed to watch task inputs: {0}")]
+    Watch(#[from] WatchError),
+    #[error("failed to watch task inputs: {0:?}")]
+    Config(ConfigError),
+    #[error("invalid glob {glob}: {source}")]
+    Glob {
+        glob: String,
+        #[source]
+        source: Box<wax::BuildError>,
+    },
+    #[error(transparent)]
+    Path(#[from] PathError),
+}
+
+/// Describes which files restart a persistent task when they change.
+#[derive(Debug, Clone)]
+pub(crate) struct InputWatcherOptions {
+    // `inputs` from the task definition, relative to the package
+    pub inputs: Vec<String>,
+    // Outputs are written by the task itself so they're never inputs
+    pub outputs: Vec<String>,
+}
+
+/// A single file watcher shared by every persistent task that restarts when
+/// its inputs change.
+pub(crate) struct InputWatcher {
+    config: WatchConfig<RecommendedWatcher>,
+    events: broadcast::Sender<Event>,
+    // The watcher stops once this is dropped
+    _stop: StopSource,
+}
+
+impl InputWatcher {
+    /// Starts the watcher. Must be called from within a tokio runtime.
+    pub fn new(flush_dir: &AbsoluteSystemPath) -> Result<Self, Error> {
+        let (watcher, config) = GlobWatcher::new(flush_dir)?;
+        let stop = StopSource::new();
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
+
+        // The stream also applies config changes, so it has to be polled even
+        // while no task is waiting on an event
+        let mut stream = watcher.into_stream(stop.token());
+        let sender = events.clone();
+        tokio::spawn(async move {
+            while let Some(Ok(event)) = stream.next().await {
+                match event {
+                    // Only fails if no task is subscribed right now
+                    Ok(event) => {
+                        sender.send(event).ok();
+                    }
+                    Err(e) => debug!("error watching task inputs: {e:?}"),
+                }
+            }
+        });
+
+        Ok(Self {
+            config,
+            events,
+            _stop: stop,
+        })
+    }
+
+    /// Starts watching the inputs of a task in `package_dir`.
+    pub async fn watch(
+        &self,
+        package_dir: &AbsoluteSystemPath,
+        options: &InputWatcherOptions,
+    ) -> Result<TaskInputs, Error> {
+        // Events are reported with resolved paths
+        let package_dir = package_dir.to_realpath()?;
+
+        let mut inputs = Vec::new();
+        let mut ignored = IGNORED
+            .iter()
+            .copied()
+            .chain(options.outputs.iter().map(String::as_str))
+            .map(parse_glob)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut include_default = options.inputs.is_empty();
+        for input in &options.inputs {
+            if input == INPUT_INCLUDE_DEFAULT_FILES {
+                include_default = true;
+            } else if let Some(exclusion) = input.strip_prefix('!') {
+                ignored.push(parse_glob(exclusion)?);
+            } else {
+                inputs.push(parse_glob(input)?);
+            }
+        }
+        if include_default {
+            inputs.clear();
+        }
+
+        // Subscribe first so no event between the two calls is missed
+        let events = self.events.subscribe();
+        self.config
+            .include_dir(package_dir.as_std_path())
+            .await
+            .map_err(Error::Config)?;
+
+        Ok(TaskInputs {
+            package_dir,
+            inputs,
+            ignored,
+            events,
+        })
+    }
+}
+
+/// How a task that restarts on change finished.
+pub(crate) enum Restarted {
+    // The latest process exited on its own
+    Exited(io::Result<Option<ChildExit>>),
+    // A restarted process couldn't be spawned
+    SpawnFailed(io::Error),
+    // Turbo started shutting down while restarting the task
+    Shutdown,
+}
+
+/// The inputs of a single persistent task.
+pub(crate) struct TaskInputs {
+    package_dir: AbsoluteSystemPathBuf,
+    // Empty if every file in the package is an input
+    inputs: Vec<Glob<'static>>,
+    ignored: Vec<Glob<'static>>,
+    events: broadcast::Receiver<Event>,
+}
+
+impl TaskInputs {
+    /// Waits for `process` to exit, restarting it with `command` every time
+    /// an input changes. `on_restart` is called with the stopped process and
+    /// the one replacing it.
+    pub async fn wait_restarting<W: Write>(
+        &mut self,
+        process: &mut Child,
+        manager: &ProcessManager,
+        command: &Command,
+        mut output: W,
+        mut on_restart: impl FnMut(&Child, &mut Child),
+    ) -> Restarted {
+        loop {
+            tokio::select! {
+                exit = process.wait_with_piped_outputs(&mut output) => {
+                    return Restarted::Exited(exit);
+                }
+                () = self.changed() => (),
+            }
+
+            debug!("inputs in {} changed, restarting", self.package_dir);
+            writeln!(output, "inputs changed, restarting...").ok();
+            process.stop().await;
+            let mut restarted = match manager.spawn(command.clone(), Duration::from_millis(500)) {
+                Some(Ok(child)) => child,
+                Some(Err(e)) => return Restarted::SpawnFailed(e),
+                None => return Restarted::Shutdown,
+            };
+            on_restart(process, &mut restarted);
+            *process = restarted;
+        }
+    }
+
+    /// Waits until an input of the task changes. If the watcher stops this
+    /// never resolves, leaving the task running.
+    pub async fn changed(&mut self) {
+        loop {
+            match self.events.recv().await {
+                Ok(event) if self.is_input_change(&event) => break,
+                Ok(_) => (),
+                // Events were dropped, so one of them might have been an input
+                Err(RecvError::Lagged(_)) => break,
+                Err(RecvError::Closed) => std::future::pending::<()>().await,
+            }
+        }
+        // Let the rest of a batch of writes, e.g. from a formatter, go by
+        while let Ok(Ok(_) | Err(RecvError::Lagged(_))) =
+            tokio::time::timeout(DEBOUNCE, self.events.recv()).await
+        {}
+    }
+
+    fn is_input_change(&self, event: &Event) -> bool {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return false;
+        }
+        event.paths.iter().any(|path| {
+            let Ok(path) = path.strip_prefix(self.package_dir.as_std_path()) else {
+                return false;
+            };
+            !self.ignored.iter().any(|glob| glob.is_match(path))
+                && (self.inputs.is_empty() || self.inputs.iter().any(|glob| glob.is_match(path)))
+        })
+    }
+}
+
+fn parse_glob(glob: &str) -> Result<Glob<'static>, Error> {
+    Glob::new(glob)
+        .map(Glob::into_owned)
+        .map_err(|source| Error::Glob {
+            glob: glob.to_string(),
+            source: Box::new(source),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+    use turbopath::AbsoluteSystemPathBuf;
+
+    use super::{InputWatcher, InputWatcherOptions, Restarted};
+    use crate::process::{Command, ProcessManager};
+
+    #[tokio::test]
+    async fn test_input_change_restarts_child() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_root = AbsoluteSystemPathBuf::try_from(tmp.path()).unwrap();
+        let web_dir = repo_root.join_components(&["packages", "web"]);
+        web_dir.join_component("src").create_dir_all().unwrap();
+        let docs_dir = repo_root.join_components(&["packages", "docs"]);
+        docs_dir.create_dir_all().unwrap();
+        let input = web_dir.join_components(&["src", "index.js"]);
+        input.create_with_contents("console.log('hello')").unwrap();
+
+        let watcher =
+            InputWatcher::new(&repo_root.join_components(&[".turbo", "restart"])).unwrap();
+        let options = InputWatcherOptions {
+            inputs: vec!["src/**".to_string()],
+            outputs: vec!["dist/**".to_string()],
+        };
+        let mut web_inputs = watcher.watch(&web_dir, &options).await.unwrap();
+        // Another task on the same watcher doesn't see changes to other packages
+        let mut docs_inputs = watcher.watch(&docs_dir, &options).await.unwrap();
+
+        let manager = ProcessManager::new(false);
+        let mut command = Command::new("node");
+        command.args(["./test/scripts/sleep_5_interruptable.js"]);
+        let mut child = manager
+            .spawn(command.clone(), Duration::from_millis(500))
+            .unwrap()
+            .unwrap();
+        let first_pid = child.pid().unwrap();
+
+        let mut restarted_pids = Vec::new();
+        let mut wait = Box::pin(web_inputs.wait_restarting(
+            &mut child,
+            &manager,
+            &command,
+            Vec::new(),
+            |old, new| restarted_pids.push((old.pid(), new.pid())),
+        ));
+
+        // Writing an output doesn't count as a change
+        let output = web_dir.join_components(&["dist", "index.js"]);
+        output.ensure_dir().unwrap();
+        output.create_with_contents("").unwrap();
+        assert!(timeout(Duration::from_millis(500), &mut wait)
+            .await
+            .is_err());
+
+        // The watcher restarts the child once an input changes, the child
+        // never exits on its own before the timeout
+        input.create_with_contents("console.log('bye')").unwrap();
+        assert!(timeout(Duration::from_secs(2), &mut wait).await.is_err());
+        drop(wait);
+
+        assert_eq!(restarted_pids.len(), 1);
+        let (old_pid, new_pid) = restarted_pids[0];
+        assert_eq!(old_pid, Some(first_pid));
+        assert_eq!(new_pid, child.pid());
+        assert_ne!(child.pid(), Some(first_pid));
+        assert!(timeout(Duration::from_millis(500), docs_inputs.changed())
+            .await
+            .is_err());
+
+        manager.stop().await;
+        assert!(matches!(
+            web_inputs
+                .wait_restarting(&mut child, &manager, &command, Vec::new(), |_, _| ())
+                .await,
+            Restarted::Exited(_)
+        ));
+    }
+}
@@ -1,8 +1,14 @@
+mod concurrency_group;
+mod input_watcher;
+mod ready;
 mod visitor;
 
 use std::str::FromStr;
 
+pub(crate) use concurrency_group::ConcurrencyGroups;
 use globwalk::{GlobError, ValidatedGlob};
+pub(crate) use input_watcher::{InputWatcher, InputWatcherOptions, Restarted};
+pub(crate) use ready::ReadyWriter;
 use serde::{Deserialize, Serialize};
 use turbopath::{AnchoredSystemPath, AnchoredSystemPathBuf, RelativeUnixPathBuf};
 use turborepo_errors::Spanned;
@@ -47,6 +53,9 @@ pub struct TaskDefinition {
     pub outputs: TaskOutputs,
     pub(crate) cache: bool,
 
+    // CacheReadonly restores outputs from the cache, but never writes them
+    pub(crate) cache_readonly: bool,
+
     // This field is custom-marshalled from `env` and `depends_on``
     pub(crate) env: Vec<String>,
 
@@ -80,6 +89,19 @@ pub struct TaskDefinition {
     // input.
     pub interactive: bool,
 
+    // ReadyOutput is text that a persistent task prints once it's ready. Tasks that
+    // depend on it are started once it's printed instead of being disallowed.
+    pub ready_output: Option<String>,
+
+    // ConcurrencyGroup names a group of tasks that are never run at the same time,
+    // even if they are independent in the task graph.
+    pub concurrency_group: Option<String>,
+
+    // NoopExitCode is an exit code the task uses to report that it had nothing to do.
+    // The task is treated as successful and tasks that depend on it don't consider it
+    // changed.
+    pub noop_exit_code: Option<i32>,
+
     // Override for global env mode setting
     pub env_mode: Option<EnvMode>,
 }
@@ -88,6 +110,7 @@ impl Default for TaskDefinition {
     fn default() -> Self {
         Self {
             cache: true,
+            cache_readonly: false,
             outputs: Default::default(),
             env: Default::default(),
             pass_through_env: Default::default(),
@@ -97,6 +120,9 @@ impl Default for TaskDefinition {
             output_logs: Default::default(),
             persistent: Default::default(),
             interactive: Default::default(),
+            ready_output: Default::default(),
+            concurrency_group: Default::default(),
+            noop_exit_code: Default::default(),
             env_mode: Default::default(),
         }
     }
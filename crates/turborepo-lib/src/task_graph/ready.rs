@@ -0,0 +1,104 @@
+use std::io::Write;
+
+use tokio::sync::oneshot;
+
+use crate::engine::StopExecution;
+
+type Callback = oneshot::Sender<Result<(), StopExecution>>;
+
+/// Forwards the output of a persistent task and tells the engine that the task
+/// is finished as soon as its ready output is printed. This lets tasks that
+/// depend on a dev server start while the server keeps running.
+pub(crate) struct ReadyWriter<'a, W> {
+    inner: W,
+    ready_output: &'a str,
+    // Taken once the ready output has been seen
+    callback: &'a mut Option<Callback>,
+    // Recent output, kept in case the ready output or a color code is split
+    // across writes
+    pending: Vec<u8>,
+}
+
+impl<'a, W: Write> ReadyWriter<'a, W> {
+    pub fn new(inner: W, ready_output: &'a str, callback: &'a mut Option<Callback>) -> Self {
+        Self {
+            inner,
+            ready_output,
+            callback,
+            pending: Vec::new(),
+        }
+    }
+
+    fn check_ready(&mut self, buf: &[u8]) {
+        if self.callback.is_none() {
+            return;
+        }
+
+        self.pending.extend_from_slice(buf);
+        let output = String::from_utf8_lossy(&self.pending);
+        if console::strip_ansi_codes(&output).contains(self.ready_output) {
+            if let Some(callback) = self.callback.take() {
+                callback.send(Ok(())).ok();
+            }
+            self.pending = Vec::new();
+        } else {
+            // Only the lines that the ready output could still finish are kept
+            let lines = self.ready_output.matches('\n').count();
+            if let Some(end) = self
+                .pending
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, byte)| **byte == b'\n')
+                .map(|(i, _)| i)
+                .nth(lines)
+            {
+                self.pending.drain(..=end);
+            }
+        }
+    }
+}
+
+impl<'a, W: Write> Write for ReadyWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        // Write the output first so it shows up before any output from dependents
+        self.inner.write_all(buf)?;
+        self.check_ready(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use tokio::sync::oneshot;
+
+    use super::ReadyWriter;
+
+    #[test]
+    fn test_ready_output_split_across_writes() {
+        let (callback, mut receiver) = oneshot::channel();
+        let mut callback = Some(callback);
+        let mut output = Vec::new();
+
+        let mut writer = ReadyWriter::new(&mut output, "ready on", &mut callback);
+        writer.write_all(b"compiling...\n\x1b[32mrea").unwrap();
+        assert!(receiver.try_recv().is_err());
+        writer
+            .write_all(b"dy\x1b[0m on http://localhost:3000\n")
+            .unwrap();
+        assert!(matches!(receiver.try_recv(), Ok(Ok(()))));
+        writer.write_all(b"GET / 200\n").unwrap();
+
+        assert!(callback.is_none());
+        assert_eq!(
+            output,
+            b"compiling...\n\x1b[32mready\x1b[0m on http://localhost:3000\nGET / 200\n"
+        );
+    }
+}
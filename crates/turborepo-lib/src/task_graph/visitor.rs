@@ -1,8 +1,11 @@
 use std::{
     borrow::Cow,
     collections::HashSet,
-    io::Write,
-    sync::{Arc, Mutex, OnceLock},
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, OnceLock,
+    },
     time::{Duration, Instant},
 };
 
@@ -13,14 +16,15 @@ use itertools::Itertools;
 use miette::{Diagnostic, NamedSource, SourceSpan};
 use regex::Regex;
 use tokio::sync::{mpsc, oneshot};
-use tracing::{debug, error, Instrument, Span};
+use tracing::{debug, error, warn, Instrument, Span};
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPath};
 use turborepo_ci::{Vendor, VendorBehavior};
 use turborepo_env::EnvironmentVariableMap;
 use turborepo_repository::{
-    package_graph::{PackageGraph, PackageName, ROOT_PKG_NAME},
+    package_graph::{PackageGraph, PackageName, PackageNode, ROOT_PKG_NAME},
     package_manager::PackageManager,
 };
+use turborepo_scm::SCM;
 use turborepo_telemetry::events::{
     generic::GenericEventBuilder, task::PackageTaskEventBuilder, EventBuilder, TrackedErrors,
 };
@@ -36,24 +40,34 @@ use crate::{
     config::UIMode,
     engine::{Engine, ExecutionOptions, StopExecution},
     opts::RunOpts,
-    process::{ChildExit, Command, ProcessManager},
+    process::{Child, ChildExit, Command, ProcessManager},
     run::{
         global_hash::GlobalHashableInputs,
+        invocation_depth,
         summary::{
-            self, GlobalHashSummary, RunTracker, SpacesTaskClient, SpacesTaskInformation,
-            TaskExecutionSummary, TaskTracker,
+            self, GlobalHashSummary, RunResult, RunTracker, SpacesTaskClient,
+            SpacesTaskInformation, TaskExecutionSummary, TaskTracker,
         },
         task_access::TaskAccess,
         task_id::TaskId,
         CacheOutput, RunCache, TaskCache,
     },
-    task_hash::{self, PackageInputsHashes, TaskHashTracker, TaskHashTrackerState, TaskHasher},
+    task_graph::{ConcurrencyGroups, InputWatcher, InputWatcherOptions, ReadyWriter, Restarted},
+    task_hash::{
+        self, undeclared_env_vars, PackageInputsHashes, TaskHashTracker, TaskHashTrackerState,
+        TaskHasher,
+    },
+    tracing::TASK_SPAN,
 };
 
 // This holds the whole world
 pub struct Visitor<'a> {
     color_cache: ColorSelector,
     dry: bool,
+    // Set when a dry run only computes hashes and doesn't check the cache
+    hash_only: bool,
+    // Set when the run summary should be serialized into the run's result
+    include_summary: bool,
     global_env: EnvironmentVariableMap,
     global_env_mode: EnvMode,
     manager: ProcessManager,
@@ -68,6 +82,8 @@ pub struct Visitor<'a> {
     color_config: ColorConfig,
     is_watch: bool,
     ui_sender: Option<UISender>,
+    // Set once writing to stdout fails because the reader went away
+    stdout_closed: Arc<AtomicBool>,
 }
 
 #[derive(Debug, thiserror::Error, Diagnostic)]
@@ -112,6 +128,7 @@ impl<'a> Visitor<'a> {
         task_access: &'a TaskAccess,
         run_opts: &'a RunOpts,
         package_inputs_hashes: PackageInputsHashes,
+        scm: &'a SCM,
         env_at_execution_start: &'a EnvironmentVariableMap,
         global_hash: &'a str,
         global_env_mode: EnvMode,
@@ -124,6 +141,8 @@ impl<'a> Visitor<'a> {
     ) -> Self {
         let task_hasher = TaskHasher::new(
             package_inputs_hashes,
+            scm,
+            repo_root,
             run_opts,
             env_at_execution_start,
             global_hash,
@@ -139,6 +158,8 @@ impl<'a> Visitor<'a> {
         Self {
             color_cache,
             dry: false,
+            hash_only: false,
+            include_summary: false,
             global_env_mode,
             manager,
             run_opts,
@@ -153,6 +174,7 @@ impl<'a> Visitor<'a> {
             global_env,
             ui_sender,
             is_watch,
+            stdout_closed: Arc::default(),
         }
     }
 
@@ -162,10 +184,6 @@ impl<'a> Visitor<'a> {
         engine: Arc<Engine>,
         telemetry: &GenericEventBuilder,
     ) -> Result<Vec<TaskError>, Error> {
-        for task in engine.tasks().sorted() {
-            self.color_cache.color_for_key(&task.to_string());
-        }
-
         let concurrency = self.run_opts.concurrency as usize;
         let (node_sender, mut node_stream) = mpsc::channel(concurrency);
 
@@ -178,6 +196,7 @@ impl<'a> Visitor<'a> {
         let span = Span::current();
 
         let factory = ExecContextFactory::new(self, errors.clone(), self.manager.clone(), &engine);
+        let mut concurrency_groups = ConcurrencyGroups::default();
 
         while let Some(message) = node_stream.recv().await {
             let span = tracing::debug_span!(parent: &span, "queue_task", task = %message.info);
@@ -223,6 +242,19 @@ impl<'a> Visitor<'a> {
             package_task_event.track_env_mode(&task_env_mode.to_string());
 
             let dependency_set = engine.dependencies(&info).ok_or(Error::MissingDefinition)?;
+            // Dependencies have finished by now, so their outputs can be hashed
+            let dependency_packages: Vec<_> = self
+                .package_graph
+                .immediate_dependencies(&PackageNode::Workspace(package_name.clone()))
+                .into_iter()
+                .flatten()
+                .filter_map(|node| match node {
+                    PackageNode::Workspace(dependency) => {
+                        self.package_graph.package_info(dependency)
+                    }
+                    PackageNode::Root => None,
+                })
+                .collect();
 
             let task_hash_telemetry = package_task_event.child();
             let task_hash = self.task_hasher.calculate_task_hash(
@@ -230,6 +262,7 @@ impl<'a> Visitor<'a> {
                 task_definition,
                 task_env_mode,
                 workspace_info,
+                &dependency_packages,
                 dependency_set,
                 task_hash_telemetry,
             )?;
@@ -241,6 +274,22 @@ impl<'a> Visitor<'a> {
             let execution_env =
                 self.task_hasher
                     .env(&info, task_env_mode, task_definition, &self.global_env)?;
+            if let (EnvMode::LooseWarn, Some(command)) = (task_env_mode, &command) {
+                let strict_env = self.task_hasher.env(
+                    &info,
+                    EnvMode::Strict,
+                    task_definition,
+                    &self.global_env,
+                )?;
+                let undeclared = undeclared_env_vars(command, &execution_env, &strict_env);
+                if !undeclared.is_empty() {
+                    warn!(
+                        "{info} uses environment variables that are not declared in turbo.json \
+                         and will be unavailable in strict mode: {}",
+                        undeclared.join(", ")
+                    );
+                }
+            }
 
             let task_cache = self.run_cache.task_cache(
                 task_definition,
@@ -275,6 +324,12 @@ impl<'a> Visitor<'a> {
                     let workspace_directory = self.repo_root.resolve(workspace_info.package_path());
 
                     let takes_input = task_definition.interactive || task_definition.persistent;
+                    let input_watcher_options = (self.run_opts.restart_on_change
+                        && task_definition.persistent)
+                        .then(|| InputWatcherOptions {
+                            inputs: task_definition.inputs.clone(),
+                            outputs: task_definition.outputs.inclusions.clone(),
+                        });
                     let mut exec_context = factory.exec_context(
                         info.clone(),
                         task_hash,
@@ -282,6 +337,9 @@ impl<'a> Visitor<'a> {
                         workspace_directory,
                         execution_env,
                         takes_input,
+                        task_definition.ready_output.clone(),
+                        task_definition.noop_exit_code,
+                        input_watcher_options,
                         self.task_access.clone(),
                     );
 
@@ -297,8 +355,17 @@ impl<'a> Visitor<'a> {
                     let spaces_client = self.run_tracker.spaces_task_client();
                     let parent_span = Span::current();
                     let execution_telemetry = package_task_event.child();
+                    let concurrency_group_permit = task_definition
+                        .concurrency_group
+                        .as_deref()
+                        .map(|group| concurrency_groups.acquire(group));
 
                     tasks.push(tokio::spawn(async move {
+                        // Held until the task finishes so other tasks in the group wait for it
+                        let _permit = match concurrency_group_permit {
+                            Some(permit) => Some(permit.await),
+                            None => None,
+                        };
                         exec_context
                             .execute(
                                 parent_span.id(),
@@ -367,7 +434,7 @@ impl<'a> Visitor<'a> {
         engine: &Engine,
         env_at_execution_start: &EnvironmentVariableMap,
         pkg_inference_root: Option<&AnchoredSystemPath>,
-    ) -> Result<(), Error> {
+    ) -> Result<RunResult, Error> {
         let Self {
             package_graph,
             color_config: ui,
@@ -376,6 +443,8 @@ impl<'a> Visitor<'a> {
             global_env_mode,
             task_hasher,
             is_watch,
+            include_summary,
+            stdout_closed,
             ..
         } = self;
 
@@ -397,6 +466,10 @@ impl<'a> Visitor<'a> {
                 task_hasher.task_hash_tracker(),
                 env_at_execution_start,
                 is_watch,
+                include_summary,
+                // There's nowhere left to print the summary if the reader of our
+                // output went away, e.g. when piping into `head`
+                stdout_closed.load(Ordering::Relaxed),
             )
             .await?)
     }
@@ -493,8 +566,7 @@ impl<'a> Visitor<'a> {
         prefixed_ui
     }
 
-    /// Only used for the hashing comparison between Rust and Go. After port,
-    /// should delete
+    /// Returns the hashes of the visited tasks
     pub fn into_task_hash_tracker(self) -> TaskHashTrackerState {
         self.task_hasher.into_task_hash_tracker_state()
     }
@@ -504,6 +576,18 @@ impl<'a> Visitor<'a> {
         // No need to start a UI on dry run
         self.ui_sender = None;
     }
+
+    /// Performs a dry run that only computes task hashes, the cache isn't
+    /// checked for the tasks
+    pub fn hash_only(&mut self) {
+        self.dry_run();
+        self.hash_only = true;
+    }
+
+    /// Includes the serialized run summary in the result of `finish`
+    pub fn include_summary(&mut self) {
+        self.include_summary = true;
+    }
 }
 
 // A tiny enum that allows us to use the same type for stdout and stderr without
@@ -597,6 +681,12 @@ pub enum InternalError {
     Logs(#[from] crate::run::CacheError),
 }
 
+impl InternalError {
+    fn is_broken_pipe(&self) -> bool {
+        matches!(self, InternalError::Io(e) if e.kind() == io::ErrorKind::BrokenPipe)
+    }
+}
+
 impl TaskError {
     pub fn exit_code(&self) -> Option<i32> {
         match self.cause {
@@ -639,6 +729,8 @@ struct ExecContextFactory<'a> {
     errors: Arc<Mutex<Vec<TaskError>>>,
     manager: ProcessManager,
     engine: &'a Arc<Engine>,
+    // Shared by every task that restarts when its inputs change
+    input_watcher: Option<Arc<InputWatcher>>,
 }
 
 impl<'a> ExecContextFactory<'a> {
@@ -648,11 +740,22 @@ impl<'a> ExecContextFactory<'a> {
         manager: ProcessManager,
         engine: &'a Arc<Engine>,
     ) -> Self {
+        let input_watcher = visitor
+            .run_opts
+            .restart_on_change
+            .then(|| InputWatcher::new(&visitor.repo_root.join_components(&[".turbo", "restart"])))
+            .and_then(|watcher| {
+                watcher
+                    .inspect_err(|e| warn!("unable to restart tasks on change: {e}"))
+                    .ok()
+            })
+            .map(Arc::new);
         Self {
             visitor,
             errors,
             manager,
             engine,
+            input_watcher,
         }
     }
 
@@ -665,11 +768,14 @@ impl<'a> ExecContextFactory<'a> {
         workspace_directory: AbsoluteSystemPathBuf,
         execution_env: EnvironmentVariableMap,
         takes_input: bool,
+        ready_output: Option<String>,
+        noop_exit_code: Option<i32>,
+        input_watcher_options: Option<InputWatcherOptions>,
         task_access: TaskAccess,
     ) -> ExecContext {
         let task_id_for_display = self.visitor.display_task_id(&task_id);
         let pass_through_args = self.visitor.run_opts.args_for_task(&task_id);
-        let task_id_string = &task_id.to_string();
+        let materialized_hash_file = materialized_hash_file(self.visitor.repo_root, &task_id);
         ExecContext {
             engine: self.engine.clone(),
             ui_mode: self.visitor.run_opts.ui_mode,
@@ -678,7 +784,7 @@ impl<'a> ExecContextFactory<'a> {
             pretty_prefix: self
                 .visitor
                 .color_cache
-                .prefix_with_color(task_id_string, &self.visitor.prefix(&task_id)),
+                .prefix_with_color(task_id.package(), &self.visitor.prefix(&task_id)),
             task_id,
             task_id_for_display,
             task_cache,
@@ -689,10 +795,17 @@ impl<'a> ExecContextFactory<'a> {
             task_hash,
             execution_env,
             continue_on_error: self.visitor.run_opts.continue_on_error,
+            max_failures: self.visitor.run_opts.max_failures,
             pass_through_args,
             errors: self.errors.clone(),
             takes_input,
+            ready_output,
+            noop_exit_code,
+            materialized_hash_file,
+            input_watcher: self.input_watcher.clone().zip(input_watcher_options),
             task_access,
+            stdout_closed: self.visitor.stdout_closed.clone(),
+            peak_memory: None,
         }
     }
 
@@ -703,7 +816,7 @@ impl<'a> ExecContextFactory<'a> {
     ) -> DryRunExecContext {
         DryRunExecContext {
             task_id,
-            task_cache,
+            task_cache: (!self.visitor.hash_only).then_some(task_cache),
             hash_tracker: self.visitor.task_hasher.task_hash_tracker(),
         }
     }
@@ -725,10 +838,22 @@ struct ExecContext {
     task_hash: String,
     execution_env: EnvironmentVariableMap,
     continue_on_error: bool,
+    max_failures: Option<usize>,
     pass_through_args: Option<Vec<String>>,
     errors: Arc<Mutex<Vec<TaskError>>>,
     takes_input: bool,
+    ready_output: Option<String>,
+    // Exit code the task uses to report that it had nothing to do
+    noop_exit_code: Option<i32>,
+    // Where the hash of the task's last run that did something is kept, only
+    // used if it has a noop exit code
+    materialized_hash_file: AbsoluteSystemPathBuf,
+    // Set if the task should be restarted when its inputs change
+    input_watcher: Option<(Arc<InputWatcher>, InputWatcherOptions)>,
     task_access: TaskAccess,
+    stdout_closed: Arc<AtomicBool>,
+    // The highest memory use of any process run for the task
+    peak_memory: Option<u64>,
 }
 
 enum ExecOutcome {
@@ -738,6 +863,8 @@ enum ExecOutcome {
     Task {
         exit_code: Option<i32>,
         message: String,
+        // Whether the rest of the run should go on despite this failure
+        continue_run: bool,
     },
     // Task didn't execute normally due to a shutdown being initiated by another task
     Shutdown,
@@ -746,6 +873,8 @@ enum ExecOutcome {
 enum SuccessOutcome {
     CacheHit,
     Run,
+    // The task exited with its noop exit code
+    NoOp(i32),
 }
 
 impl ExecContext {
@@ -767,12 +896,20 @@ impl ExecContext {
         telemetry: &PackageTaskEventBuilder,
     ) -> Result<(), InternalError> {
         let tracker = tracker.start().await;
-        let span = tracing::debug_span!("execute_task", task = %self.task_id.task());
+        // Persistent tasks with ready output send this as soon as they're ready so
+        // their dependents can start, leaving `None` behind.
+        let mut callback = Some(callback);
+        let span = tracing::debug_span!(
+            TASK_SPAN,
+            task = %self.task_id.task(),
+            package = %self.task_id.package()
+        );
         span.follows_from(parent_span_id);
         let mut result = self
-            .execute_inner(&output_client, telemetry)
+            .execute_inner(&output_client, &mut callback, telemetry)
             .instrument(span)
             .await;
+        let tracker = tracker.with_peak_memory(self.peak_memory);
 
         // If the task resulted in an error, do not group in order to better highlight
         // the error.
@@ -788,29 +925,55 @@ impl ExecContext {
             }
         };
 
+        // Nobody is reading our output anymore, e.g. `turbo run build | head`. This
+        // isn't a failure, so we shut down the same way as if another task
+        // stopped the run.
+        let stdout_closed = matches!(&result, Err(e) if e.is_broken_pipe());
+        if stdout_closed {
+            debug!(
+                "stdout closed while running {}, shutting down",
+                self.task_id
+            );
+            self.stdout_closed.store(true, Ordering::Relaxed);
+            result = Ok(ExecOutcome::Shutdown);
+        }
+
         match result {
             Ok(ExecOutcome::Success(outcome)) => {
+                // This has to happen before dependents are started and hashed
+                if self.noop_exit_code.is_some() {
+                    self.update_materialized_hash(matches!(outcome, SuccessOutcome::NoOp(_)));
+                }
                 let task_summary = match outcome {
                     SuccessOutcome::CacheHit => tracker.cached().await,
                     SuccessOutcome::Run => tracker.build_succeeded(0).await,
+                    SuccessOutcome::NoOp(code) => tracker.build_noop(code).await,
                 };
-                callback.send(Ok(())).ok();
+                if let Some(callback) = callback {
+                    callback.send(Ok(())).ok();
+                }
                 if let Some(client) = spaces_client {
                     let logs = logs.expect("spaces enabled logs should be collected");
                     let info = self.spaces_task_info(self.task_id.clone(), task_summary, logs);
                     client.finish_task(info).await.ok();
                 }
             }
-            Ok(ExecOutcome::Task { exit_code, message }) => {
+            Ok(ExecOutcome::Task {
+                exit_code,
+                message,
+                continue_run,
+            }) => {
                 let task_summary = tracker.build_failed(exit_code, message).await;
-                callback
-                    .send(match self.continue_on_error {
-                        true => Ok(()),
-                        false => Err(StopExecution),
-                    })
-                    .ok();
+                if let Some(callback) = callback {
+                    callback
+                        .send(match continue_run {
+                            true => Ok(()),
+                            false => Err(StopExecution),
+                        })
+                        .ok();
+                }
 
-                match (spaces_client, self.continue_on_error) {
+                match (spaces_client, continue_run) {
                     // Nothing to do
                     (None, true) => (),
                     // Shut down manager
@@ -833,15 +996,25 @@ impl ExecContext {
                 }
             }
             Ok(ExecOutcome::Shutdown) => {
-                tracker.cancel();
-                callback.send(Err(StopExecution)).ok();
+                // The task still shows up in the run summary if it was only stopped
+                // because its output went unread
+                if stdout_closed {
+                    tracker.stopped().await;
+                } else {
+                    tracker.cancel();
+                }
+                if let Some(callback) = callback {
+                    callback.send(Err(StopExecution)).ok();
+                }
                 // Probably overkill here, but we should make sure the process manager is
                 // stopped if we think we're shutting down.
                 self.manager.stop().await;
             }
             Err(e) => {
                 tracker.cancel();
-                callback.send(Err(StopExecution)).ok();
+                if let Some(callback) = callback {
+                    callback.send(Err(StopExecution)).ok();
+                }
                 self.manager.stop().await;
                 return Err(e);
             }
@@ -869,6 +1042,7 @@ impl ExecContext {
     async fn execute_inner(
         &mut self,
         output_client: &TaskOutput<impl Write>,
+        callback: &mut Option<oneshot::Sender<Result<(), StopExecution>>>,
         telemetry: &PackageTaskEventBuilder,
     ) -> Result<ExecOutcome, InternalError> {
         let task_start = Instant::now();
@@ -921,6 +1095,12 @@ impl ExecContext {
         // We clear the env before populating it with variables we expect
         cmd.env_clear();
         cmd.envs(self.execution_env.iter());
+        // Always passed so that a nested turbo knows it's nested, regardless of env
+        // mode
+        cmd.env(
+            invocation_depth::INVOCATION_DEPTH_ENV,
+            (invocation_depth::current() + 1).to_string(),
+        );
         // Always last to make sure it overwrites any user configured env var.
         cmd.env("TURBO_HASH", &self.task_hash);
 
@@ -940,20 +1120,30 @@ impl ExecContext {
 
         cmd.open_stdin();
 
-        let mut process = match self.manager.spawn(cmd, Duration::from_millis(500)) {
+        let mut task_inputs = match &self.input_watcher {
+            Some((watcher, options)) => watcher
+                .watch(&self.workspace_directory, options)
+                .await
+                .inspect_err(|e| {
+                    prefixed_ui.warn(format!("unable to restart task on change: {e}"));
+                })
+                .ok(),
+            None => None,
+        };
+
+        let mut process = match self.manager.spawn(cmd.clone(), Duration::from_millis(500)) {
             Some(Ok(child)) => child,
             // Turbo was unable to spawn a process
             Some(Err(e)) => {
                 // Note: we actually failed to spawn, but this matches the Go output
                 prefixed_ui.error(&format!("command finished with error: {e}"));
                 let error_string = e.to_string();
-                self.errors
-                    .lock()
-                    .expect("lock poisoned")
-                    .push(TaskError::from_spawn(self.task_id_for_display.clone(), e));
+                let continue_run =
+                    self.record_failure(TaskError::from_spawn(self.task_id_for_display.clone(), e));
                 return Ok(ExecOutcome::Task {
                     exit_code: None,
                     message: error_string,
+                    continue_run,
                 });
             }
             // Turbo is shutting down
@@ -962,20 +1152,7 @@ impl ExecContext {
             }
         };
 
-        if self.ui_mode.has_sender() && self.takes_input {
-            if let TaskOutput::UI(task) = output_client {
-                if let Some(stdin) = process.stdin() {
-                    task.set_stdin(stdin);
-                }
-            }
-        }
-
-        // Even if user does not have the TUI and cannot interact with a task, we keep
-        // stdin open for persistent tasks as some programs will shut down if stdin is
-        // closed.
-        if !self.takes_input && !self.manager.closing_stdin_ends_process() {
-            process.stdin();
-        }
+        self.attach_stdin(&mut process, output_client);
 
         let mut stdout_writer = self
             .task_cache
@@ -984,7 +1161,44 @@ impl ExecContext {
                 telemetry.track_error(TrackedErrors::FailedToCaptureOutputs);
             })?;
 
-        let exit_status = match process.wait_with_piped_outputs(&mut stdout_writer).await {
+        let output = match &self.ready_output {
+            Some(ready_output) => {
+                Either::Left(ReadyWriter::new(&mut stdout_writer, ready_output, callback))
+            }
+            None => Either::Right(&mut stdout_writer),
+        };
+        let piped_outputs = match &mut task_inputs {
+            Some(task_inputs) => {
+                let manager = self.manager.clone();
+                let mut restarted_peak_memory = None;
+                let restarted = task_inputs
+                    .wait_restarting(&mut process, &manager, &cmd, output, |old, new| {
+                        debug!("inputs of {} changed, restarted", self.task_id);
+                        restarted_peak_memory = restarted_peak_memory.max(old.peak_memory());
+                        self.attach_stdin(new, output_client);
+                    })
+                    .await;
+                self.peak_memory = self.peak_memory.max(restarted_peak_memory);
+                match restarted {
+                    Restarted::Exited(piped_outputs) => piped_outputs,
+                    Restarted::SpawnFailed(e) => {
+                        let error_string = e.to_string();
+                        let continue_run = self.record_failure(TaskError::from_spawn(
+                            self.task_id_for_display.clone(),
+                            e,
+                        ));
+                        return Ok(ExecOutcome::Task {
+                            exit_code: None,
+                            message: error_string,
+                            continue_run,
+                        });
+                    }
+                    Restarted::Shutdown => return Ok(ExecOutcome::Shutdown),
+                }
+            }
+            None => process.wait_with_piped_outputs(output).await,
+        };
+        let exit_status = match piped_outputs {
             Ok(Some(exit_status)) => exit_status,
             Err(e) => {
                 telemetry.track_error(TrackedErrors::FailedToPipeOutputs);
@@ -1000,6 +1214,7 @@ impl ExecContext {
             }
         };
         let task_duration = task_start.elapsed();
+        self.peak_memory = self.peak_memory.max(process.peak_memory());
 
         match exit_status {
             ChildExit::Finished(Some(0)) => {
@@ -1026,6 +1241,15 @@ impl ExecContext {
                 // Return success outcome
                 Ok(ExecOutcome::Success(SuccessOutcome::Run))
             }
+            // The task had nothing to do. Its outputs aren't cached so that it gets a
+            // chance to do work on the next run.
+            ChildExit::Finished(Some(code)) if Some(code) == self.noop_exit_code => {
+                if let Err(e) = stdout_writer.flush() {
+                    error!("error flushing logs: {e}");
+                }
+                debug!("{} exited with noop exit code {code}", self.task_id);
+                Ok(ExecOutcome::Success(SuccessOutcome::NoOp(code)))
+            }
             ChildExit::Finished(Some(code)) => {
                 // If there was an error, flush the buffered output
                 if let Err(e) = stdout_writer.flush() {
@@ -1036,18 +1260,24 @@ impl ExecContext {
                 }
                 let error = TaskErrorCause::from_execution(process.label().to_string(), code);
                 let message = error.to_string();
-                if self.continue_on_error {
-                    prefixed_ui.warn("command finished with error, but continuing...");
-                } else {
-                    prefixed_ui.error(&format!("command finished with error: {error}"));
-                }
-                self.errors.lock().expect("lock poisoned").push(TaskError {
+                let continue_run = self.record_failure(TaskError {
                     task_id: self.task_id_for_display.clone(),
                     cause: error,
                 });
+                if continue_run {
+                    prefixed_ui.warn("command finished with error, but continuing...");
+                } else if self.continue_on_error {
+                    prefixed_ui.error(&format!(
+                        "command finished with error: {message}, stopping after reaching the \
+                         maximum number of failures"
+                    ));
+                } else {
+                    prefixed_ui.error(&format!("command finished with error: {message}"));
+                }
                 Ok(ExecOutcome::Task {
                     exit_code: Some(code),
                     message,
+                    continue_run,
                 })
             }
             // The child exited in a way where we can't figure out how it finished so we assume it
@@ -1060,6 +1290,30 @@ impl ExecContext {
         }
     }
 
+    // Records a failed task and returns whether the run should keep going
+    fn record_failure(&self, error: TaskError) -> bool {
+        let mut errors = self.errors.lock().expect("lock poisoned");
+        errors.push(error);
+        should_continue(self.continue_on_error, self.max_failures, errors.len())
+    }
+
+    fn attach_stdin(&self, process: &mut Child, output_client: &TaskOutput<impl Write>) {
+        if self.ui_mode.has_sender() && self.takes_input {
+            if let TaskOutput::UI(task) = output_client {
+                if let Some(stdin) = process.stdin() {
+                    task.set_stdin(stdin);
+                }
+            }
+        }
+
+        // Even if user does not have the TUI and cannot interact with a task, we keep
+        // stdin open for persistent tasks as some programs will shut down if stdin is
+        // closed.
+        if !self.takes_input && !self.manager.closing_stdin_ends_process() {
+            process.stdin();
+        }
+    }
+
     fn spaces_task_info(
         &self,
         task_id: TaskId<'static>,
@@ -1079,20 +1333,55 @@ impl ExecContext {
             dependents,
         }
     }
+
+    // Dependents of a task with a noop exit code don't see the hash of a run
+    // that had nothing to do, but the hash of the last run that did something.
+    // A run that had nothing to do left its outputs as that run produced them,
+    // so its dependents don't have to run again. This is kept on disk so that
+    // dependents get the same hash on every run until the task does something.
+    fn update_materialized_hash(&self, noop: bool) {
+        let path = &self.materialized_hash_file;
+        let materialized_hash = if noop {
+            path.read_existing_to_string().unwrap_or_else(|e| {
+                warn!("unable to read last hash of {}: {e}", self.task_id);
+                None
+            })
+        } else {
+            None
+        };
+
+        match materialized_hash {
+            Some(hash) => self
+                .hash_tracker
+                .insert_materialized_hash(self.task_id.clone(), hash),
+            // Either the task did something, or this is the first time it ran
+            None => {
+                if let Err(e) = path
+                    .ensure_dir()
+                    .and_then(|()| path.create_with_contents(&self.task_hash))
+                {
+                    warn!("unable to record hash of {}: {e}", self.task_id);
+                }
+            }
+        }
+    }
 }
 
 struct DryRunExecContext {
     task_id: TaskId<'static>,
-    task_cache: TaskCache,
+    // Not set if the cache shouldn't be checked
+    task_cache: Option<TaskCache>,
     hash_tracker: TaskHashTracker,
 }
 
 impl DryRunExecContext {
     pub async fn execute_dry_run(&self, tracker: TaskTracker<()>) -> Result<(), InternalError> {
         // may also need to do framework & command stuff?
-        if let Ok(Some(status)) = self.task_cache.exists().await {
-            self.hash_tracker
-                .insert_cache_status(self.task_id.clone(), status);
+        if let Some(task_cache) = &self.task_cache {
+            if let Ok(Some(status)) = task_cache.exists().await {
+                self.hash_tracker
+                    .insert_cache_status(self.task_id.clone(), status);
+            }
         }
         tracker.dry_run().await;
         Ok(())
@@ -1182,3 +1471,93 @@ impl<W: Write> TaskOutput<W> {
         }
     }
 }
+
+// Whether a run continues after a task failure, given the number of failures
+// so far
+fn should_continue(continue_on_error: bool, max_failures: Option<usize>, failures: usize) -> bool {
+    continue_on_error && max_failures.map_or(true, |max_failures| failures < max_failures)
+}
+
+// Where the hash of the last run of a task with a noop exit code that did
+// something is kept
+fn materialized_hash_file(
+    repo_root: &AbsoluteSystemPath,
+    task_id: &TaskId,
+) -> AbsoluteSystemPathBuf {
+    let filename = task_id
+        .to_string()
+        .replace('/', "$slash$")
+        .replace(':', "$colon$");
+    repo_root.join_components(&[".turbo", "noop", &filename])
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+    use turbopath::AbsoluteSystemPath;
+    use turborepo_repository::package_graph::ROOT_PKG_NAME;
+
+    use super::{materialized_hash_file, should_continue, InternalError};
+    use crate::run::task_id::TaskId;
+
+    #[test_case(false, None, 1, false ; "stop on first failure")]
+    #[test_case(true, None, 100, true ; "continue without limit")]
+    #[test_case(true, Some(2), 1, true ; "below limit")]
+    #[test_case(true, Some(2), 2, false ; "limit reached")]
+    fn test_should_continue(
+        continue_on_error: bool,
+        max_failures: Option<usize>,
+        failures: usize,
+        expected: bool,
+    ) {
+        assert_eq!(
+            should_continue(continue_on_error, max_failures, failures),
+            expected
+        );
+    }
+
+    #[test_case("web", "build", "web#build" ; "package task")]
+    #[test_case("@scope/web", "build", "@scope$slash$web#build" ; "scoped package")]
+    #[test_case(ROOT_PKG_NAME, "build:prod", "$slash$$slash$#build$colon$prod" ; "root task")]
+    fn test_materialized_hash_file(package: &str, task: &str, expected: &str) {
+        let repo_root =
+            AbsoluteSystemPath::new(if cfg!(windows) { "C:\\repo" } else { "/repo" }).unwrap();
+        assert_eq!(
+            materialized_hash_file(repo_root, &TaskId::new(package, task)),
+            repo_root.join_components(&[".turbo", "noop", expected])
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_closed_reader_is_broken_pipe() {
+        use std::{
+            io::Write,
+            process::{Command, Stdio},
+        };
+
+        use turborepo_ui::{OutputClientBehavior, OutputSink};
+
+        let mut head = Command::new("head")
+            .args(["-n", "1"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        let stdin: Box<dyn Write> = Box::new(head.stdin.take().unwrap());
+        let sink = OutputSink::new(stdin, Box::new(std::io::sink()));
+        let client = sink.logger(OutputClientBehavior::Passthrough);
+        let mut stdout = client.stdout();
+
+        // Keep writing until `head` exits after reading its one line
+        let err = loop {
+            if let Err(e) = stdout.write_all(b"some task output\n") {
+                break e;
+            }
+        };
+        head.wait().unwrap();
+
+        assert!(InternalError::from(err).is_broken_pipe());
+        assert!(!InternalError::UnknownChildExit.is_broken_pipe());
+    }
+}
@@ -1,13 +1,16 @@
 use std::{
-    collections::{HashMap, HashSet},
-    sync::{Arc, Mutex},
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::{Arc, Mutex, OnceLock},
 };
 
 use rayon::prelude::*;
+use regex::Regex;
 use serde::Serialize;
 use thiserror::Error;
 use tracing::{debug, Span};
-use turbopath::{AbsoluteSystemPath, AnchoredSystemPath, AnchoredSystemPathBuf};
+use turbopath::{
+    AbsoluteSystemPath, AnchoredSystemPath, AnchoredSystemPathBuf, RelativeUnixPathBuf,
+};
 use turborepo_cache::CacheHitMetadata;
 use turborepo_env::{BySource, DetailedMap, EnvironmentVariableMap};
 use turborepo_repository::package_graph::{PackageInfo, PackageName};
@@ -20,10 +23,11 @@ use crate::{
     cli::EnvMode,
     engine::TaskNode,
     framework::infer_framework,
-    hash::{FileHashes, LockFilePackages, TaskHashable, TurboHash},
+    hash::{FileHashes, HashAlgorithm, LockFilePackages, TaskHashable, TurboHash},
     opts::RunOpts,
     run::task_id::TaskId,
     task_graph::TaskDefinition,
+    turboignore::{self, TurboIgnore},
     DaemonClient, DaemonConnector,
 };
 
@@ -49,15 +53,23 @@ pub enum Error {
     Regex(#[from] regex::Error),
     #[error(transparent)]
     Path(#[from] turbopath::PathError),
+    #[error("unable to create thread pool for file hashing: {0}")]
+    ThreadPool(#[from] rayon::ThreadPoolBuildError),
+    #[error(transparent)]
+    TurboIgnore(#[from] turboignore::Error),
 }
 
+// Inputs with this prefix match files in the package's dependencies instead of
+// the package itself, e.g. `$DEP_OUTPUTS/types/**`
+const DEP_OUTPUTS_PREFIX: &str = "$DEP_OUTPUTS/";
+
 impl TaskHashable<'_> {
-    fn calculate_task_hash(mut self) -> String {
-        if matches!(self.env_mode, EnvMode::Loose) {
+    fn calculate_task_hash(mut self, algorithm: HashAlgorithm) -> String {
+        if self.env_mode.is_loose() {
             self.pass_through_env = &[];
         }
 
-        self.hash()
+        self.hash_with(algorithm)
     }
 }
 
@@ -65,10 +77,20 @@ impl TaskHashable<'_> {
 pub struct PackageInputsHashes {
     hashes: HashMap<TaskId<'static>, String>,
     expanded_hashes: HashMap<TaskId<'static>, FileHashes>,
+    turbo_ignore: TurboIgnore,
 }
 
 impl PackageInputsHashes {
-    #[tracing::instrument(skip(all_tasks, workspaces, task_definitions, repo_root, scm))]
+    /// Hashes the inputs of every task. If `hash_workers` is set, hashing
+    /// happens on a dedicated pool of that many threads instead of rayon's
+    /// global pool.
+    ///
+    /// `$DEP_OUTPUTS/` inputs are left out here, they're hashed by
+    /// [`TaskHasher`] once the task's dependencies have run.
+    ///
+    /// Files matched by the root `.turboignore` are left out of every task's
+    /// inputs.
+    #[allow(clippy::too_many_arguments)]
     pub fn calculate_file_hashes<'a>(
         scm: &SCM,
         all_tasks: impl ParallelIterator<Item = &'a TaskNode>,
@@ -77,8 +99,44 @@ impl PackageInputsHashes {
         repo_root: &AbsoluteSystemPath,
         telemetry: &GenericEventBuilder,
         daemon: &Option<DaemonClient<DaemonConnector>>,
+        hash_workers: Option<usize>,
+    ) -> Result<PackageInputsHashes, Error> {
+        let hash_inputs = || {
+            Self::hash_inputs(
+                scm,
+                all_tasks,
+                workspaces,
+                task_definitions,
+                repo_root,
+                telemetry,
+                daemon,
+            )
+        };
+        // Parallel iterators run on the pool they're driven from, so
+        // installing a pool bounds hashing without affecting anything else
+        // that uses rayon
+        match hash_workers {
+            Some(hash_workers) => rayon::ThreadPoolBuilder::new()
+                .num_threads(hash_workers)
+                .build()?
+                .install(hash_inputs),
+            None => hash_inputs(),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(all_tasks, workspaces, task_definitions, repo_root, scm))]
+    fn hash_inputs<'a>(
+        scm: &SCM,
+        all_tasks: impl ParallelIterator<Item = &'a TaskNode>,
+        workspaces: HashMap<&PackageName, &PackageInfo>,
+        task_definitions: &HashMap<TaskId<'static>, TaskDefinition>,
+        repo_root: &AbsoluteSystemPath,
+        telemetry: &GenericEventBuilder,
+        daemon: &Option<DaemonClient<DaemonConnector>>,
     ) -> Result<PackageInputsHashes, Error> {
         tracing::trace!(scm_manual=%scm.is_manual(), "scm running in {} mode", if scm.is_manual() { "manual" } else { "git" });
+        let turbo_ignore = TurboIgnore::load(repo_root)?;
 
         let span = Span::current();
         let (hashes, expanded_hashes): (HashMap<_, _>, HashMap<_, _>) = all_tasks
@@ -116,6 +174,13 @@ impl PackageInputsHashes {
                     .parent()
                     .unwrap_or_else(|| AnchoredSystemPath::new("").unwrap());
 
+                let inputs: Vec<_> = task_definition
+                    .inputs
+                    .iter()
+                    .filter(|input| !input.starts_with(DEP_OUTPUTS_PREFIX))
+                    .cloned()
+                    .collect();
+
                 let scm_telemetry = package_task_event.child();
                 // Try hashing with the daemon, if we have a connection. If we don't, or if we
                 // timeout or get an error, fallback to local hashing
@@ -137,8 +202,7 @@ impl PackageInputsHashes {
                                 .block_on(async {
                                     tokio::time::timeout(
                                         std::time::Duration::from_millis(100),
-                                        daemon
-                                            .get_file_hashes(package_path, &task_definition.inputs),
+                                        daemon.get_file_hashes(package_path, &inputs),
                                     )
                                     .await
                                 })
@@ -184,13 +248,13 @@ impl PackageInputsHashes {
                     None
                 };
 
-                let hash_object = match hash_object {
+                let mut hash_object = match hash_object {
                     Some(hash_object) => hash_object,
                     None => {
                         let local_hash_result = scm.get_package_file_hashes(
                             repo_root,
                             package_path,
-                            &task_definition.inputs,
+                            &inputs,
                             Some(scm_telemetry),
                         );
                         match local_hash_result {
@@ -200,6 +264,11 @@ impl PackageInputsHashes {
                     }
                 };
 
+                let package_dir = repo_root.resolve(package_path);
+                hash_object.retain(|path, _| {
+                    !is_ignored(repo_root, &package_dir.join_unix_path(path), &turbo_ignore)
+                });
+
                 let file_hashes = FileHashes(hash_object);
                 let hash = file_hashes.clone().hash();
 
@@ -213,10 +282,47 @@ impl PackageInputsHashes {
         Ok(PackageInputsHashes {
             hashes,
             expanded_hashes,
+            turbo_ignore,
         })
     }
 }
 
+/// Hashes the files matching `globs` in each dependency. The paths are made
+/// relative to the package so that they can't collide with its own files.
+fn dependency_output_hashes<'a>(
+    scm: &SCM,
+    repo_root: &AbsoluteSystemPath,
+    package_path: &AnchoredSystemPath,
+    dependency_paths: impl Iterator<Item = &'a AnchoredSystemPath>,
+    globs: &[&str],
+    turbo_ignore: &TurboIgnore,
+) -> Result<HashMap<RelativeUnixPathBuf, String>, Error> {
+    let package_dir = repo_root.resolve(package_path);
+    let mut hashes = HashMap::new();
+    for dependency_path in dependency_paths {
+        let dependency_dir = repo_root.resolve(dependency_path);
+        for (path, hash) in scm.get_package_file_hashes(repo_root, dependency_path, globs, None)? {
+            let file = dependency_dir.join_unix_path(&path);
+            if is_ignored(repo_root, &file, turbo_ignore) {
+                continue;
+            }
+            let path = AnchoredSystemPathBuf::relative_path_between(&package_dir, &file).to_unix();
+            hashes.insert(path, hash);
+        }
+    }
+    Ok(hashes)
+}
+
+fn is_ignored(
+    repo_root: &AbsoluteSystemPath,
+    file: &AbsoluteSystemPath,
+    turbo_ignore: &TurboIgnore,
+) -> bool {
+    repo_root
+        .anchor(file)
+        .map_or(false, |path| turbo_ignore.is_ignored(&path.to_unix()))
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct TaskHashTracker {
     state: Arc<Mutex<TaskHashTrackerState>>,
@@ -235,11 +341,24 @@ pub struct TaskHashTrackerState {
     package_task_cache: HashMap<TaskId<'static>, CacheHitMetadata>,
     #[serde(skip)]
     package_task_inputs_expanded_hashes: HashMap<TaskId<'static>, FileHashes>,
+    // Hashes of the last runs that did something for tasks that exited with
+    // their noop exit code
+    #[serde(skip)]
+    package_task_materialized_hashes: HashMap<TaskId<'static>, String>,
+}
+
+impl TaskHashTrackerState {
+    pub fn task_hashes(&self) -> &HashMap<TaskId<'static>, String> {
+        &self.package_task_hashes
+    }
 }
 
 /// Caches package-inputs hashes, and package-task hashes.
 pub struct TaskHasher<'a> {
     hashes: HashMap<TaskId<'static>, String>,
+    turbo_ignore: TurboIgnore,
+    scm: &'a SCM,
+    repo_root: &'a AbsoluteSystemPath,
     run_opts: &'a RunOpts,
     env_at_execution_start: &'a EnvironmentVariableMap,
     global_hash: &'a str,
@@ -249,6 +368,8 @@ pub struct TaskHasher<'a> {
 impl<'a> TaskHasher<'a> {
     pub fn new(
         package_inputs_hashes: PackageInputsHashes,
+        scm: &'a SCM,
+        repo_root: &'a AbsoluteSystemPath,
         run_opts: &'a RunOpts,
         env_at_execution_start: &'a EnvironmentVariableMap,
         global_hash: &'a str,
@@ -256,9 +377,13 @@ impl<'a> TaskHasher<'a> {
         let PackageInputsHashes {
             hashes,
             expanded_hashes,
+            turbo_ignore,
         } = package_inputs_hashes;
         Self {
             hashes,
+            turbo_ignore,
+            scm,
+            repo_root,
             run_opts,
             env_at_execution_start,
             global_hash,
@@ -266,23 +391,34 @@ impl<'a> TaskHasher<'a> {
         }
     }
 
-    #[tracing::instrument(skip(self, task_definition, task_env_mode, workspace, dependency_set))]
+    /// Hashes a task once its dependencies have run. `$DEP_OUTPUTS/` inputs
+    /// are resolved here against `dependency_packages`, the package's direct
+    /// dependencies, so that they include the outputs those tasks wrote.
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(
+        self,
+        task_definition,
+        task_env_mode,
+        workspace,
+        dependency_packages,
+        dependency_set
+    ))]
     pub fn calculate_task_hash(
         &self,
         task_id: &TaskId<'static>,
         task_definition: &TaskDefinition,
         task_env_mode: EnvMode,
         workspace: &PackageInfo,
+        dependency_packages: &[&PackageInfo],
         dependency_set: HashSet<&TaskNode>,
         telemetry: PackageTaskEventBuilder,
     ) -> Result<String, Error> {
         let do_framework_inference = self.run_opts.framework_inference;
         let is_monorepo = !self.run_opts.single_package;
 
-        let hash_of_files = self
-            .hashes
-            .get(task_id)
-            .ok_or_else(|| Error::MissingPackageFileHash(task_id.to_string()))?;
+        let hash_of_files =
+            self.hash_of_files(task_id, task_definition, workspace, dependency_packages)?;
+        let hash_of_files = hash_of_files.as_str();
         let mut explicit_env_var_map = EnvironmentVariableMap::default();
         let mut all_env_var_map = EnvironmentVariableMap::default();
         let mut matching_env_var_map = EnvironmentVariableMap::default();
@@ -379,6 +515,14 @@ impl<'a> TaskHasher<'a> {
         // We wrap in an Option to mimic Go's serialization of nullable values
         let optional_package_dir = (!is_root_package).then_some(package_dir);
 
+        // Changes to the script itself aren't reflected in the package's file
+        // inputs if they're excluded, so the script body is hashed directly
+        let command = workspace
+            .package_json
+            .scripts
+            .get(task_id.task())
+            .map(|script| script.as_inner().as_str());
+
         let task_hashable = TaskHashable {
             global_hash: self.global_hash,
             task_dependency_hashes,
@@ -389,6 +533,7 @@ impl<'a> TaskHasher<'a> {
             outputs,
 
             pass_through_args: &self.run_opts.pass_through_args,
+            command,
             env: &task_definition.env,
             resolved_env_vars: hashable_env_pairs,
             pass_through_env: task_definition
@@ -398,7 +543,7 @@ impl<'a> TaskHasher<'a> {
             env_mode: task_env_mode,
         };
 
-        let task_hash = task_hashable.calculate_task_hash();
+        let task_hash = task_hashable.calculate_task_hash(self.run_opts.hash_algorithm);
 
         self.task_hash_tracker.insert_hash(
             task_id.clone(),
@@ -410,6 +555,48 @@ impl<'a> TaskHasher<'a> {
         Ok(task_hash)
     }
 
+    fn hash_of_files(
+        &self,
+        task_id: &TaskId<'static>,
+        task_definition: &TaskDefinition,
+        workspace: &PackageInfo,
+        dependency_packages: &[&PackageInfo],
+    ) -> Result<String, Error> {
+        let hash_of_files = self
+            .hashes
+            .get(task_id)
+            .ok_or_else(|| Error::MissingPackageFileHash(task_id.to_string()))?;
+        let globs: Vec<_> = task_definition
+            .inputs
+            .iter()
+            .filter_map(|input| input.strip_prefix(DEP_OUTPUTS_PREFIX))
+            .collect();
+        if globs.is_empty() {
+            return Ok(hash_of_files.clone());
+        }
+
+        let FileHashes(mut file_hashes) = self
+            .task_hash_tracker
+            .get_expanded_inputs(task_id)
+            .ok_or_else(|| Error::MissingPackageFileHash(task_id.to_string()))?;
+        file_hashes.extend(dependency_output_hashes(
+            self.scm,
+            self.repo_root,
+            workspace.package_path(),
+            dependency_packages
+                .iter()
+                .map(|dependency| dependency.package_path()),
+            &globs,
+            &self.turbo_ignore,
+        )?);
+        let file_hashes = FileHashes(file_hashes);
+        let hash = file_hashes.clone().hash();
+        self.task_hash_tracker
+            .insert_expanded_inputs(task_id.clone(), file_hashes);
+
+        Ok(hash)
+    }
+
     /// Gets the hashes of a task's dependencies. Because the visitor
     /// receives the nodes in topological order, we know that all of
     /// the dependencies have been processed before the current task.
@@ -432,7 +619,7 @@ impl<'a> TaskHasher<'a> {
 
             let dependency_hash = self
                 .task_hash_tracker
-                .hash(dependency_task_id)
+                .dependency_hash(dependency_task_id)
                 .ok_or_else(|| Error::MissingDependencyTaskHash(dependency_task.to_string()))?;
             dependency_hash_set.insert(dependency_hash.clone());
         }
@@ -531,11 +718,36 @@ impl<'a> TaskHasher<'a> {
 
                 Ok(pass_through_env)
             }
-            EnvMode::Loose => Ok(self.env_at_execution_start.clone()),
+            EnvMode::Loose | EnvMode::LooseWarn => Ok(self.env_at_execution_start.clone()),
         }
     }
 }
 
+/// Environment variables that `command` references which are set, but which
+/// wouldn't be passed to the task in strict mode. Only `$VAR`, `${VAR}` and
+/// `%VAR%` references in the command itself can be detected, variables read by
+/// the programs it runs are not visible to us.
+pub fn undeclared_env_vars(
+    command: &str,
+    execution_env: &EnvironmentVariableMap,
+    strict_env: &EnvironmentVariableMap,
+) -> Vec<String> {
+    static ENV_REFERENCE: OnceLock<Regex> = OnceLock::new();
+    let env_reference = ENV_REFERENCE.get_or_init(|| {
+        Regex::new(r"\$\{?([A-Za-z_][A-Za-z0-9_]*)|%([A-Za-z_][A-Za-z0-9_]*)%").unwrap()
+    });
+
+    env_reference
+        .captures_iter(command)
+        .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+        .map(|name| name.as_str())
+        .filter(|name| execution_env.contains_key(*name) && !strict_env.contains_key(*name))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
 pub fn get_external_deps_hash(
     transitive_dependencies: &Option<HashSet<turborepo_lockfiles::Package>>,
 ) -> String {
@@ -597,6 +809,23 @@ impl TaskHashTracker {
         state.package_task_hashes.get(task_id).cloned()
     }
 
+    // The hash dependents of a task see. A task that had nothing to do left
+    // things as they were after its last run that did something, so that
+    // run's hash is used.
+    fn dependency_hash(&self, task_id: &TaskId) -> Option<String> {
+        let state = self.state.lock().expect("hash tracker mutex poisoned");
+        state
+            .package_task_materialized_hashes
+            .get(task_id)
+            .or_else(|| state.package_task_hashes.get(task_id))
+            .cloned()
+    }
+
+    pub fn insert_materialized_hash(&self, task_id: TaskId<'static>, hash: String) {
+        let mut state = self.state.lock().expect("hash tracker mutex poisoned");
+        state.package_task_materialized_hashes.insert(task_id, hash);
+    }
+
     fn insert_hash(
         &self,
         task_id: TaskId<'static>,
@@ -650,6 +879,13 @@ impl TaskHashTracker {
         state.package_task_cache.insert(task_id, cache_status);
     }
 
+    fn insert_expanded_inputs(&self, task_id: TaskId<'static>, inputs: FileHashes) {
+        let mut state = self.state.lock().expect("hash tracker mutex poisoned");
+        state
+            .package_task_inputs_expanded_hashes
+            .insert(task_id, inputs);
+    }
+
     pub fn get_expanded_inputs(&self, task_id: &TaskId) -> Option<FileHashes> {
         let state = self.state.lock().expect("hash tracker mutex poisoned");
         state
@@ -661,7 +897,382 @@ impl TaskHashTracker {
 
 #[cfg(test)]
 mod test {
+    use turborepo_errors::Spanned;
+    use turborepo_repository::package_json::PackageJson;
+
     use super::*;
+    use crate::{
+        opts::{ResolvedLogOrder, ResolvedLogPrefix},
+        turbo_json::UIMode,
+    };
+
+    fn run_opts() -> RunOpts {
+        RunOpts {
+            tasks: vec!["build".to_string()],
+            concurrency: 10,
+            hash_workers: None,
+            hash_algorithm: HashAlgorithm::default(),
+            parallel: false,
+            env_mode: EnvMode::Strict,
+            cache_dir: camino::Utf8PathBuf::new(),
+            framework_inference: false,
+            profile: None,
+            continue_on_error: false,
+            max_failures: None,
+            restart_on_change: false,
+            pass_through_args: vec![],
+            only: false,
+            dry_run: None,
+            graph: None,
+            daemon: None,
+            single_package: false,
+            log_prefix: ResolvedLogPrefix::Task,
+            log_order: ResolvedLogOrder::Stream,
+            summarize: false,
+            summarize_only: false,
+            print_opts: false,
+            csv_output: None,
+            experimental_space_id: None,
+            anonymize_spaces: false,
+            is_github_actions: false,
+            ui_mode: UIMode::Stream,
+        }
+    }
+
+    fn task_hash_with_script(script: &str) -> String {
+        let task_id = TaskId::new("web", "build").into_owned();
+        let run_opts = run_opts();
+        let env = EnvironmentVariableMap::default();
+        let package_inputs_hashes = PackageInputsHashes {
+            hashes: HashMap::from([(task_id.clone(), "file-hash".to_string())]),
+            expanded_hashes: HashMap::new(),
+            turbo_ignore: TurboIgnore::default(),
+        };
+        let hasher = TaskHasher::new(
+            package_inputs_hashes,
+            &SCM::Manual,
+            AbsoluteSystemPath::new(if cfg!(windows) { "C:\\" } else { "/" }).unwrap(),
+            &run_opts,
+            &env,
+            "global-hash",
+        );
+
+        let workspace = PackageInfo {
+            package_json: PackageJson {
+                name: Some("web".to_string()),
+                scripts: [("build".to_string(), Spanned::new(script.to_string()))]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            },
+            package_json_path: AnchoredSystemPathBuf::from_raw("apps/web/package.json").unwrap(),
+            ..Default::default()
+        };
+
+        hasher
+            .calculate_task_hash(
+                &task_id,
+                &TaskDefinition::default(),
+                EnvMode::Strict,
+                &workspace,
+                &[],
+                HashSet::new(),
+                PackageTaskEventBuilder::new("web", "build"),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_file_hashes_with_single_thread() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_root = AbsoluteSystemPath::from_std_path(tmp.path()).unwrap();
+        let mut workspaces = Vec::new();
+        let mut task_definitions = HashMap::new();
+        let mut tasks = Vec::new();
+        for name in ["a", "b", "c"] {
+            let package_dir = repo_root.join_components(&["packages", name]);
+            package_dir.create_dir_all().unwrap();
+            package_dir
+                .join_component("package.json")
+                .create_with_contents(format!("{{\"name\": \"{name}\"}}"))
+                .unwrap();
+            package_dir
+                .join_component("index.js")
+                .create_with_contents(format!("console.log('{name}')"))
+                .unwrap();
+            workspaces.push((
+                PackageName::from(name),
+                PackageInfo {
+                    package_json_path: AnchoredSystemPathBuf::from_raw(format!(
+                        "packages/{name}/package.json"
+                    ))
+                    .unwrap(),
+                    ..Default::default()
+                },
+            ));
+            let task_id = TaskId::new(name, "build").into_owned();
+            task_definitions.insert(task_id.clone(), TaskDefinition::default());
+            tasks.push(TaskNode::Task(task_id));
+        }
+
+        let scm = SCM::new(repo_root);
+        let telemetry = GenericEventBuilder::new();
+        let calculate = |hash_workers| {
+            PackageInputsHashes::calculate_file_hashes(
+                &scm,
+                tasks.par_iter(),
+                workspaces.iter().map(|(name, info)| (name, info)).collect(),
+                &task_definitions,
+                repo_root,
+                &telemetry,
+                &None,
+                hash_workers,
+            )
+            .unwrap()
+        };
+
+        let single_threaded = calculate(Some(1));
+        let global_pool = calculate(None);
+        assert_eq!(single_threaded.hashes.len(), 3);
+        assert_eq!(single_threaded.hashes, global_pool.hashes);
+    }
+
+    #[test]
+    fn test_dependency_outputs_change_task_hash() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_root = AbsoluteSystemPath::from_std_path(tmp.path()).unwrap();
+        let mut workspaces = Vec::new();
+        for name in ["app", "ui"] {
+            let package_dir = repo_root.join_components(&["packages", name]);
+            package_dir.create_dir_all().unwrap();
+            package_dir
+                .join_component("package.json")
+                .create_with_contents(format!("{{\"name\": \"{name}\"}}"))
+                .unwrap();
+            workspaces.push((
+                PackageName::from(name),
+                PackageInfo {
+                    package_json_path: AnchoredSystemPathBuf::from_raw(format!(
+                        "packages/{name}/package.json"
+                    ))
+                    .unwrap(),
+                    ..Default::default()
+                },
+            ));
+        }
+        let ui_dir = repo_root.join_components(&["packages", "ui"]);
+        let ui_types = ui_dir.join_components(&["types", "index.d.ts"]);
+        ui_types.ensure_dir().unwrap();
+        let ui_source = ui_dir.join_component("index.ts");
+        ui_source.create_with_contents("export {};").unwrap();
+
+        let task_id = TaskId::new("app", "build").into_owned();
+        let task_definition = TaskDefinition {
+            inputs: vec!["$DEP_OUTPUTS/types/**".to_string()],
+            ..Default::default()
+        };
+        let task_definitions = HashMap::from([(task_id.clone(), task_definition.clone())]);
+        let tasks = [TaskNode::Task(task_id.clone())];
+        let (app_info, ui_info) = (&workspaces[0].1, &workspaces[1].1);
+
+        let scm = SCM::new(repo_root);
+        let run_opts = run_opts();
+        let env = EnvironmentVariableMap::default();
+        let telemetry = GenericEventBuilder::new();
+        // `run_dependency` stands in for the dependency's task, which runs
+        // after file hashing but before the task itself is hashed
+        let hash = |run_dependency: &dyn Fn()| {
+            let package_inputs_hashes = PackageInputsHashes::calculate_file_hashes(
+                &scm,
+                tasks.par_iter(),
+                workspaces.iter().map(|(name, info)| (name, info)).collect(),
+                &task_definitions,
+                repo_root,
+                &telemetry,
+                &None,
+                None,
+            )
+            .unwrap();
+            run_dependency();
+            let hasher = TaskHasher::new(
+                package_inputs_hashes,
+                &scm,
+                repo_root,
+                &run_opts,
+                &env,
+                "global-hash",
+            );
+            let hash = hasher
+                .calculate_task_hash(
+                    &task_id,
+                    &task_definition,
+                    EnvMode::Strict,
+                    app_info,
+                    &[ui_info],
+                    HashSet::new(),
+                    PackageTaskEventBuilder::new("app", "build"),
+                )
+                .unwrap();
+            let inputs = hasher.task_hash_tracker().get_expanded_inputs(&task_id);
+            (hash, inputs.unwrap())
+        };
+
+        let types_input = RelativeUnixPathBuf::new("../ui/types/index.d.ts").unwrap();
+        let (original, inputs) = hash(&|| ());
+        assert!(!inputs.0.contains_key(&types_input));
+        let (built, inputs) = hash(&|| {
+            ui_types
+                .create_with_contents("export type Button = {};")
+                .unwrap()
+        });
+        assert_ne!(built, original);
+        assert!(inputs.0.contains_key(&types_input));
+        // Only the dependency's outputs matching the input are hashed
+        let (unchanged, _) = hash(&|| {
+            ui_source
+                .create_with_contents("export const a = 1;")
+                .unwrap()
+        });
+        assert_eq!(unchanged, built);
+    }
+
+    #[test]
+    fn test_turboignore_files_do_not_change_file_hash() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_root = AbsoluteSystemPath::from_std_path(tmp.path()).unwrap();
+        repo_root
+            .join_component(".turboignore")
+            .create_with_contents("*.swp\n")
+            .unwrap();
+        let package_dir = repo_root.join_components(&["packages", "web"]);
+        package_dir.create_dir_all().unwrap();
+        package_dir
+            .join_component("package.json")
+            .create_with_contents("{\"name\": \"web\"}")
+            .unwrap();
+        package_dir
+            .join_component("index.ts")
+            .create_with_contents("export {};")
+            .unwrap();
+        let swap_file = package_dir.join_component(".index.ts.swp");
+
+        let web = PackageName::from("web");
+        let web_info = PackageInfo {
+            package_json_path: AnchoredSystemPathBuf::from_raw("packages/web/package.json")
+                .unwrap(),
+            ..Default::default()
+        };
+        let task_id = TaskId::new("web", "build").into_owned();
+        let task_definitions = HashMap::from([(task_id.clone(), TaskDefinition::default())]);
+        let tasks = [TaskNode::Task(task_id.clone())];
+
+        let scm = SCM::new(repo_root);
+        let telemetry = GenericEventBuilder::new();
+        let hash = || {
+            let hashes = PackageInputsHashes::calculate_file_hashes(
+                &scm,
+                tasks.par_iter(),
+                HashMap::from([(&web, &web_info)]),
+                &task_definitions,
+                repo_root,
+                &telemetry,
+                &None,
+                None,
+            )
+            .unwrap();
+            hashes.hashes[&task_id].clone()
+        };
+
+        let original = hash();
+        swap_file.create_with_contents("swap").unwrap();
+        assert_eq!(hash(), original);
+        swap_file.create_with_contents("more swap").unwrap();
+        assert_eq!(hash(), original);
+    }
+
+    #[test]
+    fn test_script_body_changes_task_hash() {
+        let original = task_hash_with_script("tsc");
+        assert_eq!(original, task_hash_with_script("tsc"));
+        assert_ne!(original, task_hash_with_script("tsc --build"));
+    }
+
+    #[test]
+    fn test_noop_dependencies_use_materialized_hash() {
+        let run_opts = run_opts();
+        let env = EnvironmentVariableMap::default();
+        let package_inputs_hashes = PackageInputsHashes {
+            hashes: HashMap::new(),
+            expanded_hashes: HashMap::new(),
+            turbo_ignore: TurboIgnore::default(),
+        };
+        let hasher = TaskHasher::new(
+            package_inputs_hashes,
+            &SCM::Manual,
+            AbsoluteSystemPath::new(if cfg!(windows) { "C:\\" } else { "/" }).unwrap(),
+            &run_opts,
+            &env,
+            "global-hash",
+        );
+        let tracker = hasher.task_hash_tracker();
+
+        let codegen = TaskNode::Task(TaskId::new("util", "gen").into_owned());
+        let build = TaskNode::Task(TaskId::new("util", "build").into_owned());
+        for (task, hash) in [(&codegen, "gen-hash"), (&build, "build-hash")] {
+            let TaskNode::Task(task_id) = task else {
+                unreachable!()
+            };
+            tracker.insert_hash(
+                task_id.clone(),
+                DetailedMap::default(),
+                hash.to_string(),
+                None,
+            );
+        }
+        let dependencies = || HashSet::from([&codegen, &build]);
+        assert_eq!(
+            hasher.calculate_dependency_hashes(dependencies()).unwrap(),
+            vec!["build-hash".to_string(), "gen-hash".to_string()]
+        );
+
+        tracker.insert_materialized_hash(
+            TaskId::new("util", "gen").into_owned(),
+            "previous-gen-hash".to_string(),
+        );
+        assert_eq!(
+            hasher.calculate_dependency_hashes(dependencies()).unwrap(),
+            vec!["build-hash".to_string(), "previous-gen-hash".to_string()]
+        );
+        // The task's own hash is unchanged
+        assert_eq!(
+            tracker.hash(&TaskId::new("util", "gen")),
+            Some("gen-hash".to_string())
+        );
+    }
+
+    #[test]
+    fn test_undeclared_env_vars() {
+        let execution_env = EnvironmentVariableMap::from(HashMap::from([
+            ("API_KEY".to_string(), "secret".to_string()),
+            ("NODE_ENV".to_string(), "production".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+            ("USERPROFILE".to_string(), "C:\\Users\\turbo".to_string()),
+        ]));
+        let strict_env = EnvironmentVariableMap::from(HashMap::from([
+            ("NODE_ENV".to_string(), "production".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+        ]));
+
+        assert_eq!(
+            undeclared_env_vars(
+                "NODE_ENV=$NODE_ENV deploy --key ${API_KEY} --home %USERPROFILE% $UNSET $API_KEY",
+                &execution_env,
+                &strict_env,
+            ),
+            vec!["API_KEY".to_string(), "USERPROFILE".to_string()]
+        );
+        assert!(undeclared_env_vars("next build", &execution_env, &strict_env).is_empty());
+    }
 
     #[test]
     fn test_hash_tracker_is_send_and_sync() {
@@ -10,7 +10,7 @@ use tracing_appender::{non_blocking::NonBlocking, rolling::RollingFileAppender};
 use tracing_chrome::ChromeLayer;
 pub use tracing_subscriber::reload::Error;
 use tracing_subscriber::{
-    filter::Filtered,
+    filter::{filter_fn, FilterExt, Filtered},
     fmt::{
         self,
         format::{DefaultFields, Writer},
@@ -24,6 +24,8 @@ use tracing_subscriber::{
 };
 use turborepo_ui::ColorConfig;
 
+use crate::cli::LogFormat;
+
 // a lot of types to make sure we record the right relationships
 
 /// Note that we cannot express the type of `std::io::stderr` directly, so
@@ -38,12 +40,12 @@ impl<'a> MakeWriter<'a> for StdErrWrapper {
     }
 }
 
-/// A basic logger that logs to stderr using the TurboFormatter.
-/// The first generic parameter refers to the previous layer, which
-/// is in this case the default layer (`Registry`).
-type StdErrLog = fmt::Layer<Registry, DefaultFields, TurboFormatter, StdErrWrapper>;
-/// We filter this using an EnvFilter.
-type StdErrLogFiltered = Filtered<StdErrLog, EnvFilter, Registry>;
+/// A basic logger that logs to stderr using either the TurboFormatter or,
+/// when JSON logs are requested, the JSON formatter. It is filtered using an
+/// EnvFilter. The layer is boxed since the two formatters produce different
+/// types. The generic parameter refers to the previous layer, which is in this
+/// case the default layer (`Registry`).
+type StdErrLogFiltered = Box<dyn Layer<Registry> + Send + Sync>;
 /// When the `StdErrLogFiltered` is applied to the `Registry`, we get a
 /// `StdErrLogLayered`, which forms the base for the next layer.
 type StdErrLogLayered = layer::Layered<StdErrLogFiltered, Registry>;
@@ -96,13 +98,19 @@ impl TurboSubscriber {
     /// - If the verbosity argument (usually determined by a flag) is provided,
     ///   it overrides the default global log level. This means it overrides the
     ///   `TURBO_LOG_VERBOSITY` global setting, but not per-module settings.
+    /// - If the log format is `Json`, each event is written as a JSON object on
+    ///   its own line, including the fields of the task currently executing.
     ///
     /// `TurboSubscriber` has optional loggers that can be enabled later:
     /// - `set_daemon_logger` enables logging to a file, using the standard
     ///   formatter.
     /// - `enable_chrome_tracing` enables logging to a file, using the chrome
     ///   tracing formatter.
-    pub fn new_with_verbosity(verbosity: usize, color_config: &ColorConfig) -> Self {
+    pub fn new_with_verbosity(
+        verbosity: usize,
+        log_format: LogFormat,
+        color_config: &ColorConfig,
+    ) -> Self {
         let level_override = match verbosity {
             0 => None,
             1 => Some(LevelFilter::INFO),
@@ -126,12 +134,23 @@ impl TurboSubscriber {
             }
         };
 
-        let stderr = fmt::layer()
-            .with_writer(StdErrWrapper {})
-            .event_format(TurboFormatter::new_with_ansi(
-                !color_config.should_strip_ansi,
-            ))
-            .with_filter(env_filter(LevelFilter::WARN));
+        let stderr: StdErrLogFiltered = match log_format {
+            LogFormat::Text => fmt::layer()
+                .with_writer(StdErrWrapper {})
+                .event_format(TurboFormatter::new_with_ansi(
+                    !color_config.should_strip_ansi,
+                ))
+                .with_filter(env_filter(LevelFilter::WARN))
+                .boxed(),
+            // Task spans are always enabled so that every event emitted while a task
+            // executes carries the task and package it belongs to.
+            LogFormat::Json => json_layer(StdErrWrapper {})
+                .with_filter(
+                    env_filter(LevelFilter::WARN)
+                        .or(filter_fn(|meta| meta.is_span() && meta.name() == TASK_SPAN)),
+                )
+                .boxed(),
+        };
 
         // we set this layer to None to start with, effectively disabling it
         let (logrotate, daemon_update) = reload::Layer::new(Option::<DaemonLog>::None);
@@ -236,6 +255,26 @@ impl Drop for TurboSubscriber {
     }
 }
 
+/// The name of the span that wraps the execution of a single task.
+pub const TASK_SPAN: &str = "execute_task";
+
+/// A logger that writes each event as a JSON object on its own line. Event
+/// fields are flattened into the object and the fields of the current span are
+/// included under the `span` key.
+fn json_layer<S, W>(writer: W) -> impl Layer<S> + Send + Sync + 'static
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    fmt::layer()
+        .json()
+        .flatten_event(true)
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_ansi(false)
+        .with_writer(writer)
+}
+
 /// The formatter for TURBOREPO
 ///
 /// This is a port of the go formatter, which follows a few main rules:
@@ -353,3 +392,65 @@ fn write_message<FG: Color, BG: Color>(
     event.record(&mut visitor);
     writeln!(writer)
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing_subscriber::{fmt::MakeWriter, prelude::*, Registry};
+
+    use super::{json_layer, TASK_SPAN};
+
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for BufferWriter {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_json_log_lines() {
+        let writer = BufferWriter::default();
+        let subscriber = Registry::default().with(json_layer(writer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("outside of a task");
+            let span = tracing::info_span!(TASK_SPAN, task = "build", package = "web");
+            let _guard = span.enter();
+            tracing::error!(exit_code = 1, "task failed");
+        });
+
+        let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        let lines = output
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "WARN");
+        assert_eq!(lines[0]["message"], "outside of a task");
+        assert!(lines[0].get("span").is_none());
+        assert_eq!(lines[1]["level"], "ERROR");
+        assert_eq!(lines[1]["message"], "task failed");
+        assert_eq!(lines[1]["exit_code"], 1);
+        assert_eq!(lines[1]["span"]["task"], "build");
+        assert_eq!(lines[1]["span"]["package"], "web");
+    }
+}
@@ -8,7 +8,7 @@ use turborepo_repository::{
     package_json::PackageJson,
 };
 
-use super::{Pipeline, RawTaskDefinition, TurboJson, CONFIG_FILE};
+use super::{CacheMode, Pipeline, RawTaskDefinition, TurboJson, CONFIG_FILE};
 use crate::{
     cli::EnvMode,
     config::Error,
@@ -23,6 +23,8 @@ pub struct TurboJsonLoader {
     repo_root: AbsoluteSystemPathBuf,
     cache: HashMap<PackageName, TurboJson>,
     strategy: Strategy,
+    // The `environments` entry whose task overrides are applied on load
+    environment: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +59,7 @@ impl TurboJsonLoader {
         Self {
             repo_root,
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::Workspace { packages },
         }
     }
@@ -71,6 +74,7 @@ impl TurboJsonLoader {
         Self {
             repo_root,
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::WorkspaceNoTurboJson { packages },
         }
     }
@@ -85,6 +89,7 @@ impl TurboJsonLoader {
         Self {
             repo_root,
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::SinglePackage {
                 root_turbo_json,
                 package_json,
@@ -102,6 +107,7 @@ impl TurboJsonLoader {
         Self {
             repo_root,
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::TaskAccess {
                 root_turbo_json,
                 package_json,
@@ -119,14 +125,29 @@ impl TurboJsonLoader {
                 .expect("wasn't able to create absolute system path")
                 .to_owned(),
             cache: turbo_jsons,
+            environment: None,
             strategy: Strategy::Noop,
         }
     }
 
+    /// Apply the task overrides of the given environment to every loaded
+    /// turbo.json
+    pub fn with_environment(mut self, environment: Option<String>) -> Self {
+        self.environment = environment;
+        self
+    }
+
     /// Load a turbo.json for a given package
     pub fn load<'a>(&'a mut self, package: &PackageName) -> Result<&'a TurboJson, Error> {
         if !self.cache.contains_key(package) {
-            let turbo_json = self.uncached_load(package)?;
+            let mut turbo_json = self.uncached_load(package)?;
+            if let Some(environment) = &self.environment {
+                match turbo_json.apply_environment(environment) {
+                    // Only the root turbo.json has to define the environment
+                    Err(Error::UnknownEnvironment { .. }) if package != &PackageName::Root => (),
+                    result => result?,
+                }
+            }
             self.cache.insert(package.clone(), turbo_json);
         }
         Ok(self
@@ -279,7 +300,7 @@ fn load_from_root_package_json(
             turbo_json.tasks.insert(
                 task_name,
                 Spanned::new(RawTaskDefinition {
-                    cache: Some(Spanned::new(false)),
+                    cache: Some(Spanned::new(CacheMode::Disabled)),
                     ..RawTaskDefinition::default()
                 }),
             );
@@ -298,7 +319,7 @@ fn root_turbo_json_from_scripts(scripts: &[String]) -> Result<TurboJson, Error>
         turbo_json.tasks.insert(
             task_name,
             Spanned::new(RawTaskDefinition {
-                cache: Some(Spanned::new(false)),
+                cache: Some(Spanned::new(CacheMode::Disabled)),
                 env_mode: Some(EnvMode::Loose),
                 ..Default::default()
             }),
@@ -317,7 +338,7 @@ fn workspace_turbo_json_from_scripts(scripts: &[String]) -> Result<TurboJson, Er
         turbo_json.tasks.insert(
             task_name,
             Spanned::new(RawTaskDefinition {
-                cache: Some(Spanned::new(false)),
+                cache: Some(Spanned::new(CacheMode::Disabled)),
                 env_mode: Some(EnvMode::Loose),
                 ..Default::default()
             }),
@@ -346,7 +367,7 @@ fn load_task_access_trace_turbo_json(
 
 #[cfg(test)]
 mod test {
-    use std::{collections::BTreeMap, fs};
+    use std::fs;
 
     use anyhow::Result;
     use tempfile::tempdir;
@@ -381,6 +402,7 @@ mod test {
         let mut loader = TurboJsonLoader {
             repo_root: repo_root.to_owned(),
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::Workspace {
                 packages: vec![(PackageName::Root, root_turbo_json)]
                     .into_iter()
@@ -407,7 +429,7 @@ mod test {
             tasks: Pipeline([(
                 "//#build".into(),
                 Spanned::new(RawTaskDefinition {
-                    cache: Some(Spanned::new(false)),
+                    cache: Some(Spanned::new(CacheMode::Disabled)),
                     ..RawTaskDefinition::default()
                 })
               )].into_iter().collect()
@@ -431,14 +453,14 @@ mod test {
             tasks: Pipeline([(
                 "//#build".into(),
                 Spanned::new(RawTaskDefinition {
-                    cache: Some(Spanned::new(true).with_range(81..85)),
+                    cache: Some(Spanned::new(CacheMode::Enabled).with_range(81..85)),
                     ..RawTaskDefinition::default()
                 }).with_range(50..103)
             ),
             (
                 "//#test".into(),
                 Spanned::new(RawTaskDefinition {
-                     cache: Some(Spanned::new(false)),
+                     cache: Some(Spanned::new(CacheMode::Disabled)),
                     ..RawTaskDefinition::default()
                 })
             )].into_iter().collect()),
@@ -513,10 +535,10 @@ mod test {
             trace_path.create_with_contents(content.as_bytes())?;
         }
 
-        let mut scripts = BTreeMap::new();
-        scripts.insert("build".into(), Spanned::new("echo building".into()));
         let root_package_json = PackageJson {
-            scripts,
+            scripts: [("build".into(), Spanned::new("echo building".into()))]
+                .into_iter()
+                .collect(),
             ..Default::default()
         };
 
@@ -581,6 +603,7 @@ mod test {
         let mut loader = TurboJsonLoader {
             repo_root: repo_root.to_owned(),
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::Workspace { packages },
         };
         let result = loader.load(&PackageName::from("a"));
@@ -610,6 +633,7 @@ mod test {
         let mut loader = TurboJsonLoader {
             repo_root: repo_root.to_owned(),
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::Workspace { packages },
         };
         a_turbo_json
@@ -642,6 +666,7 @@ mod test {
         let mut loader = TurboJsonLoader {
             repo_root: repo_root.to_owned(),
             cache: HashMap::new(),
+            environment: None,
             strategy: Strategy::WorkspaceNoTurboJson { packages },
         };
 
@@ -651,7 +676,7 @@ mod test {
                 if let Some(def) = root_json.tasks.get(&TaskName::from(task_name)) {
                     assert_eq!(
                         def.cache.as_ref().map(|cache| *cache.as_inner()),
-                        Some(false)
+                        Some(CacheMode::Disabled)
                     );
                 } else {
                     panic!("didn't find {task_name}");
@@ -665,7 +690,7 @@ mod test {
                 if let Some(def) = pkg_a_json.tasks.get(&TaskName::from(task_name)) {
                     assert_eq!(
                         def.cache.as_ref().map(|cache| *cache.as_inner()),
-                        Some(false)
+                        Some(CacheMode::Disabled)
                     );
                 } else {
                     panic!("didn't find {task_name}");
@@ -12,12 +12,13 @@ use serde::{Deserialize, Serialize};
 use struct_iterable::Iterable;
 use turbopath::AbsoluteSystemPath;
 use turborepo_errors::Spanned;
-use turborepo_repository::package_graph::ROOT_PKG_NAME;
+use turborepo_repository::package_graph::{PackageName, ROOT_PKG_NAME};
 use turborepo_unescape::UnescapedString;
 
 use crate::{
     cli::{EnvMode, OutputLogsMode},
     config::{ConfigurationOptions, Error, InvalidEnvPrefixError},
+    hash::HashAlgorithm,
     run::{
         task_access::TaskAccessTraceFile,
         task_id::{TaskId, TaskName},
@@ -55,6 +56,7 @@ pub struct TurboJson {
     pub(crate) global_env: Vec<String>,
     pub(crate) global_pass_through_env: Option<Vec<String>>,
     pub(crate) tasks: Pipeline,
+    pub(crate) environments: BTreeMap<String, Pipeline>,
 }
 
 // Iterable is required to enumerate allowed keys
@@ -77,6 +79,12 @@ pub(crate) struct RawRemoteCacheOptions {
     timeout: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s3_endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s3_bucket: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s3_region: Option<String>,
 }
 
 impl From<&RawRemoteCacheOptions> for ConfigurationOptions {
@@ -90,6 +98,9 @@ impl From<&RawRemoteCacheOptions> for ConfigurationOptions {
             preflight: remote_cache_opts.preflight,
             timeout: remote_cache_opts.timeout,
             enabled: remote_cache_opts.enabled,
+            s3_endpoint: remote_cache_opts.s3_endpoint.clone(),
+            s3_bucket: remote_cache_opts.s3_bucket.clone(),
+            s3_region: remote_cache_opts.s3_region.clone(),
             ..Self::default()
         }
     }
@@ -123,6 +134,10 @@ pub struct RawTurboJson {
 
     #[serde(skip_serializing)]
     pub pipeline: Option<Spanned<Pipeline>>,
+    // Task overrides keyed by environment name, applied on top of `tasks`
+    // when that environment is selected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environments: Option<BTreeMap<String, RawEnvironment>>,
     // Configuration options when interfacing with the remote cache
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) remote_cache: Option<RawRemoteCacheOptions>,
@@ -139,12 +154,23 @@ pub struct RawTurboJson {
     pub env_mode: Option<EnvMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cache_dir: Option<Spanned<UnescapedString>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_max_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash_algorithm: Option<HashAlgorithm>,
 
     #[deserializable(rename = "//")]
     #[serde(skip)]
     _comment: Option<String>,
 }
 
+#[derive(Serialize, Default, Debug, Clone, Iterable, Deserializable)]
+#[serde(rename_all = "camelCase")]
+pub struct RawEnvironment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<Pipeline>,
+}
+
 #[derive(Serialize, Default, Debug, PartialEq, Clone)]
 #[serde(transparent)]
 pub struct Pipeline(BTreeMap<TaskName<'static>, Spanned<RawTaskDefinition>>);
@@ -182,6 +208,8 @@ pub enum UIMode {
     Stream,
     /// Use the web user interface (experimental)
     Web,
+    /// Use a compact view with one line per running task
+    Compact,
 }
 
 impl Default for UIMode {
@@ -196,9 +224,39 @@ impl UIMode {
     }
 
     /// Returns true if the UI mode has a sender,
-    /// i.e. web or tui but not stream
+    /// i.e. web, tui, or compact but not stream
     pub fn has_sender(&self) -> bool {
-        matches!(self, Self::Tui | Self::Web)
+        matches!(self, Self::Tui | Self::Web | Self::Compact)
+    }
+}
+
+/// The value of a task's `cache` key. Besides `true` and `false` a task can be
+/// `"readonly"`, which restores outputs from the cache but never writes them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CacheMode {
+    Enabled,
+    Disabled,
+    ReadOnly,
+}
+
+pub(crate) const CACHE_MODE_READONLY: &str = "readonly";
+
+impl From<bool> for CacheMode {
+    fn from(cache: bool) -> Self {
+        match cache {
+            true => CacheMode::Enabled,
+            false => CacheMode::Disabled,
+        }
+    }
+}
+
+impl Serialize for CacheMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CacheMode::Enabled => serializer.serialize_bool(true),
+            CacheMode::Disabled => serializer.serialize_bool(false),
+            CacheMode::ReadOnly => serializer.serialize_str(CACHE_MODE_READONLY),
+        }
     }
 }
 
@@ -207,7 +265,7 @@ impl UIMode {
 #[deserializable(unknown_fields = "deny")]
 pub struct RawTaskDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
-    cache: Option<Spanned<bool>>,
+    cache: Option<Spanned<CacheMode>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     depends_on: Option<Spanned<Vec<Spanned<UnescapedString>>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -224,6 +282,12 @@ pub struct RawTaskDefinition {
     output_logs: Option<Spanned<OutputLogsMode>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     interactive: Option<Spanned<bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ready_output: Option<Spanned<UnescapedString>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    concurrency_group: Option<Spanned<UnescapedString>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    noop_exit_code: Option<Spanned<i32>>,
     // TODO: Remove this once we have the ability to load task definitions directly
     // instead of deriving them from a TurboJson
     #[serde(skip)]
@@ -260,6 +324,9 @@ impl RawTaskDefinition {
         set_field!(self, other, env);
         set_field!(self, other, pass_through_env);
         set_field!(self, other, interactive);
+        set_field!(self, other, ready_output);
+        set_field!(self, other, concurrency_group);
+        set_field!(self, other, noop_exit_code);
         set_field!(self, other, env_mode);
     }
 }
@@ -316,7 +383,10 @@ impl TryFrom<RawTaskDefinition> for TaskDefinition {
     fn try_from(raw_task: RawTaskDefinition) -> Result<Self, Error> {
         let outputs = raw_task.outputs.unwrap_or_default().try_into()?;
 
-        let cache = raw_task.cache.map_or(true, |c| c.into_inner());
+        let cache_mode = raw_task
+            .cache
+            .map_or(CacheMode::Enabled, |c| c.into_inner());
+        let cache = cache_mode != CacheMode::Disabled;
         let interactive = raw_task
             .interactive
             .as_ref()
@@ -330,6 +400,27 @@ impl TryFrom<RawTaskDefinition> for TaskDefinition {
             }
         }
 
+        let persistent = *raw_task.persistent.unwrap_or_default();
+        if let Some(ready_output) = &raw_task.ready_output {
+            if !persistent {
+                let (span, text) = ready_output.span_and_text("turbo.json");
+                return Err(Error::ReadyOutputNotPersistent { span, text });
+            }
+        }
+        if let Some(concurrency_group) = &raw_task.concurrency_group {
+            if persistent {
+                let (span, text) = concurrency_group.span_and_text("turbo.json");
+                return Err(Error::PersistentConcurrencyGroup { span, text });
+            }
+        }
+
+        if let Some(noop_exit_code) = &raw_task.noop_exit_code {
+            if noop_exit_code.value == 0 {
+                let (span, text) = noop_exit_code.span_and_text("turbo.json");
+                return Err(Error::ZeroNoopExitCode { span, text });
+            }
+        }
+
         let mut env_var_dependencies = HashSet::new();
         let mut topological_dependencies: Vec<Spanned<TaskName>> = Vec::new();
         let mut task_dependencies: Vec<Spanned<TaskName>> = Vec::new();
@@ -401,14 +492,22 @@ impl TryFrom<RawTaskDefinition> for TaskDefinition {
         Ok(TaskDefinition {
             outputs,
             cache,
+            cache_readonly: cache_mode == CacheMode::ReadOnly,
             topological_dependencies,
             task_dependencies,
             env,
             inputs,
             pass_through_env,
             output_logs: *raw_task.output_logs.unwrap_or_default(),
-            persistent: *raw_task.persistent.unwrap_or_default(),
+            persistent,
             interactive,
+            ready_output: raw_task
+                .ready_output
+                .map(|ready_output| ready_output.into_inner().into()),
+            concurrency_group: raw_task
+                .concurrency_group
+                .map(|concurrency_group| concurrency_group.into_inner().into()),
+            noop_exit_code: raw_task.noop_exit_code.map(|code| code.into_inner()),
             env_mode: raw_task.env_mode,
         })
     }
@@ -419,7 +518,7 @@ impl RawTurboJson {
         repo_root: &AbsoluteSystemPath,
         path: &AbsoluteSystemPath,
     ) -> Result<RawTurboJson, Error> {
-        let contents = path.read_to_string()?;
+        let contents = path.read_to_string_lossy_bom()?;
         // Anchoring the path can fail if the path resides outside of the repository
         // Just display absolute path in that case.
         let root_relative_path = repo_root.anchor(path).map_or_else(
@@ -546,6 +645,12 @@ impl TryFrom<RawTurboJson> for TurboJson {
                 global_deps
             },
             tasks: raw_turbo.tasks.unwrap_or_default(),
+            environments: raw_turbo
+                .environments
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, environment)| (name, environment.tasks.unwrap_or_default()))
+                .collect(),
             // copy these over, we don't need any changes here.
             extends: raw_turbo
                 .extends
@@ -578,6 +683,35 @@ impl TurboJson {
         raw_turbo_json.try_into()
     }
 
+    /// Merges the task overrides of the given environment into `tasks`.
+    /// Overrides for tasks that aren't in `tasks` are added as new tasks.
+    pub(crate) fn apply_environment(&mut self, environment: &str) -> Result<(), Error> {
+        let Some(overrides) = self.environments.remove(environment) else {
+            let available = match self.environments.is_empty() {
+                true => "none".to_string(),
+                false => self
+                    .environments
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            };
+            return Err(Error::UnknownEnvironment {
+                name: environment.to_string(),
+                available,
+            });
+        };
+        for (task_name, task_override) in overrides {
+            match self.tasks.get_mut(&task_name) {
+                Some(task_definition) => task_definition.value.merge(task_override.into_inner()),
+                None => {
+                    self.tasks.insert(task_name, task_override);
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn task(&self, task_id: &TaskId, task_name: &TaskName) -> Option<RawTaskDefinition> {
         match self.tasks.get(&task_id.as_task_name()) {
             Some(entry) => Some(entry.value.clone()),
@@ -648,6 +782,72 @@ pub fn validate_extends(turbo_json: &TurboJson) -> Vec<Error> {
     }
 }
 
+/// Checks that every `dependsOn` entry of `turbo_json` refers to a task
+/// defined in the root turbo.json or in one of the package turbo.jsons.
+pub fn validate_depends_on(
+    turbo_json: &TurboJson,
+    root_turbo_json: &TurboJson,
+    package_turbo_jsons: &HashMap<PackageName, TurboJson>,
+) -> Vec<Error> {
+    let package_defines = |package: &PackageName, task: &str| {
+        package_turbo_jsons
+            .get(package)
+            .is_some_and(|turbo_json| turbo_json.tasks.contains_key(&TaskName::from(task)))
+    };
+    let is_defined = |dependency: &TaskName| {
+        if root_turbo_json.tasks.contains_key(dependency) {
+            return true;
+        }
+        match dependency.package() {
+            // Root tasks need their own entry before they can be depended on
+            Some(ROOT_PKG_NAME) => false,
+            Some(package) => {
+                root_turbo_json
+                    .tasks
+                    .contains_key(&TaskName::from(dependency.task()))
+                    || package_defines(&PackageName::from(package), dependency.task())
+            }
+            None => {
+                root_turbo_json
+                    .tasks
+                    .keys()
+                    .any(|task_name| task_name.task() == dependency.task())
+                    || package_turbo_jsons
+                        .keys()
+                        .any(|package| package_defines(package, dependency.task()))
+            }
+        }
+    };
+
+    turbo_json
+        .tasks
+        .iter()
+        .flat_map(|(task_name, entry)| {
+            entry
+                .depends_on
+                .iter()
+                .flat_map(|depends_on| depends_on.iter())
+                .filter_map(move |dependency| {
+                    let dependency_name = TaskName::from(
+                        dependency
+                            .trim_start_matches(TOPOLOGICAL_PIPELINE_DELIMITER)
+                            .to_string(),
+                    );
+                    if is_defined(&dependency_name) {
+                        return None;
+                    }
+                    let (span, text) = dependency.span_and_text("turbo.json");
+                    Some(Error::MissingDependsOnTask {
+                        task_name: dependency_name.to_string(),
+                        dependant: task_name.to_string(),
+                        span,
+                        text,
+                    })
+                })
+        })
+        .collect()
+}
+
 fn gather_env_vars(
     vars: Vec<Spanned<impl Into<String>>>,
     key: &str,
@@ -680,17 +880,21 @@ fn gather_env_vars(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use anyhow::Result;
     use biome_deserialize::json::deserialize_from_json_str;
     use biome_json_parser::JsonParserOptions;
     use pretty_assertions::assert_eq;
     use serde_json::json;
     use test_case::test_case;
+    use turborepo_repository::package_graph::PackageName;
     use turborepo_unescape::UnescapedString;
 
-    use super::{RawTurboJson, Spanned, UIMode};
+    use super::{validate_depends_on, CacheMode, RawTurboJson, Spanned, TurboJson, UIMode};
     use crate::{
-        cli::OutputLogsMode,
+        cli::{EnvMode, OutputLogsMode},
+        config::Error,
         run::task_id::TaskName,
         task_graph::{TaskDefinition, TaskOutputs},
         turbo_json::RawTaskDefinition,
@@ -710,6 +914,57 @@ mod tests {
         TaskDefinition::default()
     ; "just persistent"
     )]
+    #[test_case(
+        r#"{ "persistent": true, "readyOutput": "ready on" }"#,
+        RawTaskDefinition {
+            persistent: Some(Spanned::new(true).with_range(16..20)),
+            ready_output: Some(Spanned::<UnescapedString>::new("ready on".into()).with_range(37..47)),
+            ..RawTaskDefinition::default()
+        },
+        TaskDefinition {
+            persistent: true,
+            ready_output: Some("ready on".to_string()),
+            ..TaskDefinition::default()
+        }
+    ; "persistent with ready output"
+    )]
+    #[test_case(
+        r#"{ "concurrencyGroup": "db" }"#,
+        RawTaskDefinition {
+            concurrency_group: Some(Spanned::<UnescapedString>::new("db".into()).with_range(22..26)),
+            ..RawTaskDefinition::default()
+        },
+        TaskDefinition {
+            concurrency_group: Some("db".to_string()),
+            ..TaskDefinition::default()
+        }
+    ; "concurrency group"
+    )]
+    #[test_case(
+        r#"{ "noopExitCode": 78 }"#,
+        RawTaskDefinition {
+            noop_exit_code: Some(Spanned::new(78).with_range(18..20)),
+            ..RawTaskDefinition::default()
+        },
+        TaskDefinition {
+            noop_exit_code: Some(78),
+            ..TaskDefinition::default()
+        }
+    ; "noop exit code"
+    )]
+    #[test_case(
+        r#"{ "cache": "readonly" }"#,
+        RawTaskDefinition {
+            cache: Some(Spanned::new(CacheMode::ReadOnly).with_range(11..21)),
+            ..RawTaskDefinition::default()
+        },
+        TaskDefinition {
+            cache: true,
+            cache_readonly: true,
+            ..TaskDefinition::default()
+        }
+    ; "readonly cache"
+    )]
     #[test_case(
         r#"{
           "dependsOn": ["cli#build"],
@@ -727,11 +982,14 @@ mod tests {
             env: Some(vec![Spanned::<UnescapedString>::new("OS".into()).with_range(58..62)]),
             pass_through_env: Some(vec![Spanned::<UnescapedString>::new("AWS_SECRET_KEY".into()).with_range(94..110)]),
             outputs: Some(vec![Spanned::<UnescapedString>::new("package/a/dist".into()).with_range(135..151)]),
-            cache: Some(Spanned::new(false).with_range(173..178)),
+            cache: Some(Spanned::new(CacheMode::Disabled).with_range(173..178)),
             inputs: Some(vec![Spanned::<UnescapedString>::new("package/a/src/**".into()).with_range(201..219)]),
             output_logs: Some(Spanned::new(OutputLogsMode::Full).with_range(246..252)),
             persistent: Some(Spanned::new(true).with_range(278..282)),
             interactive: Some(Spanned::new(true).with_range(309..313)),
+            ready_output: None,
+            concurrency_group: None,
+            noop_exit_code: None,
             env_mode: None,
         },
         TaskDefinition {
@@ -741,6 +999,7 @@ mod tests {
               exclusions: vec![],
           },
           cache: false,
+          cache_readonly: false,
           inputs: vec!["package/a/src/**".to_string()],
           output_logs: OutputLogsMode::Full,
           pass_through_env: Some(vec!["AWS_SECRET_KEY".to_string()]),
@@ -748,6 +1007,9 @@ mod tests {
           topological_dependencies: vec![],
           persistent: true,
           interactive: true,
+          ready_output: None,
+          concurrency_group: None,
+          noop_exit_code: None,
           env_mode: None,
         }
       ; "full"
@@ -768,11 +1030,14 @@ mod tests {
             env: Some(vec![Spanned::<UnescapedString>::new("OS".into()).with_range(66..70)]),
             pass_through_env: Some(vec![Spanned::<UnescapedString>::new("AWS_SECRET_KEY".into()).with_range(106..122)]),
             outputs: Some(vec![Spanned::<UnescapedString>::new("package\\a\\dist".into()).with_range(151..169)]),
-            cache: Some(Spanned::new(false).with_range(195..200)),
+            cache: Some(Spanned::new(CacheMode::Disabled).with_range(195..200)),
             inputs: Some(vec![Spanned::<UnescapedString>::new("package\\a\\src\\**".into()).with_range(227..248)]),
             output_logs: Some(Spanned::new(OutputLogsMode::Full).with_range(279..285)),
             persistent: Some(Spanned::new(true).with_range(315..319)),
             interactive: None,
+            ready_output: None,
+            concurrency_group: None,
+            noop_exit_code: None,
             env_mode: None,
         },
         TaskDefinition {
@@ -782,6 +1047,7 @@ mod tests {
                 exclusions: vec![],
             },
             cache: false,
+            cache_readonly: false,
             inputs: vec!["package\\a\\src\\**".to_string()],
             output_logs: OutputLogsMode::Full,
             pass_through_env: Some(vec!["AWS_SECRET_KEY".to_string()]),
@@ -789,6 +1055,9 @@ mod tests {
             topological_dependencies: vec![],
             persistent: true,
             interactive: false,
+            ready_output: None,
+            concurrency_group: None,
+            noop_exit_code: None,
             env_mode: None,
         }
       ; "full (windows)"
@@ -813,6 +1082,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_ready_output_requires_persistent() {
+        let raw_task_definition: RawTaskDefinition = deserialize_from_json_str(
+            r#"{ "readyOutput": "ready on" }"#,
+            JsonParserOptions::default(),
+            "turbo.json",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        let result = TaskDefinition::try_from(raw_task_definition);
+        assert!(matches!(
+            result,
+            Err(crate::config::Error::ReadyOutputNotPersistent { .. })
+        ));
+    }
+
+    #[test]
+    fn test_concurrency_group_rejects_persistent() {
+        let raw_task_definition: RawTaskDefinition = deserialize_from_json_str(
+            r#"{ "persistent": true, "concurrencyGroup": "db" }"#,
+            JsonParserOptions::default(),
+            "turbo.json",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        let result = TaskDefinition::try_from(raw_task_definition);
+        assert!(matches!(
+            result,
+            Err(crate::config::Error::PersistentConcurrencyGroup { .. })
+        ));
+    }
+
+    #[test]
+    fn test_noop_exit_code_cannot_be_zero() {
+        let raw_task_definition: RawTaskDefinition = deserialize_from_json_str(
+            r#"{ "noopExitCode": 0 }"#,
+            JsonParserOptions::default(),
+            "turbo.json",
+        )
+        .into_deserialized()
+        .unwrap();
+
+        let result = TaskDefinition::try_from(raw_task_definition);
+        assert!(matches!(
+            result,
+            Err(crate::config::Error::ZeroNoopExitCode { .. })
+        ));
+    }
+
     #[test_case("[]", TaskOutputs::default() ; "empty")]
     #[test_case(r#"["target/**"]"#, TaskOutputs { inclusions: vec!["target/**".to_string()], exclusions: vec![] })]
     #[test_case(
@@ -882,6 +1202,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_environment_overrides() {
+        let raw = RawTurboJson::parse_from_serde(json!({
+            "tasks": {
+                "build": { "outputs": ["dist/**"] },
+                "test": {},
+            },
+            "environments": {
+                "ci": {
+                    "tasks": {
+                        "build": { "cache": false },
+                        "deploy": { "dependsOn": ["build"] },
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        let local = TurboJson::try_from(raw).unwrap();
+        let build = TaskName::from("build");
+
+        let mut ci = local.clone();
+        ci.apply_environment("ci").unwrap();
+        let ci_build = &ci.tasks.get(&build).unwrap().value;
+        assert_eq!(ci_build.cache.as_deref(), Some(&CacheMode::Disabled));
+        // Fields the environment doesn't set are kept
+        assert!(ci_build.outputs.is_some());
+        assert!(ci.tasks.contains_key(&TaskName::from("deploy")));
+
+        let mut local = local;
+        let err = local.apply_environment("local").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "environment `local` isn't defined in turbo.json, available environments: ci"
+        );
+        assert!(local.tasks.get(&build).unwrap().value.cache.is_none());
+        assert!(!local.tasks.contains_key(&TaskName::from("deploy")));
+    }
+
+    #[test_case(json!({ "build": { "dependsOn": ["^build"] } }), &[] ; "topological")]
+    #[test_case(json!({ "build": { "dependsOn": ["^nonsense"] } }), &["nonsense"] ; "missing topological")]
+    #[test_case(json!({ "web#build": {}, "test": { "dependsOn": ["build"] } }), &[] ; "defined for a package")]
+    #[test_case(json!({ "build": {}, "test": { "dependsOn": ["web#build"] } }), &[] ; "package task")]
+    #[test_case(json!({ "test": { "dependsOn": ["//#build"] } }), &["//#build"] ; "missing root task")]
+    fn test_validate_depends_on(tasks: serde_json::Value, expected: &[&str]) {
+        let raw = RawTurboJson::parse_from_serde(json!({ "tasks": tasks })).unwrap();
+        let turbo_json = TurboJson::try_from(raw).unwrap();
+        let missing = validate_depends_on(&turbo_json, &turbo_json, &HashMap::new())
+            .into_iter()
+            .map(|error| match error {
+                Error::MissingDependsOnTask { task_name, .. } => task_name,
+                error => panic!("unexpected error: {error}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(missing, expected);
+    }
+
+    #[test_case(&["codegen"], &[] ; "defined in the same package")]
+    #[test_case(&["build"], &[] ; "defined in the root")]
+    #[test_case(&["ui#storybook"], &[] ; "defined in another package")]
+    #[test_case(&["^storybook"], &[] ; "defined in a dependency")]
+    #[test_case(&["^nonsense"], &["nonsense"] ; "missing")]
+    #[test_case(&["web#storybook"], &["web#storybook"] ; "defined for a different package")]
+    fn test_validate_package_depends_on(depends_on: &[&str], expected: &[&str]) {
+        let turbo_json = |json: serde_json::Value| {
+            TurboJson::try_from(RawTurboJson::parse_from_serde(json).unwrap()).unwrap()
+        };
+        let root = turbo_json(json!({ "tasks": { "build": {} } }));
+        let web = turbo_json(json!({
+            "extends": ["//"],
+            "tasks": { "codegen": {}, "dev": { "dependsOn": depends_on } }
+        }));
+        let ui = turbo_json(json!({ "extends": ["//"], "tasks": { "storybook": {} } }));
+        let packages = HashMap::from([
+            (PackageName::from("web"), web.clone()),
+            (PackageName::from("ui"), ui),
+        ]);
+
+        let missing = validate_depends_on(&web, &root, &packages)
+            .into_iter()
+            .map(|error| match error {
+                Error::MissingDependsOnTask { task_name, .. } => task_name,
+                error => panic!("unexpected error: {error}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(missing, expected);
+    }
+
     #[test_case("full", Some(OutputLogsMode::Full) ; "full")]
     #[test_case("hash-only", Some(OutputLogsMode::HashOnly) ; "hash-only")]
     #[test_case("new-only", Some(OutputLogsMode::NewOnly) ; "new-only")]
@@ -915,6 +1322,15 @@ mod tests {
         assert_eq!(json.ui, expected);
     }
 
+    #[test_case(r#"{ "envMode": "loose" }"#, Some(EnvMode::Loose) ; "loose")]
+    #[test_case(r#"{ "envMode": "strict" }"#, Some(EnvMode::Strict) ; "strict")]
+    #[test_case(r#"{ "envMode": "loose-warn" }"#, Some(EnvMode::LooseWarn) ; "loose-warn")]
+    #[test_case(r#"{}"#, None ; "missing")]
+    fn test_env_mode(json: &str, expected: Option<EnvMode>) {
+        let json = RawTurboJson::parse(json, "").unwrap();
+        assert_eq!(json.env_mode, expected);
+    }
+
     #[test_case(r#"{ "daemon": true }"#, r#"{"daemon":true}"# ; "daemon_on")]
     #[test_case(r#"{ "daemon": false }"#, r#"{"daemon":false}"# ; "daemon_off")]
     fn test_daemon(json: &str, expected: &str) {
@@ -931,6 +1347,20 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test_case(r#"{"tasks":{"build":{"cache":true}}}"# ; "enabled")]
+    #[test_case(r#"{"tasks":{"build":{"cache":false}}}"# ; "disabled")]
+    #[test_case(r#"{"tasks":{"build":{"cache":"readonly"}}}"# ; "readonly")]
+    fn test_cache_mode_serde(json_str: &str) {
+        let parsed = RawTurboJson::parse(json_str, "").unwrap();
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json_str);
+    }
+
+    #[test]
+    fn test_invalid_cache_mode() {
+        let json = r#"{"tasks":{"build":{"cache":"sometimes"}}}"#;
+        assert!(RawTurboJson::parse(json, "").is_err());
+    }
+
     #[test_case(r#"{"dangerouslyDisablePackageManagerCheck":true}"#, Some(true) ; "t")]
     #[test_case(r#"{"dangerouslyDisablePackageManagerCheck":false}"#, Some(false) ; "f")]
     #[test_case(r#"{}"#, None ; "missing")]
@@ -940,4 +1370,52 @@ mod tests {
         let serialized = serde_json::to_string(&json).unwrap();
         assert_eq!(serialized, json_str);
     }
+
+    #[test]
+    fn test_jsonc() {
+        let json = r#"{
+          // Build everything
+          "tasks": {
+            "build": {
+              /* Next.js output */
+              "outputs": [".next/**", "!.next/cache/**",],
+            },
+          },
+        }"#;
+        let parsed = RawTurboJson::parse(json, "turbo.json").unwrap();
+        let outputs = parsed
+            .tasks
+            .as_ref()
+            .and_then(|pipeline| pipeline.0.get(&TaskName::from("build")))
+            .and_then(|build| build.value.outputs.as_ref())
+            .expect("build outputs should be present");
+        assert_eq!(
+            outputs.iter().map(|o| &***o).collect::<Vec<_>>(),
+            vec![".next/**", "!.next/cache/**"]
+        );
+
+        // Writing the config back out produces strict JSON
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#"{"tasks":{"build":{"outputs":[".next/**","!.next/cache/**"]}}}"#
+        );
+    }
+
+    #[test]
+    fn test_jsonc_syntax_error() {
+        let json = r#"{
+          "globalEnv": ["FOO",],,
+          "tasks": {
+            "build": {},
+          },
+        }"#;
+        let err = RawTurboJson::parse(json, "turbo.json").unwrap_err();
+        let errors = miette::Diagnostic::related(&err)
+            .expect("syntax errors should be reported")
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        // Only the doubled comma is reported, not the trailing commas or everything
+        // after the doubled comma
+        assert_eq!(errors, vec!["Expected a property but instead found ','."]);
+    }
 }
@@ -1,11 +1,16 @@
-use std::{backtrace, collections::BTreeMap, fmt::Debug, sync::Arc};
+use std::{
+    backtrace,
+    collections::{BTreeMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+};
 
 use biome_deserialize::{
-    json::deserialize_from_json_str, Deserializable, DeserializableValue,
-    DeserializationDiagnostic, DeserializationVisitor, VisitableType,
+    json::deserialize_from_json_ast, Deserializable, DeserializableValue,
+    DeserializationDiagnostic, DeserializationVisitor, Text, VisitableType,
 };
-use biome_diagnostics::DiagnosticExt;
-use biome_json_parser::JsonParserOptions;
+use biome_diagnostics::{Diagnostic as _, DiagnosticExt};
+use biome_json_parser::{parse_json, JsonParse, JsonParserOptions};
 use biome_json_syntax::TextRange;
 use convert_case::{Case, Casing};
 use miette::Diagnostic;
@@ -16,7 +21,9 @@ use turborepo_unescape::UnescapedString;
 
 use crate::{
     run::task_id::TaskName,
-    turbo_json::{Pipeline, RawTaskDefinition, RawTurboJson, Spanned},
+    turbo_json::{
+        CacheMode, Pipeline, RawTaskDefinition, RawTurboJson, Spanned, CACHE_MODE_READONLY,
+    },
 };
 
 #[derive(Debug, Error, Diagnostic)]
@@ -96,6 +103,53 @@ impl DeserializationVisitor for PipelineVisitor {
     }
 }
 
+impl Deserializable for CacheMode {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(CacheModeVisitor, name, diagnostics)
+    }
+}
+
+struct CacheModeVisitor;
+
+impl DeserializationVisitor for CacheModeVisitor {
+    type Output = CacheMode;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::BOOL.union(VisitableType::STR);
+
+    fn visit_bool(
+        self,
+        value: bool,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(CacheMode::from(value))
+    }
+
+    fn visit_str(
+        self,
+        value: Text,
+        range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        if value.text() == CACHE_MODE_READONLY {
+            Some(CacheMode::ReadOnly)
+        } else {
+            diagnostics.push(DeserializationDiagnostic::new_unknown_value(
+                value.text(),
+                range,
+                &[CACHE_MODE_READONLY],
+            ));
+            None
+        }
+    }
+}
+
 impl WithMetadata for RawTurboJson {
     fn add_text(&mut self, text: Arc<str>) {
         self.span.add_text(text.clone());
@@ -105,6 +159,9 @@ impl WithMetadata for RawTurboJson {
         self.global_pass_through_env.add_text(text.clone());
         self.tasks.add_text(text.clone());
         self.cache_dir.add_text(text.clone());
+        for environment in self.environments.iter_mut().flat_map(|e| e.values_mut()) {
+            environment.tasks.add_text(text.clone());
+        }
         self.pipeline.add_text(text);
     }
 
@@ -116,6 +173,9 @@ impl WithMetadata for RawTurboJson {
         self.global_pass_through_env.add_path(path.clone());
         self.tasks.add_path(path.clone());
         self.cache_dir.add_path(path.clone());
+        for environment in self.environments.iter_mut().flat_map(|e| e.values_mut()) {
+            environment.tasks.add_path(path.clone());
+        }
         self.pipeline.add_path(path);
     }
 }
@@ -148,7 +208,10 @@ impl WithMetadata for RawTaskDefinition {
         self.persistent.add_text(text.clone());
         self.outputs.add_text(text.clone());
         self.output_logs.add_text(text.clone());
-        self.interactive.add_text(text);
+        self.interactive.add_text(text.clone());
+        self.ready_output.add_text(text.clone());
+        self.concurrency_group.add_text(text.clone());
+        self.noop_exit_code.add_text(text);
     }
 
     fn add_path(&mut self, path: Arc<str>) {
@@ -162,7 +225,10 @@ impl WithMetadata for RawTaskDefinition {
         self.persistent.add_path(path.clone());
         self.outputs.add_path(path.clone());
         self.output_logs.add_path(path.clone());
-        self.interactive.add_path(path);
+        self.interactive.add_path(path.clone());
+        self.ready_output.add_path(path.clone());
+        self.concurrency_group.add_path(path.clone());
+        self.noop_exit_code.add_path(path);
     }
 }
 
@@ -184,11 +250,17 @@ impl RawTurboJson {
     ///
     /// returns: Result<RawTurboJson, Error>
     pub fn parse(text: &str, file_path: &str) -> Result<RawTurboJson, Error> {
-        let result = deserialize_from_json_str::<RawTurboJson>(
+        let parse = parse_json(
             text,
-            JsonParserOptions::default().with_allow_comments(),
-            file_path,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
         );
+        if !parse.diagnostics().is_empty() {
+            return Err(Self::syntax_error(text, file_path, parse));
+        }
+
+        let result = deserialize_from_json_ast::<RawTurboJson>(&parse.tree(), file_path);
 
         if !result.diagnostics().is_empty() {
             let diagnostics = result
@@ -219,4 +291,45 @@ impl RawTurboJson {
 
         Ok(turbo_json)
     }
+
+    // With trailing commas allowed, biome treats a doubled comma as the end of
+    // the object and reports everything after it as unexpected. Parsing without
+    // trailing commas recovers better, so we report those errors instead, minus
+    // the ones for trailing commas since only the strict parse has them.
+    fn syntax_error(text: &str, file_path: &str, parse: JsonParse) -> Error {
+        let error_starts = parse
+            .diagnostics()
+            .iter()
+            .filter_map(|d| d.location().span)
+            .map(|span| span.start())
+            .collect::<HashSet<_>>();
+        let strict_diagnostics =
+            parse_json(text, JsonParserOptions::default().with_allow_comments())
+                .into_diagnostics()
+                .into_iter()
+                .filter(|d| {
+                    d.location()
+                        .span
+                        .map_or(true, |span| error_starts.contains(&span.start()))
+                })
+                .collect::<Vec<_>>();
+        let diagnostics = if strict_diagnostics.is_empty() {
+            parse.into_diagnostics()
+        } else {
+            strict_diagnostics
+        };
+
+        Error {
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|d| {
+                    biome_diagnostics::Error::from(d)
+                        .with_file_source_code(text)
+                        .with_file_path(file_path)
+                        .into()
+                })
+                .collect(),
+            backtrace: backtrace::Backtrace::capture(),
+        }
+    }
 }
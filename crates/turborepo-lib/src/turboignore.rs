@@ -0,0 +1,119 @@
+//! `.turboignore` lists files at the repository root that never affect task
+//! hashes. It uses the same syntax as `.gitignore`.
+
+use thiserror::Error;
+use turbopath::{AbsoluteSystemPath, RelativeUnixPath};
+use wax::{Glob, Program};
+
+const TURBOIGNORE_FILE: &str = ".turboignore";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("failed to read .turboignore: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid pattern on line {line} of .turboignore: {source}")]
+    Pattern {
+        line: usize,
+        #[source]
+        source: Box<wax::BuildError>,
+    },
+}
+
+#[derive(Debug, Default)]
+pub struct TurboIgnore {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    // A pattern matches a path as well as everything below it
+    globs: Vec<Glob<'static>>,
+    negated: bool,
+}
+
+impl TurboIgnore {
+    /// Loads `.turboignore` from the repository root. A missing file ignores
+    /// nothing.
+    pub fn load(repo_root: &AbsoluteSystemPath) -> Result<Self, Error> {
+        let contents = repo_root
+            .join_component(TURBOIGNORE_FILE)
+            .read_existing_to_string()?;
+        contents.as_deref().map_or(Ok(Self::default()), Self::parse)
+    }
+
+    fn parse(contents: &str) -> Result<Self, Error> {
+        let mut rules = Vec::new();
+        for (index, line) in contents.lines().enumerate() {
+            let pattern = line.trim_end();
+            if pattern.is_empty() || pattern.starts_with('#') {
+                continue;
+            }
+            let (pattern, negated) = match pattern.strip_prefix('!') {
+                Some(pattern) => (pattern, true),
+                None => (pattern, false),
+            };
+            let (pattern, directory_only) = match pattern.strip_suffix('/') {
+                Some(pattern) => (pattern, true),
+                None => (pattern, false),
+            };
+            // Like .gitignore, a pattern containing a slash is relative to the
+            // root while any other pattern matches at any depth
+            let pattern = match pattern.strip_prefix('/') {
+                Some(pattern) => pattern.to_string(),
+                None if pattern.contains('/') => pattern.to_string(),
+                None => format!("**/{pattern}"),
+            };
+
+            let mut patterns = vec![format!("{pattern}/**")];
+            if !directory_only {
+                patterns.push(pattern);
+            }
+            let globs = patterns
+                .iter()
+                .map(|pattern| Glob::new(pattern).map(Glob::into_owned))
+                .collect::<Result<_, _>>()
+                .map_err(|source| Error::Pattern {
+                    line: index + 1,
+                    source: Box::new(source),
+                })?;
+            rules.push(Rule { globs, negated });
+        }
+        Ok(Self { rules })
+    }
+
+    /// Checks if a path relative to the repository root is ignored. As with
+    /// .gitignore the last matching pattern wins.
+    pub fn is_ignored(&self, path: &RelativeUnixPath) -> bool {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.globs.iter().any(|glob| glob.is_match(path.as_str())))
+            .map_or(false, |rule| !rule.negated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+    use turbopath::RelativeUnixPathBuf;
+
+    use super::TurboIgnore;
+
+    const TURBOIGNORE: &str =
+        "# editor files\n*.swp\n.vscode/\n/generated\npackages/*/meta.json\n\n*.log\n!keep.log\n";
+
+    #[test_case("apps/web/index.ts", false ; "not listed")]
+    #[test_case("apps/web/.index.ts.swp", true ; "matches at any depth")]
+    #[test_case("packages/ui/.vscode/settings.json", true ; "directory contents")]
+    #[test_case("generated/types.ts", true ; "anchored to root")]
+    #[test_case("apps/web/generated/types.ts", false ; "anchored only at root")]
+    #[test_case("packages/ui/meta.json", true ; "pattern with slash")]
+    #[test_case("apps/ui/meta.json", false ; "pattern with slash is anchored")]
+    #[test_case("apps/web/debug.log", true ; "wildcard")]
+    #[test_case("apps/web/keep.log", false ; "negated")]
+    fn test_is_ignored(path: &str, expected: bool) {
+        let turboignore = TurboIgnore::parse(TURBOIGNORE).unwrap();
+        let path = RelativeUnixPathBuf::new(path).unwrap();
+        assert_eq!(turboignore.is_ignored(&path), expected);
+    }
+}
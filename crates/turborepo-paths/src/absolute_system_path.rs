@@ -20,6 +20,8 @@ use crate::{
     AbsoluteSystemPathBuf, AnchoredSystemPath, AnchoredSystemPathBuf, PathError, RelativeUnixPath,
 };
 
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
 /// Models how two paths relate to each other
 #[derive(Debug, PartialEq, Eq)]
 pub enum PathRelation {
@@ -248,6 +250,21 @@ impl AbsoluteSystemPath {
         )
     }
 
+    /// Joins a `/` separated relative path, e.g. one read from a config file,
+    /// regardless of the platform's separator.
+    pub fn join_unix(&self, unix_path: &str) -> Result<AbsoluteSystemPathBuf, PathError> {
+        let unix_path = RelativeUnixPath::new(unix_path)?;
+        // A leading `/` is already rejected, but on Windows a drive prefix or a
+        // leading `\` would still replace the path we're joining onto
+        if matches!(
+            unix_path.to_system_path_buf().components().next(),
+            Some(Utf8Component::Prefix(_) | Utf8Component::RootDir)
+        ) {
+            return Err(PathError::NotRelative(unix_path.to_string()));
+        }
+        Ok(self.join_unix_path(unix_path))
+    }
+
     pub fn anchor(&self, path: &AbsoluteSystemPath) -> Result<AnchoredSystemPathBuf, PathError> {
         AnchoredSystemPathBuf::new(self, path)
     }
@@ -407,6 +424,13 @@ impl AbsoluteSystemPath {
         self.0.parent().map(Self::new_unchecked)
     }
 
+    /// Returns the closest ancestor of this path, starting with the path
+    /// itself, that contains an entry named `file_name`.
+    pub fn find_ancestor_containing(&self, file_name: &str) -> Option<&AbsoluteSystemPath> {
+        self.ancestors()
+            .find(|ancestor| ancestor.join_component(file_name).exists())
+    }
+
     pub fn file_name(&self) -> Option<&str> {
         self.0.file_name()
     }
@@ -441,6 +465,27 @@ impl AbsoluteSystemPath {
         fs::read_to_string(&self.0)
     }
 
+    /// Reads a text file that may have been written by an editor that adds a
+    /// byte order mark. A UTF-8 BOM is stripped, while UTF-16 content results
+    /// in an `InvalidData` error naming the encoding instead of a generic UTF-8
+    /// decoding failure.
+    pub fn read_to_string_lossy_bom(&self) -> Result<String, io::Error> {
+        let bytes = self.read()?;
+        if bytes.starts_with(&[0xFF, 0xFE]) || bytes.starts_with(&[0xFE, 0xFF]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{self} is encoded as UTF-16, only UTF-8 is supported"),
+            ));
+        }
+        let bytes = bytes.strip_prefix(UTF8_BOM).unwrap_or(&bytes);
+        String::from_utf8(bytes.to_vec()).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{self} is not valid UTF-8: {}", e.utf8_error()),
+            )
+        })
+    }
+
     /// Attempts to read a file returning None if the file does not exist
     /// For all other scenarios passes through the `read_to_string` results.
     pub fn read_existing_to_string(&self) -> Result<Option<String>, io::Error> {
@@ -584,6 +629,24 @@ mod tests {
         assert_eq!(base.contains(&other), expected);
     }
 
+    #[test_case(Some(&["a", "b"]) ; "found at self")]
+    #[test_case(Some(&["a"]) ; "found in parent")]
+    #[test_case(None ; "not found")]
+    fn test_find_ancestor_containing(marker_dir: Option<&[&str]>) -> Result<()> {
+        const MARKER: &str = "turbo-find-ancestor-marker.json";
+        let test_dir = tempfile::TempDir::with_prefix("find-ancestor")?;
+        let root = AbsoluteSystemPathBuf::try_from(test_dir.path())?;
+        let start = root.join_components(&["a", "b"]);
+        start.create_dir_all()?;
+        let expected = marker_dir.map(|dir| root.join_components(dir));
+        if let Some(dir) = &expected {
+            dir.join_component(MARKER).create_with_contents("{}")?;
+        }
+
+        assert_eq!(start.find_ancestor_containing(MARKER), expected.as_deref());
+        Ok(())
+    }
+
     #[test]
     fn test_read_non_existing_to_string() -> Result<()> {
         let test_dir = tempfile::TempDir::with_prefix("read-existing")?;
@@ -606,6 +669,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_to_string_lossy_bom() -> Result<()> {
+        let test_dir = tempfile::TempDir::with_prefix("read-bom")?;
+        let path = AbsoluteSystemPathBuf::try_from(test_dir.path())?.join_component("turbo.json");
+
+        path.create_with_contents(b"\xEF\xBB\xBF{\"tasks\": {}}")?;
+        let contents = path.read_to_string_lossy_bom()?;
+        assert_eq!(contents, "{\"tasks\": {}}");
+        serde_json::from_str::<serde_json::Value>(&contents)?;
+
+        path.create_with_contents(b"\xFF\xFE{\x00}\x00")?;
+        let err = path.read_to_string_lossy_bom().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("UTF-16"), "{err}");
+        Ok(())
+    }
+
     // Constructing a windows permissions struct is only possible by calling
     // fs::metadata so we only run these tests on unix.
     #[cfg(unix)]
@@ -655,6 +735,28 @@ mod tests {
         }
     }
 
+    #[test_case("foo/bar", Some(&["foo", "bar"]) ; "nested path")]
+    #[test_case("foo/../bar", Some(&["bar"]) ; "parent segment")]
+    #[test_case("/foo", None ; "absolute path")]
+    fn test_join_unix(unix_path: &str, expected: Option<&[&str]>) {
+        let root = AbsoluteSystemPathBuf::cwd().unwrap();
+        let actual = root.join_unix(unix_path).ok();
+        assert_eq!(
+            actual,
+            expected.map(|segments| root.join_components(segments))
+        );
+    }
+
+    #[cfg(windows)]
+    #[test_case("packages/web/dist", Some(r"C:\repo\packages\web\dist") ; "forward slashes")]
+    #[test_case("C:/other", None ; "drive prefix")]
+    #[test_case(r"\\server\share", None ; "unc path")]
+    fn test_join_unix_windows(unix_path: &str, expected: Option<&str>) {
+        let root = AbsoluteSystemPath::new(r"C:\repo").unwrap();
+        let actual = root.join_unix(unix_path).ok();
+        assert_eq!(actual.as_ref().map(|path| path.as_str()), expected);
+    }
+
     #[test_case(&["a", "b"], &["a", "b"], PathRelation::Parent ; "equal paths return parent")]
     #[test_case(&["a"], &["a", "b"], PathRelation::Parent ; "a is a parent of a/b")]
     #[test_case(&["a", "b"], &["a"], PathRelation::Child ; "a/b is a child of a")]
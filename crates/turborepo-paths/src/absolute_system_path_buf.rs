@@ -246,7 +246,7 @@ impl AsRef<Path> for AbsoluteSystemPathBuf {
 
 #[cfg(test)]
 mod tests {
-    use std::assert_matches::assert_matches;
+    use std::assert_matches;
 
     use crate::{AbsoluteSystemPathBuf, PathError, RelativeUnixPathBuf};
 
@@ -239,7 +239,7 @@ impl Drop for Pidlock {
 
 #[cfg(test)]
 mod tests {
-    use std::{assert_matches::assert_matches, fs, io::Write, path::PathBuf};
+    use std::{assert_matches, fs, io::Write, path::PathBuf};
 
     use rand::{distributions::Alphanumeric, thread_rng, Rng};
 
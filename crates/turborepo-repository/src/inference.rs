@@ -1,4 +1,8 @@
+use std::{env, iter};
+
+use itertools::Itertools;
 use thiserror::Error;
+use tracing::warn;
 use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
 
 use crate::{
@@ -20,10 +24,16 @@ pub struct RepoState {
     pub package_manager: Result<PackageManager, package_manager::Error>,
 }
 
+// Package managers describe themselves to the scripts they run with this,
+// e.g. `pnpm/8.14.0 npm/? node/v20.10.0 darwin arm64`
+const USER_AGENT_ENV: &str = "npm_config_user_agent";
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Failed to find repository root containing {0}")]
     NotFound(AbsoluteSystemPathBuf),
+    #[error("package.json requires {expected} but turbo was run with {actual}")]
+    PackageManagerMismatch { expected: String, actual: String },
 }
 
 #[derive(Debug)]
@@ -73,8 +83,11 @@ impl RepoState {
     ///
     /// returns: Result<RepoState, Error>
     pub fn infer(reference_dir: &AbsoluteSystemPath) -> Result<Self, Error> {
-        reference_dir
-            .ancestors()
+        let package_json_dirs = iter::successors(
+            reference_dir.find_ancestor_containing("package.json"),
+            |path| path.parent()?.find_ancestor_containing("package.json"),
+        );
+        package_json_dirs
             .filter_map(|path| {
                 PackageJson::load(&path.join_component("package.json"))
                     .ok()
@@ -108,16 +121,93 @@ impl RepoState {
                     current
                 }
             })
-            .map(|root| root.into())
+            .map(|root| {
+                warn_on_multiple_lockfiles(&root);
+                root.into()
+            })
             .ok_or_else(|| Error::NotFound(reference_dir.to_owned()))
     }
+
+    /// Checks that the package manager running turbo matches the version
+    /// pinned by the `packageManager` field of the root package.json. This
+    /// can only be checked when turbo is run from a package.json script.
+    ///
+    /// A mismatch is a warning unless `strict` is set.
+    pub fn check_package_manager_version(&self, strict: bool) -> Result<(), Error> {
+        let user_agent = env::var(USER_AGENT_ENV).unwrap_or_default();
+        check_package_manager_version(&self.root_package_json, &user_agent, strict)
+    }
+}
+
+fn check_package_manager_version(
+    package_json: &PackageJson,
+    user_agent: &str,
+    strict: bool,
+) -> Result<(), Error> {
+    let Some(package_manager) = &package_json.package_manager else {
+        return Ok(());
+    };
+    // An invalid `packageManager` is reported when detecting the package manager
+    let Ok((expected_manager, expected_version)) =
+        PackageManager::parse_package_manager_string(package_manager)
+    else {
+        return Ok(());
+    };
+    let Some((manager, version)) = user_agent
+        .split_whitespace()
+        .next()
+        .and_then(|agent| agent.split_once('/'))
+    else {
+        return Ok(());
+    };
+    // Running turbo through a different tool, e.g. `npx turbo` in a pnpm
+    // repository, doesn't mean the wrong package manager is installed
+    if manager != expected_manager || version == expected_version {
+        return Ok(());
+    }
+
+    let mismatch = Error::PackageManagerMismatch {
+        expected: format!("{expected_manager}@{expected_version}"),
+        actual: format!("{manager}@{version}"),
+    };
+    if strict {
+        return Err(mismatch);
+    }
+    warn!("{mismatch}");
+    Ok(())
+}
+
+// A lockfile for a package manager other than the one we use is most likely
+// left over from switching package managers, but it makes it unclear which
+// one the repository is meant to use.
+fn warn_on_multiple_lockfiles(root: &InferInfo) {
+    let Ok(package_manager) = &root.package_manager else {
+        return;
+    };
+    let lockfiles = [
+        PackageManager::Pnpm,
+        PackageManager::Npm,
+        PackageManager::Yarn,
+        PackageManager::Bun,
+    ]
+    .iter()
+    .map(PackageManager::lockfile_name)
+    .filter(|lockfile| root.path.join_component(lockfile).exists())
+    .collect::<Vec<_>>();
+    if lockfiles.len() > 1 {
+        warn!(
+            "found multiple lockfiles ({}), turbo will use {package_manager} and {}",
+            lockfiles.iter().join(", "),
+            package_manager.lockfile_name()
+        );
+    }
 }
 
 #[cfg(test)]
 mod test {
     use turbopath::AbsoluteSystemPathBuf;
 
-    use super::{RepoMode, RepoState};
+    use super::{check_package_manager_version, Error, RepoMode, RepoState};
     use crate::{package_json::PackageJson, package_manager::PackageManager};
 
     fn tmp_dir() -> (tempfile::TempDir, AbsoluteSystemPathBuf) {
@@ -348,6 +438,69 @@ mod test {
         );
     }
 
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_multiple_lockfiles_warns() {
+        let (_tmp, repo_root) = tmp_dir();
+        repo_root
+            .join_component("package.json")
+            .create_with_contents(r#"{"name": "mono", "packageManager": "pnpm@8.14.0"}"#)
+            .unwrap();
+        repo_root
+            .join_component("pnpm-lock.yaml")
+            .create_with_contents("")
+            .unwrap();
+        repo_root
+            .join_component("package-lock.json")
+            .create_with_contents("")
+            .unwrap();
+
+        let repo_state = RepoState::infer(&repo_root).unwrap();
+
+        assert_eq!(repo_state.package_manager.unwrap(), PackageManager::Pnpm);
+        assert!(logs_contain(
+            "found multiple lockfiles (pnpm-lock.yaml, package-lock.json), turbo will use pnpm \
+             and pnpm-lock.yaml"
+        ));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_package_manager_version_mismatch_warns() {
+        let package_json =
+            PackageJson::from_value(serde_json::json!({ "packageManager": "pnpm@8.14.0" }))
+                .unwrap();
+        let user_agent = "pnpm/8.6.0 npm/? node/v20.10.0 darwin arm64";
+
+        check_package_manager_version(&package_json, user_agent, false).unwrap();
+        assert!(logs_contain(
+            "package.json requires pnpm@8.14.0 but turbo was run with pnpm@8.6.0"
+        ));
+
+        let result = check_package_manager_version(&package_json, user_agent, true);
+        assert!(matches!(
+            result,
+            Err(Error::PackageManagerMismatch { expected, actual })
+                if expected == "pnpm@8.14.0" && actual == "pnpm@8.6.0"
+        ));
+    }
+
+    #[test]
+    fn test_package_manager_version_match() {
+        let package_json =
+            PackageJson::from_value(serde_json::json!({ "packageManager": "pnpm@8.14.0" }))
+                .unwrap();
+        for user_agent in [
+            "pnpm/8.14.0 npm/? node/v20.10.0 darwin arm64",
+            // npx isn't the package manager of the repository
+            "npm/10.2.4 node/v20.10.0 darwin arm64 workspaces/false",
+            // Not run by a package manager
+            "",
+        ] {
+            assert!(check_package_manager_version(&package_json, user_agent, true).is_ok());
+        }
+    }
+
     #[test]
     fn test_gh_8599() {
         // TODO: this test documents existing broken behavior, when we have time we
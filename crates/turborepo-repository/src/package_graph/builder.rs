@@ -601,7 +601,7 @@ impl PackageInfo {
 
 #[cfg(test)]
 mod test {
-    use std::assert_matches::assert_matches;
+    use std::assert_matches;
 
     use super::*;
 
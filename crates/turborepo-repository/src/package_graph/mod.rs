@@ -410,6 +410,106 @@ impl PackageGraph {
         visited
     }
 
+    /// Returns a new graph containing only `packages` and the edges among
+    /// them. The root package is always kept as every package depends on it,
+    /// and packages that lose all of their dependencies depend on the root
+    /// just like packages without internal dependencies do. The lockfile is
+    /// pruned to the external dependencies of the kept packages.
+    ///
+    /// Example:
+    ///
+    /// a -> b -> c
+    ///
+    /// subgraph({a, c}) = a, c with no edges between them
+    pub fn subgraph(&self, packages: &HashSet<PackageName>) -> Result<PackageGraph, Error> {
+        let keep = |name: &PackageName| name == &PackageName::Root || packages.contains(name);
+        let mut graph = self.graph.filter_map(
+            |_, node| match node {
+                PackageNode::Workspace(name) if !keep(name) => None,
+                node => Some(node.clone()),
+            },
+            |_, _| Some(()),
+        );
+        let node_lookup: HashMap<_, _> = graph
+            .node_indices()
+            .map(|index| (graph[index].clone(), index))
+            .collect();
+        let root_index = node_lookup[&PackageNode::Root];
+        let orphans = graph
+            .node_indices()
+            .filter(|index| *index != root_index)
+            .filter(|index| {
+                graph
+                    .neighbors_directed(*index, petgraph::Outgoing)
+                    .next()
+                    .is_none()
+            })
+            .collect::<Vec<_>>();
+        for index in orphans {
+            graph.add_edge(index, root_index, ());
+        }
+        let packages: HashMap<_, _> = self
+            .packages
+            .iter()
+            .filter(|(name, _)| keep(name))
+            .map(|(name, info)| (name.clone(), info.clone()))
+            .collect();
+
+        let lockfile = self
+            .lockfile
+            .as_deref()
+            .map(|lockfile| {
+                let workspace_paths = packages
+                    .iter()
+                    .filter(|(name, _)| matches!(name, PackageName::Other(_)))
+                    .map(|(_, info)| info.package_path().to_unix().to_string())
+                    .collect::<Vec<_>>();
+                let lockfile_keys = self
+                    .transitive_external_dependencies(packages.keys())
+                    .into_iter()
+                    .map(|package| package.key.clone())
+                    .collect::<Vec<_>>();
+                lockfile.subgraph(&workspace_paths, &lockfile_keys)
+            })
+            .transpose()?;
+
+        Ok(PackageGraph {
+            graph,
+            node_lookup,
+            packages,
+            package_manager: self.package_manager,
+            lockfile,
+            repo_root: self.repo_root.clone(),
+        })
+    }
+
+    /// Like `subgraph`, but also keeps the packages that `packages` depend on
+    /// transitively.
+    ///
+    /// Example:
+    ///
+    /// a -> b -> c
+    ///
+    /// subgraph_with_dependencies({b}) = b -> c
+    pub fn subgraph_with_dependencies(
+        &self,
+        packages: &HashSet<PackageName>,
+    ) -> Result<PackageGraph, Error> {
+        let focus = packages
+            .iter()
+            .map(|name| PackageNode::Workspace(name.clone()))
+            .collect::<Vec<_>>();
+        let packages = self
+            .transitive_closure(&focus)
+            .into_iter()
+            .filter_map(|node| match node {
+                PackageNode::Workspace(name) => Some(name.clone()),
+                PackageNode::Root => None,
+            })
+            .collect();
+        self.subgraph(&packages)
+    }
+
     pub fn transitive_external_dependencies<'a, I: IntoIterator<Item = &'a PackageName>>(
         &self,
         packages: I,
@@ -562,7 +662,7 @@ impl AsRef<str> for PackageName {
 
 #[cfg(test)]
 mod test {
-    use std::assert_matches::assert_matches;
+    use std::assert_matches;
 
     use serde_json::json;
 
@@ -688,6 +788,97 @@ mod test {
         );
     }
 
+    // Returns the edges of the graph as (dependent, dependency) pairs
+    fn edges(pkg_graph: &PackageGraph) -> HashSet<(PackageNode, PackageNode)> {
+        pkg_graph
+            .graph
+            .edge_indices()
+            .filter_map(|index| pkg_graph.graph.edge_endpoints(index))
+            .map(|(src, dst)| (pkg_graph.graph[src].clone(), pkg_graph.graph[dst].clone()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_subgraph() {
+        let root =
+            AbsoluteSystemPathBuf::new(if cfg!(windows) { r"C:\repo" } else { "/repo" }).unwrap();
+        let pkg_graph = PackageGraph::builder(
+            &root,
+            PackageJson::from_value(json!({ "name": "root" })).unwrap(),
+        )
+        .with_package_discovery(MockDiscovery)
+        .with_package_jsons(Some(
+            [
+                (
+                    "a",
+                    json!({ "name": "a", "dependencies": { "b": "workspace:*" } }),
+                ),
+                (
+                    "b",
+                    json!({ "name": "b", "dependencies": { "c": "workspace:*" } }),
+                ),
+                ("c", json!({ "name": "c" })),
+                (
+                    "d",
+                    json!({ "name": "d", "dependencies": { "a": "workspace:*" } }),
+                ),
+            ]
+            .into_iter()
+            .map(|(dir, package_json)| {
+                (
+                    root.join_component(dir),
+                    PackageJson::from_value(package_json).unwrap(),
+                )
+            })
+            .collect(),
+        ))
+        .build()
+        .await
+        .unwrap();
+
+        let a = PackageNode::Workspace("a".into());
+        let b = PackageNode::Workspace("b".into());
+        let c = PackageNode::Workspace("c".into());
+        let d = PackageNode::Workspace("d".into());
+        let root_workspace = PackageNode::Workspace(PackageName::Root);
+
+        let subgraph = pkg_graph
+            .subgraph(&["a".into(), "d".into()].into_iter().collect())
+            .unwrap();
+        assert_eq!(
+            subgraph.packages.keys().cloned().collect::<HashSet<_>>(),
+            [PackageName::Root, "a".into(), "d".into()]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(
+            edges(&subgraph),
+            [
+                (root_workspace.clone(), PackageNode::Root),
+                // a loses its dependency on b so it depends on the root
+                (a.clone(), PackageNode::Root),
+                (d, a),
+            ]
+            .into_iter()
+            .collect()
+        );
+        assert!(subgraph.validate().is_ok());
+
+        let subgraph = pkg_graph
+            .subgraph_with_dependencies(&["b".into()].into_iter().collect())
+            .unwrap();
+        assert_eq!(
+            edges(&subgraph),
+            [
+                (root_workspace, PackageNode::Root),
+                (c.clone(), PackageNode::Root),
+                (b, c),
+            ]
+            .into_iter()
+            .collect()
+        );
+    }
+
     #[derive(Debug)]
     struct MockLockfile {}
     impl turborepo_lockfiles::Lockfile for MockLockfile {
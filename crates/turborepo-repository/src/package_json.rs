@@ -8,6 +8,7 @@ use biome_deserialize::{json::deserialize_from_json_str, Text};
 use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::DiagnosticExt;
 use biome_json_parser::JsonParserOptions;
+use indexmap::IndexMap;
 use miette::Diagnostic;
 use serde::Serialize;
 use turbopath::{AbsoluteSystemPath, RelativeUnixPathBuf};
@@ -31,8 +32,9 @@ pub struct PackageJson {
     pub optional_dependencies: Option<BTreeMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub peer_dependencies: Option<BTreeMap<String, String>>,
-    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub scripts: BTreeMap<String, Spanned<String>>,
+    // Scripts are kept in the order they're declared in
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub scripts: IndexMap<String, Spanned<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resolutions: Option<BTreeMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,7 +63,7 @@ pub struct RawPackageJson {
     pub dev_dependencies: Option<BTreeMap<String, UnescapedString>>,
     pub optional_dependencies: Option<BTreeMap<String, UnescapedString>>,
     pub peer_dependencies: Option<BTreeMap<String, UnescapedString>>,
-    pub scripts: BTreeMap<String, Spanned<UnescapedString>>,
+    pub scripts: IndexMap<String, Spanned<UnescapedString>>,
     pub resolutions: Option<BTreeMap<String, UnescapedString>>,
     pub pnpm: Option<RawPnpmConfig>,
     // Unstructured fields kept for round trip capabilities
@@ -160,7 +162,7 @@ impl From<RawPnpmConfig> for PnpmConfig {
 impl PackageJson {
     pub fn load(path: &AbsoluteSystemPath) -> Result<PackageJson, Error> {
         tracing::trace!("loading package.json from {}", path);
-        let contents = path.read_to_string()?;
+        let contents = path.read_to_string_lossy_bom()?;
         Self::load_from_str(&contents, path.as_str())
     }
 
@@ -204,6 +206,11 @@ impl PackageJson {
             .chain(self.dependencies.iter().flatten())
     }
 
+    /// Returns true if the package.json declares a script named `script_name`
+    pub fn has_script(&self, script_name: &str) -> bool {
+        self.scripts.contains_key(script_name)
+    }
+
     /// Returns the command for script_name if it is non-empty
     pub fn command(&self, script_name: &str) -> Option<&str> {
         self.scripts
@@ -231,6 +238,7 @@ mod test {
     use pretty_assertions::assert_eq;
     use serde_json::json;
     use test_case::test_case;
+    use turbopath::AbsoluteSystemPathBuf;
 
     use super::*;
 
@@ -251,4 +259,44 @@ mod test {
         let actual = serde_json::to_value(package_json).unwrap();
         assert_eq!(actual, json);
     }
+
+    #[test]
+    fn test_scripts_keep_declaration_order() {
+        let package_json = PackageJson::load_from_str(
+            r#"{
+                "name": "web",
+                "scripts": {
+                    "lint": "eslint .",
+                    "build": "next build",
+                    "dev": "next dev",
+                    "check-types": ""
+                }
+            }"#,
+            "package.json",
+        )
+        .unwrap();
+
+        assert_eq!(
+            package_json.scripts.keys().collect::<Vec<_>>(),
+            vec!["lint", "build", "dev", "check-types"]
+        );
+        assert!(package_json.has_script("build"));
+        assert!(package_json.has_script("check-types"));
+        assert!(!package_json.has_script("test"));
+        assert_eq!(package_json.command("build"), Some("next build"));
+        assert_eq!(package_json.command("check-types"), None);
+    }
+
+    #[test]
+    fn test_load_with_bom() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = AbsoluteSystemPathBuf::try_from(tmp.path())
+            .unwrap()
+            .join_component("package.json");
+        path.create_with_contents(b"\xEF\xBB\xBF{\"name\": \"web\"}")
+            .unwrap();
+
+        let package_json = PackageJson::load(&path).unwrap();
+        assert_eq!(package_json.name.as_deref(), Some("web"));
+    }
 }
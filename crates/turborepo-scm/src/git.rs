@@ -22,10 +22,19 @@ pub struct InvalidRange {
     pub to_ref: Option<String>,
 }
 
+/// The result of `SCM::changed_files_with_renames`. Both sides of a rename
+/// are included in `files`, with the `(old, new)` pairs in `renames`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ChangedFiles {
+    pub files: HashSet<AnchoredSystemPathBuf>,
+    pub renames: Vec<(AnchoredSystemPathBuf, AnchoredSystemPathBuf)>,
+}
+
 impl SCM {
     pub fn get_current_branch(&self, path: &AbsoluteSystemPath) -> Result<String, Error> {
         match self {
             Self::Git(git) => git.get_current_branch(),
+            Self::Other(backend) => backend.get_current_branch(),
             Self::Manual => Err(Error::GitRequired(path.to_owned())),
         }
     }
@@ -33,6 +42,7 @@ impl SCM {
     pub fn get_current_sha(&self, path: &AbsoluteSystemPath) -> Result<String, Error> {
         match self {
             Self::Git(git) => git.get_current_sha(),
+            Self::Other(backend) => backend.get_current_sha(),
             Self::Manual => Err(Error::GitRequired(path.to_owned())),
         }
     }
@@ -46,11 +56,38 @@ impl SCM {
         allow_unknown_objects: bool,
         merge_base: bool,
     ) -> Result<Result<HashSet<AnchoredSystemPathBuf>, InvalidRange>, Error> {
+        Ok(self
+            .changed_files_with_renames(
+                turbo_root,
+                from_commit,
+                to_commit,
+                include_uncommitted,
+                allow_unknown_objects,
+                merge_base,
+                false,
+            )?
+            .map(|changed_files| changed_files.files))
+    }
+
+    /// Like `changed_files`, but if `detect_renames` is set renamed files are
+    /// reported with their previous path as well. Only git supports rename
+    /// detection, other SCMs never report renames.
+    #[allow(clippy::too_many_arguments)]
+    pub fn changed_files_with_renames(
+        &self,
+        turbo_root: &AbsoluteSystemPath,
+        from_commit: Option<&str>,
+        to_commit: Option<&str>,
+        include_uncommitted: bool,
+        allow_unknown_objects: bool,
+        merge_base: bool,
+        detect_renames: bool,
+    ) -> Result<Result<ChangedFiles, InvalidRange>, Error> {
         fn unable_to_detect_range(
             error: impl std::error::Error,
             from_ref: Option<String>,
             to_ref: Option<String>,
-        ) -> Result<Result<HashSet<AnchoredSystemPathBuf>, InvalidRange>, Error> {
+        ) -> Result<Result<ChangedFiles, InvalidRange>, Error> {
             warn!(
                 "unable to detect git range, assuming all files have changed: {}",
                 error
@@ -65,8 +102,9 @@ impl SCM {
                     to_commit,
                     include_uncommitted,
                     merge_base,
+                    detect_renames,
                 ) {
-                    Ok(files) => Ok(Ok(files)),
+                    Ok(changed_files) => Ok(Ok(changed_files)),
                     Err(ref error @ Error::Git(ref message, _))
                         if allow_unknown_objects
                             && (message.contains("no merge base")
@@ -86,6 +124,33 @@ impl SCM {
                     Err(e) => Err(e),
                 }
             }
+            Self::Other(backend) => {
+                match backend.changed_files(
+                    turbo_root,
+                    from_commit,
+                    to_commit,
+                    include_uncommitted,
+                    merge_base,
+                ) {
+                    Ok(files) => Ok(Ok(ChangedFiles {
+                        files,
+                        renames: Vec::new(),
+                    })),
+                    Err(ref error @ Error::Vcs(..)) if allow_unknown_objects => {
+                        unable_to_detect_range(
+                            error,
+                            from_commit.map(|c| c.to_string()),
+                            to_commit.map(|c| c.to_string()),
+                        )
+                    }
+                    Err(Error::UnableToResolveRef) => unable_to_detect_range(
+                        Error::UnableToResolveRef,
+                        from_commit.map(|c| c.to_string()),
+                        to_commit.map(|c| c.to_string()),
+                    ),
+                    Err(e) => Err(e),
+                }
+            }
             Self::Manual => Err(Error::GitRequired(turbo_root.to_owned())),
         }
     }
@@ -97,6 +162,7 @@ impl SCM {
     ) -> Result<Vec<u8>, Error> {
         match self {
             Self::Git(git) => git.previous_content(from_commit, file_path),
+            Self::Other(backend) => backend.previous_content(from_commit, file_path),
             Self::Manual => Err(Error::GitRequired(file_path.to_owned())),
         }
     }
@@ -255,6 +321,10 @@ impl Git {
             };
         }
 
+        if let Some(default_branch) = self.remote_default_branch() {
+            return Ok(default_branch);
+        }
+
         let main_result = self.execute_git_command(&["rev-parse", "main"], "");
         if main_result.is_ok() {
             return Ok("main".to_string());
@@ -267,6 +337,26 @@ impl Git {
         Err(Error::UnableToResolveRef)
     }
 
+    /// Returns the branch that `origin/HEAD` points to, which is the default
+    /// branch of the repository if it was cloned or has had
+    /// `git remote set-head` run.
+    fn remote_default_branch(&self) -> Option<String> {
+        let output = self
+            .execute_git_command(
+                &[
+                    "symbolic-ref",
+                    "--quiet",
+                    "--short",
+                    "refs/remotes/origin/HEAD",
+                ],
+                "",
+            )
+            .ok()?;
+        let branch = String::from_utf8(output).ok()?;
+        let branch = branch.trim();
+        (!branch.is_empty()).then(|| branch.to_string())
+    }
+
     fn changed_files(
         &self,
         turbo_root: &AbsoluteSystemPath,
@@ -274,26 +364,42 @@ impl Git {
         to_commit: Option<&str>,
         include_uncommitted: bool,
         merge_base: bool,
-    ) -> Result<HashSet<AnchoredSystemPathBuf>, Error> {
+        detect_renames: bool,
+    ) -> Result<ChangedFiles, Error> {
         let turbo_root_relative_to_git_root = self.root.anchor(turbo_root)?;
         let pathspec = turbo_root_relative_to_git_root.as_str();
 
-        let mut files = HashSet::new();
+        let mut changed_files = ChangedFiles::default();
 
         let valid_from = self.resolve_base(from_commit, CIEnv::new())?;
 
+        // --name-only only lists the new path of a renamed file, so we need the
+        // status to recover the old one
+        let format = if detect_renames {
+            "--name-status"
+        } else {
+            "--name-only"
+        };
         let mut args = if let Some(to_commit) = to_commit {
-            vec!["diff", "--name-only", &valid_from, to_commit]
+            vec!["diff", format, &valid_from, to_commit]
         } else {
-            vec!["diff", "--name-only", &valid_from]
+            vec!["diff", format, &valid_from]
         };
 
         if merge_base {
             args.push("--merge-base");
         }
 
+        if detect_renames {
+            args.push("--find-renames");
+        }
+
         let output = self.execute_git_command(&args, pathspec)?;
-        self.add_files_from_stdout(&mut files, turbo_root, output);
+        if detect_renames {
+            self.add_files_from_name_status(&mut changed_files, turbo_root, output);
+        } else {
+            self.add_files_from_stdout(&mut changed_files.files, turbo_root, output);
+        }
 
         // We only care about non-tracked files if we haven't specified both ends up the
         // comparison
@@ -301,10 +407,10 @@ impl Git {
             // Add untracked files, i.e. files that are not in git at all
             let output = self
                 .execute_git_command(&["ls-files", "--others", "--exclude-standard"], pathspec)?;
-            self.add_files_from_stdout(&mut files, turbo_root, output);
+            self.add_files_from_stdout(&mut changed_files.files, turbo_root, output);
         }
 
-        Ok(files)
+        Ok(changed_files)
     }
 
     fn execute_git_command(&self, args: &[&str], pathspec: &str) -> Result<Vec<u8>, Error> {
@@ -344,6 +450,39 @@ impl Git {
         }
     }
 
+    fn add_files_from_name_status(
+        &self,
+        changed_files: &mut ChangedFiles,
+        turbo_root: &AbsoluteSystemPath,
+        stdout: Vec<u8>,
+    ) {
+        let stdout = String::from_utf8(stdout).unwrap();
+        for line in stdout.lines() {
+            // Lines are `<status>\t<path>` or `R<score>\t<old path>\t<new path>`
+            let mut fields = line.split('\t');
+            let (Some(status), Some(path)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            let path = self
+                .reanchor_path_from_git_root_to_turbo_root(
+                    turbo_root,
+                    RelativeUnixPath::new(path).unwrap(),
+                )
+                .unwrap();
+            if let Some(new_path) = fields.next().filter(|_| status.starts_with('R')) {
+                let new_path = self
+                    .reanchor_path_from_git_root_to_turbo_root(
+                        turbo_root,
+                        RelativeUnixPath::new(new_path).unwrap(),
+                    )
+                    .unwrap();
+                changed_files.files.insert(new_path.clone());
+                changed_files.renames.push((path.clone(), new_path));
+            }
+            changed_files.files.insert(path);
+        }
+    }
+
     fn reanchor_path_from_git_root_to_turbo_root(
         &self,
         turbo_root: &AbsoluteSystemPath,
@@ -398,7 +537,7 @@ pub fn previous_content(
 #[cfg(test)]
 mod tests {
     use std::{
-        assert_matches::assert_matches,
+        assert_matches,
         collections::HashSet,
         env::VarError,
         fs,
@@ -409,7 +548,7 @@ mod tests {
     use git2::{Oid, Repository, RepositoryInitOptions};
     use tempfile::{NamedTempFile, TempDir};
     use test_case::test_case;
-    use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, PathError};
+    use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPathBuf, PathError};
     use which::which;
 
     use super::{previous_content, CIEnv, InvalidRange};
@@ -951,6 +1090,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_base_resolution_uses_remote_default_branch() -> Result<(), Error> {
+        let mut repo_opts = RepositoryInitOptions::new();
+        let repo_init = repo_opts.initial_head("main");
+        let (repo_root, repo) = setup_repository(Some(repo_init))?;
+        let root = AbsoluteSystemPathBuf::try_from(repo_root.path()).unwrap();
+
+        let file = root.join_component("todo.txt");
+        file.create_with_contents("1. make async Rust good")?;
+        let first_commit = commit_file(&repo, Path::new("todo.txt"), None);
+        repo.reference("refs/remotes/origin/trunk", first_commit, true, "fetch")
+            .unwrap();
+        repo.reference_symbolic(
+            "refs/remotes/origin/HEAD",
+            "refs/remotes/origin/trunk",
+            true,
+            "set-head",
+        )
+        .unwrap();
+
+        root.join_component("foo.js").create_with_contents("foo")?;
+        commit_file(&repo, Path::new("foo.js"), Some(first_commit));
+
+        let git = Git::find(&root).unwrap();
+        assert_eq!(
+            git.resolve_base(None, CIEnv::none()).ok().as_deref(),
+            Some("origin/trunk")
+        );
+
+        // Leaving off the base is the same as passing the default branch
+        let inferred = changed_files(
+            repo_root.path().to_path_buf(),
+            repo_root.path().to_path_buf(),
+            None,
+            Some("HEAD"),
+            false,
+        )?;
+        let explicit = changed_files(
+            repo_root.path().to_path_buf(),
+            repo_root.path().to_path_buf(),
+            Some("origin/trunk"),
+            Some("HEAD"),
+            false,
+        )?;
+        assert_eq!(inferred, HashSet::from(["foo.js".to_string()]));
+        assert_eq!(inferred, explicit);
+
+        Ok(())
+    }
+
     #[test]
     fn test_error_cases() -> Result<(), Error> {
         let repo_dir = tempfile::tempdir()?;
@@ -1001,6 +1190,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_changed_files_with_renames() -> Result<(), Error> {
+        let (repo_root, repo) = setup_repository(None)?;
+        let root = AbsoluteSystemPathBuf::try_from(repo_root.path()).unwrap();
+
+        let old_file = root.join_component("old.txt");
+        old_file.create_with_contents("some content that stays the same")?;
+        let first_commit = commit_file(&repo, Path::new("old.txt"), None);
+
+        old_file.rename(&root.join_component("new.txt"))?;
+        let mut index = repo.index().unwrap();
+        index.remove_path(Path::new("old.txt")).unwrap();
+        index.add_path(Path::new("new.txt")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let first_commit_ref = repo.find_commit(first_commit).unwrap();
+        repo.commit(
+            Some("HEAD"),
+            &repo.signature().unwrap(),
+            &repo.signature().unwrap(),
+            "Rename",
+            &tree,
+            &[&first_commit_ref],
+        )
+        .unwrap();
+
+        let scm = SCM::new(&root);
+        let from = first_commit.to_string();
+        let old = AnchoredSystemPathBuf::from_raw("old.txt")?;
+        let new = AnchoredSystemPathBuf::from_raw("new.txt")?;
+
+        let changed_files = scm
+            .changed_files_with_renames(
+                &root,
+                Some(&from),
+                Some("HEAD"),
+                false,
+                false,
+                false,
+                true,
+            )?
+            .unwrap();
+        assert_eq!(
+            changed_files.files,
+            HashSet::from([old.clone(), new.clone()])
+        );
+        assert_eq!(changed_files.renames, vec![(old, new)]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_changed_files_no_base() -> Result<(), Error> {
         let mut repo_opts = RepositoryInitOptions::new();
@@ -4,25 +4,33 @@
 #![deny(clippy::all)]
 
 //! Turborepo's library for interacting with source control management (SCM).
-//! Currently we only support git. We use SCM for finding changed files,
-//! for getting the previous version of a lockfile, and for hashing files.
+//! Git is fully supported, Sapling is supported for finding changed files.
+//! We use SCM for finding changed files, for getting the previous version of
+//! a lockfile, and for hashing files.
 
 use std::{
     backtrace::{self, Backtrace},
+    collections::{HashMap, HashSet},
+    fmt,
     io::Read,
     process::{Child, Command},
+    sync::{Arc, Mutex, OnceLock},
 };
 
 use bstr::io::BufReadExt;
 use thiserror::Error;
 use tracing::debug;
-use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, PathError, RelativeUnixPathBuf};
+use turbopath::{
+    AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPathBuf, PathError,
+    RelativeUnixPathBuf,
+};
 
 pub mod git;
 mod hash_object;
 mod ls_tree;
 pub mod manual;
 pub mod package_deps;
+pub mod sapling;
 mod status;
 
 #[derive(Debug, Error)]
@@ -35,6 +43,8 @@ pub enum Error {
     ),
     #[error("git error: {0}")]
     Git(String, #[backtrace] backtrace::Backtrace),
+    #[error("{0} error: {1}")]
+    Vcs(&'static str, String, #[backtrace] backtrace::Backtrace),
     #[error(
         "{0} is not part of a git repository. git is required for operations based on source \
          control"
@@ -78,6 +88,10 @@ impl Error {
         Error::Git(s.into(), Backtrace::capture())
     }
 
+    pub(crate) fn vcs_error(bin: &'static str, s: impl Into<String>) -> Self {
+        Error::Vcs(bin, s.into(), Backtrace::capture())
+    }
+
     pub(crate) fn git2_error_context(error: git2::Error, error_context: String) -> Self {
         Error::Git2(error, error_context, Backtrace::capture())
     }
@@ -209,30 +223,128 @@ fn find_git_root(turbo_root: &AbsoluteSystemPath) -> Result<AbsoluteSystemPathBu
     }
 }
 
+/// A source control system other than git. These only need to support change
+/// detection, file hashing always falls back to manual hashing.
+pub trait Backend: fmt::Debug + Send + Sync {
+    fn get_current_branch(&self) -> Result<String, Error>;
+
+    fn get_current_sha(&self) -> Result<String, Error>;
+
+    fn changed_files(
+        &self,
+        turbo_root: &AbsoluteSystemPath,
+        from_commit: Option<&str>,
+        to_commit: Option<&str>,
+        include_uncommitted: bool,
+        merge_base: bool,
+    ) -> Result<HashSet<AnchoredSystemPathBuf>, Error>;
+
+    fn previous_content(
+        &self,
+        from_commit: Option<&str>,
+        file_path: &AbsoluteSystemPath,
+    ) -> Result<Vec<u8>, Error>;
+}
+
 #[derive(Debug, Clone)]
 pub enum SCM {
     Git(Git),
+    Other(Arc<dyn Backend>),
     Manual,
 }
 
+type DetectedSCMs = Mutex<HashMap<AbsoluteSystemPathBuf, SCM>>;
+
+// Detecting the SCM shells out to git, so it is only done once per path for
+// the lifetime of the process
+static DETECTED_SCMS: OnceLock<DetectedSCMs> = OnceLock::new();
+
 impl SCM {
     #[tracing::instrument]
     pub fn new(path_in_repo: &AbsoluteSystemPath) -> SCM {
-        Git::find(path_in_repo).map(SCM::Git).unwrap_or_else(|e| {
-            debug!("{}, continuing with manual hashing", e);
-            SCM::Manual
-        })
+        Self::new_cached(
+            DETECTED_SCMS.get_or_init(Default::default),
+            path_in_repo,
+            Self::detect,
+        )
     }
 
+    fn new_cached(
+        detected: &DetectedSCMs,
+        path_in_repo: &AbsoluteSystemPath,
+        detect: impl FnOnce(&AbsoluteSystemPath) -> SCM,
+    ) -> SCM {
+        // Keyed by the realpath so that different spellings of the same
+        // directory share a result
+        let key = path_in_repo
+            .to_realpath()
+            .unwrap_or_else(|_| path_in_repo.to_owned());
+        if let Some(scm) = detected.lock().expect("lock poisoned").get(&key) {
+            return scm.clone();
+        }
+        // Detection happens without holding the lock, at worst two threads
+        // both detect the same path and store identical results
+        let scm = detect(path_in_repo);
+        // A repository can be created after we failed to find one, e.g. while the
+        // daemon is running, so only successful detections are cached
+        if matches!(scm, SCM::Git(_)) {
+            detected
+                .lock()
+                .expect("lock poisoned")
+                .insert(key, scm.clone());
+        }
+        scm
+    }
+
+    fn detect(path_in_repo: &AbsoluteSystemPath) -> SCM {
+        let git_error = match Git::find(path_in_repo) {
+            Ok(git) => return SCM::Git(git),
+            Err(e) => e,
+        };
+        find_other_backend(path_in_repo)
+            .map(SCM::Other)
+            .unwrap_or_else(|| {
+                debug!("{}, continuing with manual hashing", git_error);
+                SCM::Manual
+            })
+    }
+
+    /// Returns true if files are hashed manually rather than by the SCM
     pub fn is_manual(&self) -> bool {
-        matches!(self, SCM::Manual)
+        matches!(self, SCM::Manual | SCM::Other(_))
+    }
+}
+
+/// Looks for the metadata directory of a non-git repository containing
+/// `path_in_repo`
+fn find_other_backend(path_in_repo: &AbsoluteSystemPath) -> Option<Arc<dyn Backend>> {
+    for dir in path_in_repo.ancestors() {
+        if dir.join_component(".sl").as_std_path().is_dir() {
+            return match sapling::Sapling::new(dir.to_owned()) {
+                Ok(sapling) => Some(Arc::new(sapling)),
+                Err(e) => {
+                    debug!("found Sapling repository at {dir}, but {e}");
+                    None
+                }
+            };
+        }
+        if dir.join_component(".jj").as_std_path().is_dir() {
+            // Jujutsu repositories that are colocated with git are handled
+            // by the git backend
+            debug!(
+                "found Jujutsu repository at {dir}, which is only supported when colocated with \
+                 git"
+            );
+            return None;
+        }
     }
+    None
 }
 
 #[cfg(test)]
 mod tests {
     use std::{
-        assert_matches::assert_matches,
+        assert_matches,
         io::Read,
         process::{Command, Stdio},
     };
@@ -240,7 +352,7 @@ mod tests {
     use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf};
 
     use super::find_git_root;
-    use crate::{wait_for_success, Error};
+    use crate::{wait_for_success, Error, SCM};
 
     fn tmp_dir() -> (tempfile::TempDir, AbsoluteSystemPathBuf) {
         let tmp_dir = tempfile::tempdir().unwrap();
@@ -282,6 +394,59 @@ mod tests {
         assert_eq!(result, link);
     }
 
+    #[test]
+    fn test_scm_detection_is_cached() {
+        let (_tmp_dir, tmp_root) = tmp_dir();
+        setup_repository(&tmp_root);
+        let detected = Default::default();
+        let mut detections = 0;
+        let mut detect = |path: &AbsoluteSystemPath| {
+            detections += 1;
+            SCM::detect(path)
+        };
+
+        let first = SCM::new_cached(&detected, &tmp_root, &mut detect);
+        let second = SCM::new_cached(&detected, &tmp_root, &mut detect);
+
+        assert_eq!(detections, 1);
+        assert_matches!(first, SCM::Git(_));
+        assert_matches!(second, SCM::Git(_));
+    }
+
+    #[test]
+    fn test_scm_detection_cache_key_is_canonical() {
+        let (_tmp_dir, tmp_root) = tmp_dir();
+        let repo_root = tmp_root.join_component("repo");
+        repo_root.create_dir_all().unwrap();
+        setup_repository(&repo_root);
+        let link = tmp_root.join_component("link");
+        link.symlink_to_dir("repo").unwrap();
+        let detected = Default::default();
+        let mut detections = 0;
+        let mut detect = |path: &AbsoluteSystemPath| {
+            detections += 1;
+            SCM::detect(path)
+        };
+
+        SCM::new_cached(&detected, &repo_root, &mut detect);
+        SCM::new_cached(&detected, &link, &mut detect);
+
+        assert_eq!(detections, 1);
+    }
+
+    #[test]
+    fn test_scm_detection_failure_is_not_cached() {
+        let (_tmp_dir, tmp_root) = tmp_dir();
+        let detected = Default::default();
+        let first = SCM::new_cached(&detected, &tmp_root, |_| SCM::Manual);
+        assert_matches!(first, SCM::Manual);
+
+        // The repository is found once it exists
+        setup_repository(&tmp_root);
+        let second = SCM::new_cached(&detected, &tmp_root, SCM::detect);
+        assert_matches!(second, SCM::Git(_));
+    }
+
     #[test]
     fn test_no_git_root() {
         let (_, tmp_root) = tmp_dir();
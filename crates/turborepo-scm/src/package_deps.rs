@@ -43,7 +43,7 @@ impl SCM {
             .any(|input| input.as_ref() == INPUT_INCLUDE_DEFAULT_FILES);
 
         match self {
-            SCM::Manual => {
+            SCM::Manual | SCM::Other(_) => {
                 if let Some(telemetry) = telemetry {
                     telemetry.track_file_hash_method(FileHashMethod::Manual);
                 }
@@ -94,7 +94,7 @@ impl SCM {
         files: impl Iterator<Item = impl AsRef<AnchoredSystemPath>>,
     ) -> Result<GitHashes, Error> {
         match self {
-            SCM::Manual => crate::manual::hash_files(turbo_root, files, false),
+            SCM::Manual | SCM::Other(_) => crate::manual::hash_files(turbo_root, files, false),
             SCM::Git(git) => git.hash_files(turbo_root, files),
         }
     }
@@ -291,7 +291,7 @@ impl Git {
 
 #[cfg(test)]
 mod tests {
-    use std::{assert_matches::assert_matches, process::Command};
+    use std::{assert_matches, process::Command};
 
     use turbopath::{AbsoluteSystemPathBuf, AnchoredSystemPathBuf};
 
@@ -0,0 +1,227 @@
+//! Support for Sapling (https://sapling-scm.com) repositories. Only change
+//! detection is supported, file hashing falls back to manual hashing.
+
+use std::{collections::HashSet, path::PathBuf, process::Command};
+
+use turbopath::{
+    AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPathBuf, RelativeUnixPath,
+};
+
+use crate::{Backend, Error};
+
+const SAPLING_BIN: &str = "sl";
+
+#[derive(Debug, Clone)]
+pub struct Sapling {
+    root: AbsoluteSystemPathBuf,
+    bin: PathBuf,
+}
+
+impl Sapling {
+    pub fn new(root: AbsoluteSystemPathBuf) -> Result<Self, Error> {
+        let bin = Self::find_bin()?;
+        Ok(Self { root, bin })
+    }
+
+    pub fn find_bin() -> Result<PathBuf, Error> {
+        let bin =
+            which::which(SAPLING_BIN).map_err(|e| Error::vcs_error(SAPLING_BIN, e.to_string()))?;
+        // `sl` is also the name of a well known joke program, so make sure
+        // we've actually found Sapling before we start running commands
+        let output = Command::new(&bin).arg("--version").output()?;
+        if !String::from_utf8_lossy(&output.stdout).contains("Sapling") {
+            return Err(Error::vcs_error(
+                SAPLING_BIN,
+                format!("{} is not Sapling", bin.display()),
+            ));
+        }
+        Ok(bin)
+    }
+
+    fn execute_sl_command(&self, args: &[&str], pathspec: &str) -> Result<Vec<u8>, Error> {
+        let mut command = Command::new(&self.bin);
+        command
+            .args(args)
+            .current_dir(&self.root)
+            // Ignore user configuration that changes output formatting
+            .env("HGPLAIN", "1");
+
+        if !pathspec.is_empty() {
+            command.arg(format!("path:{pathspec}"));
+        }
+
+        let output = command.output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            Err(Error::vcs_error(SAPLING_BIN, stderr))
+        } else {
+            Ok(output.stdout)
+        }
+    }
+
+    fn resolve_base(&self, base_override: Option<&str>) -> Result<String, Error> {
+        if let Some(valid_from) = base_override {
+            return Ok(valid_from.to_string());
+        }
+
+        for candidate in ["main", "master"] {
+            if self
+                .execute_sl_command(&["log", "-r", candidate, "-T", "{node}"], "")
+                .is_ok()
+            {
+                return Ok(candidate.to_string());
+            }
+        }
+        Err(Error::UnableToResolveRef)
+    }
+
+    fn add_files_from_stdout(
+        &self,
+        files: &mut HashSet<AnchoredSystemPathBuf>,
+        turbo_root: &AbsoluteSystemPath,
+        stdout: Vec<u8>,
+    ) -> Result<(), Error> {
+        let stdout = String::from_utf8(stdout)?;
+        for line in stdout.lines() {
+            let path = RelativeUnixPath::new(line)?;
+            let absolute_file_path = self.root.join_unix_path(path);
+            files.insert(turbo_root.anchor(&absolute_file_path)?);
+        }
+        Ok(())
+    }
+}
+
+impl Backend for Sapling {
+    fn get_current_branch(&self) -> Result<String, Error> {
+        let output = self.execute_sl_command(&["log", "-r", ".", "-T", "{activebookmark}"], "")?;
+        let output = String::from_utf8(output)?;
+        Ok(output.trim().to_owned())
+    }
+
+    fn get_current_sha(&self) -> Result<String, Error> {
+        let output = self.execute_sl_command(&["log", "-r", ".", "-T", "{node}"], "")?;
+        let output = String::from_utf8(output)?;
+        Ok(output.trim().to_owned())
+    }
+
+    fn changed_files(
+        &self,
+        turbo_root: &AbsoluteSystemPath,
+        from_commit: Option<&str>,
+        to_commit: Option<&str>,
+        include_uncommitted: bool,
+        merge_base: bool,
+    ) -> Result<HashSet<AnchoredSystemPathBuf>, Error> {
+        let turbo_root_relative_to_root = self.root.anchor(turbo_root)?;
+        let pathspec = turbo_root_relative_to_root.as_str();
+
+        let mut files = HashSet::new();
+
+        let mut valid_from = self.resolve_base(from_commit)?;
+        if merge_base {
+            valid_from = format!("ancestor({valid_from}, {})", to_commit.unwrap_or("."));
+        }
+
+        // Without an end revision we compare against the working copy, which
+        // matches `git diff <from>`
+        let mut args = vec!["status", "--no-status", "-mard", "--rev", &valid_from];
+        if let Some(to_commit) = to_commit {
+            args.extend(["--rev", to_commit]);
+        }
+
+        let output = self.execute_sl_command(&args, pathspec)?;
+        self.add_files_from_stdout(&mut files, turbo_root, output)?;
+
+        if include_uncommitted {
+            // Add untracked files
+            let output = self.execute_sl_command(&["status", "--no-status", "-u"], pathspec)?;
+            self.add_files_from_stdout(&mut files, turbo_root, output)?;
+        }
+
+        Ok(files)
+    }
+
+    fn previous_content(
+        &self,
+        from_commit: Option<&str>,
+        file_path: &AbsoluteSystemPath,
+    ) -> Result<Vec<u8>, Error> {
+        let anchored_file_path = self.root.anchor(file_path)?;
+        let valid_from = self.resolve_base(from_commit)?;
+
+        self.execute_sl_command(&["cat", "-r", &valid_from], anchored_file_path.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, process::Command};
+
+    use turbopath::{AbsoluteSystemPath, AbsoluteSystemPathBuf, AnchoredSystemPathBuf};
+
+    use super::Sapling;
+    use crate::Backend;
+
+    fn require_sl_cmd(repo_root: &AbsoluteSystemPath, args: &[&str]) {
+        let mut cmd = Command::new("sl");
+        cmd.args(args)
+            .args(["--config", "ui.username=test <test@example.com>"])
+            .current_dir(repo_root);
+        assert!(cmd.output().unwrap().status.success());
+    }
+
+    #[test]
+    fn test_changed_files() {
+        if Sapling::find_bin().is_err() {
+            println!("Sapling is not installed, skipping");
+            return;
+        }
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root = AbsoluteSystemPathBuf::try_from(tmp_dir.path())
+            .unwrap()
+            .to_realpath()
+            .unwrap();
+        require_sl_cmd(&root, &["init", "."]);
+
+        root.join_component("committed.txt")
+            .create_with_contents("hello")
+            .unwrap();
+        require_sl_cmd(&root, &["commit", "-A", "-m", "initial"]);
+        let sapling = Sapling::new(root.clone()).unwrap();
+        let initial = sapling.get_current_sha().unwrap();
+
+        root.join_component("committed.txt")
+            .create_with_contents("hello world")
+            .unwrap();
+        root.join_component("untracked.txt")
+            .create_with_contents("new")
+            .unwrap();
+
+        let files = sapling
+            .changed_files(&root, Some(&initial), None, false, false)
+            .unwrap();
+        assert_eq!(
+            files,
+            HashSet::from([AnchoredSystemPathBuf::from_raw("committed.txt").unwrap()])
+        );
+
+        let files = sapling
+            .changed_files(&root, Some(&initial), None, true, false)
+            .unwrap();
+        assert_eq!(
+            files,
+            HashSet::from([
+                AnchoredSystemPathBuf::from_raw("committed.txt").unwrap(),
+                AnchoredSystemPathBuf::from_raw("untracked.txt").unwrap(),
+            ])
+        );
+
+        assert_eq!(
+            sapling
+                .previous_content(Some(&initial), &root.join_component("committed.txt"))
+                .unwrap(),
+            b"hello"
+        );
+    }
+}
@@ -1,7 +1,4 @@
-use std::{
-    collections::HashMap,
-    sync::{Arc, OnceLock, RwLock},
-};
+use std::sync::OnceLock;
 
 use console::{Style, StyledObject};
 
@@ -19,33 +16,17 @@ pub fn get_terminal_package_colors() -> &'static [Style; 5] {
     })
 }
 
-/// Selects colors for tasks and caches accordingly.
-/// Shared between tasks so allows for concurrent access.
-#[derive(Default)]
-pub struct ColorSelector {
-    inner: Arc<RwLock<ColorSelectorInner>>,
-}
-
-#[derive(Default)]
-struct ColorSelectorInner {
-    idx: usize,
-    cache: HashMap<String, &'static Style>,
-}
+/// Selects colors for tasks by hashing their key. A key is always given the
+/// same color, regardless of the order keys are seen in, so a package keeps its
+/// color across runs.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ColorSelector;
 
 impl ColorSelector {
     pub fn color_for_key(&self, key: &str) -> &'static Style {
-        if let Some(style) = self.inner.read().expect("lock poisoned").color(key) {
-            return style;
-        }
-
-        let color = {
-            self.inner
-                .write()
-                .expect("lock poisoned")
-                .insert_color(key.to_string())
-        };
-
-        color
+        let colors = get_terminal_package_colors();
+        let idx = stable_hash(key) % colors.len() as u64;
+        &colors[idx as usize]
     }
 
     pub fn prefix_with_color(&self, cache_key: &str, prefix: &str) -> StyledObject<String> {
@@ -58,21 +39,13 @@ impl ColorSelector {
     }
 }
 
-impl ColorSelectorInner {
-    fn color(&self, key: &str) -> Option<&'static Style> {
-        self.cache.get(key).copied()
-    }
-
-    fn insert_color(&mut self, key: String) -> &'static Style {
-        let colors = get_terminal_package_colors();
-        let chosen_color = &colors[self.idx % colors.len()];
-        // A color might have been chosen by the time we get to inserting
-        self.cache.entry(key).or_insert_with(|| {
-            // If a color hasn't been chosen, then we increment the index
-            self.idx += 1;
-            chosen_color
-        })
-    }
+// FNV-1a, as the output of `DefaultHasher` can change between Rust releases
+fn stable_hash(key: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    key.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
 }
 
 #[cfg(test)]
@@ -109,16 +82,27 @@ mod tests {
                 assert_ne!(color1, color2);
             });
         });
-        // We only inserted 2 keys so next index should be 2
-        assert_eq!(selector.inner.read().unwrap().idx, 2);
     }
 
     #[test]
-    fn test_color_selector_wraps_around() {
-        let selector = super::ColorSelector::default();
-        for key in &["1", "2", "3", "4", "5", "6"] {
-            selector.color_for_key(key);
-        }
-        assert_eq!(selector.color_for_key("1"), selector.color_for_key("6"));
+    fn test_color_selector_is_deterministic() {
+        let packages = ["web", "docs", "ui", "@repo/ui", "api"];
+
+        let first = super::ColorSelector::default();
+        let first_colors: Vec<_> = packages
+            .iter()
+            .map(|package| first.color_for_key(package))
+            .collect();
+
+        // Colors don't depend on the order packages are seen in
+        let second = super::ColorSelector::default();
+        let mut second_colors: Vec<_> = packages
+            .iter()
+            .rev()
+            .map(|package| second.color_for_key(package))
+            .collect();
+        second_colors.reverse();
+
+        assert_eq!(first_colors, second_colors);
     }
 }
@@ -0,0 +1,287 @@
+//! Compact progress view for runs with many tasks. Each running task gets a
+//! single line with its elapsed time that is redrawn in place. Once a task
+//! finishes a line with the result is printed above the running tasks, along
+//! with the task's output if it failed.
+
+use std::{
+    io::Write,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use tracing::warn;
+
+use crate::{
+    sender::{TaskSender, UISender},
+    tui::event::{CacheResult, OutputLogs, TaskResult},
+};
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug)]
+pub enum Event {
+    StartTask { task: String },
+    TaskOutput { task: String, output: Vec<u8> },
+    EndTask { task: String, result: TaskResult },
+    Stop,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompactSender {
+    tx: mpsc::Sender<Event>,
+}
+
+impl CompactSender {
+    pub fn new() -> (Self, mpsc::Receiver<Event>) {
+        let (tx, rx) = mpsc::channel();
+        (Self { tx }, rx)
+    }
+
+    pub fn start_task(&self, task: String, _output_logs: OutputLogs) {
+        self.tx.send(Event::StartTask { task }).ok();
+    }
+
+    pub fn restart_tasks(&self, _tasks: Vec<String>) -> Result<(), crate::Error> {
+        Ok(())
+    }
+
+    pub fn end_task(&self, task: String, result: TaskResult) {
+        self.tx.send(Event::EndTask { task, result }).ok();
+    }
+
+    pub fn status(&self, _task: String, _status: String, _result: CacheResult) {}
+
+    pub fn set_stdin(&self, _: String, _: Box<dyn Write + Send>) {
+        warn!("stdin is not supported in the compact ui");
+    }
+
+    pub fn task(&self, task: String) -> TaskSender {
+        TaskSender {
+            name: task,
+            handle: UISender::Compact(self.clone()),
+            logs: Default::default(),
+        }
+    }
+
+    pub fn stop(&self) {
+        self.tx.send(Event::Stop).ok();
+    }
+
+    pub fn update_tasks(&self, _tasks: Vec<String>) -> Result<(), crate::Error> {
+        Ok(())
+    }
+
+    pub fn output(&self, task: String, output: Vec<u8>) -> Result<(), crate::Error> {
+        // Output is only shown if the task fails, so it's fine to drop it once the
+        // view has stopped.
+        self.tx.send(Event::TaskOutput { task, output }).ok();
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct RunningTask {
+    name: String,
+    started_at: Instant,
+    output: Vec<u8>,
+}
+
+/// State of the compact view, kept separate from the terminal so it can be
+/// driven without one.
+#[derive(Debug, Default)]
+pub struct CompactView {
+    // Kept in the order the tasks started
+    running: Vec<RunningTask>,
+}
+
+impl CompactView {
+    /// Applies an event to the view, returning any text that should be
+    /// printed permanently above the running tasks.
+    pub fn handle(&mut self, event: Event, now: Instant) -> Option<String> {
+        match event {
+            Event::StartTask { task } => {
+                if !self.running.iter().any(|running| running.name == task) {
+                    self.running.push(RunningTask {
+                        name: task,
+                        started_at: now,
+                        output: Vec::new(),
+                    });
+                }
+                None
+            }
+            Event::TaskOutput { task, output } => {
+                if let Some(running) = self.running.iter_mut().find(|running| running.name == task)
+                {
+                    running.output.extend(output);
+                }
+                None
+            }
+            Event::EndTask { task, result } => {
+                let index = self
+                    .running
+                    .iter()
+                    .position(|running| running.name == task)?;
+                let finished = self.running.remove(index);
+                let elapsed = format_elapsed(now.duration_since(finished.started_at));
+                let line = match result {
+                    TaskResult::Success => format!("{task} succeeded in {elapsed}"),
+                    TaskResult::CacheHit => format!("{task} cached in {elapsed}"),
+                    TaskResult::Failure => {
+                        let output = String::from_utf8_lossy(&finished.output);
+                        format!("{task} failed in {elapsed}\n{}", output.trim_end())
+                    }
+                };
+                Some(line)
+            }
+            Event::Stop => None,
+        }
+    }
+
+    /// Lines describing each running task
+    pub fn render(&self, now: Instant) -> Vec<String> {
+        self.running
+            .iter()
+            .map(|running| {
+                format!(
+                    "{} running for {}",
+                    running.name,
+                    format_elapsed(now.duration_since(running.started_at))
+                )
+            })
+            .collect()
+    }
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{:.1}s", elapsed.as_secs_f64())
+    } else {
+        format!("{}m{}s", secs / 60, secs % 60)
+    }
+}
+
+/// Renders the compact view to stdout until the sender is stopped or dropped.
+pub fn run_compact(receiver: mpsc::Receiver<Event>) -> Result<(), crate::Error> {
+    let mut stdout = std::io::stdout().lock();
+    let mut view = CompactView::default();
+    // Number of lines currently showing running tasks
+    let mut live_lines = 0;
+
+    loop {
+        let event = match receiver.recv_timeout(REDRAW_INTERVAL) {
+            Ok(event) => Some(event),
+            Err(mpsc::RecvTimeoutError::Timeout) => None,
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        };
+        let now = Instant::now();
+        let should_stop = matches!(event, Some(Event::Stop));
+
+        // Output doesn't change what is displayed so there's no need to redraw
+        if let Some(event @ Event::TaskOutput { .. }) = event {
+            view.handle(event, now);
+            continue;
+        }
+
+        let finished = event.and_then(|event| view.handle(event, now));
+
+        if live_lines > 0 {
+            // Move to the start of the running tasks and clear to the end of the screen
+            write!(stdout, "\x1b[{live_lines}A\x1b[0J")?;
+        }
+        if let Some(finished) = finished {
+            writeln!(stdout, "{finished}")?;
+        }
+        if should_stop {
+            stdout.flush()?;
+            break;
+        }
+
+        let lines = view.render(now);
+        for line in &lines {
+            writeln!(stdout, "{line}")?;
+        }
+        live_lines = lines.len();
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::{CompactView, Event};
+    use crate::tui::event::TaskResult;
+
+    #[test]
+    fn test_task_lifecycle() {
+        let start = Instant::now();
+        let mut view = CompactView::default();
+
+        for task in ["web#build", "docs#build", "ui#build"] {
+            assert_eq!(
+                view.handle(
+                    Event::StartTask {
+                        task: task.to_string()
+                    },
+                    start
+                ),
+                None
+            );
+        }
+        assert_eq!(
+            view.render(start + Duration::from_millis(1500)),
+            vec![
+                "web#build running for 1.5s",
+                "docs#build running for 1.5s",
+                "ui#build running for 1.5s",
+            ]
+        );
+
+        assert_eq!(
+            view.handle(
+                Event::EndTask {
+                    task: "docs#build".to_string(),
+                    result: TaskResult::CacheHit,
+                },
+                start + Duration::from_secs(2),
+            ),
+            Some("docs#build cached in 2.0s".to_string())
+        );
+        view.handle(
+            Event::TaskOutput {
+                task: "ui#build".to_string(),
+                output: b"error: missing semicolon\n".to_vec(),
+            },
+            start + Duration::from_secs(3),
+        );
+        assert_eq!(
+            view.handle(
+                Event::EndTask {
+                    task: "ui#build".to_string(),
+                    result: TaskResult::Failure,
+                },
+                start + Duration::from_secs(3),
+            ),
+            Some("ui#build failed in 3.0s\nerror: missing semicolon".to_string())
+        );
+        assert_eq!(
+            view.render(start + Duration::from_secs(95)),
+            vec!["web#build running for 1m35s"]
+        );
+
+        assert_eq!(
+            view.handle(
+                Event::EndTask {
+                    task: "web#build".to_string(),
+                    result: TaskResult::Success,
+                },
+                start + Duration::from_secs(100),
+            ),
+            Some("web#build succeeded in 1m40s".to_string())
+        );
+        assert!(view.render(start + Duration::from_secs(100)).is_empty());
+    }
+}
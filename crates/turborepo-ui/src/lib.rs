@@ -5,6 +5,7 @@
 #![feature(deadline_api)]
 
 mod color_selector;
+pub mod compact;
 mod line;
 mod logs;
 mod output;
@@ -39,6 +40,8 @@ pub enum Error {
     CannotReadLogs(#[source] std::io::Error),
     #[error("cannot write logs: {0}")]
     CannotWriteLogs(#[source] std::io::Error),
+    #[error("failed to render compact ui: {0}")]
+    Compact(#[from] std::io::Error),
 }
 
 pub fn start_spinner(message: &str) -> ProgressBar {
@@ -13,6 +13,10 @@ use crate::Error;
 pub struct LogWriter<W> {
     log_file: Option<BufWriter<File>>,
     writer: Option<W>,
+    // Present if escape sequences should be removed before writing. Holds
+    // output that hasn't been written yet because its line isn't complete.
+    log_file_pending: Option<Vec<u8>>,
+    writer_pending: Option<Vec<u8>>,
 }
 
 /// Derive didn't work here.
@@ -22,6 +26,8 @@ impl<W> Default for LogWriter<W> {
         Self {
             log_file: None,
             writer: None,
+            log_file_pending: None,
+            writer_pending: None,
         }
     }
 }
@@ -46,30 +52,72 @@ impl<W: Write> LogWriter<W> {
     pub fn with_writer(&mut self, writer: W) {
         self.writer = Some(writer);
     }
+
+    /// Remove ANSI escape sequences from output before writing it to the log
+    /// file
+    pub fn strip_ansi_from_log_file(&mut self) {
+        self.log_file_pending = Some(Vec::new());
+    }
+
+    /// Remove ANSI escape sequences from output before passing it to the
+    /// writer
+    pub fn strip_ansi_from_writer(&mut self) {
+        self.writer_pending = Some(Vec::new());
+    }
+}
+
+// Escape sequences can be split across writes, so output is only stripped
+// once its line is complete
+fn write_all(
+    writer: &mut impl Write,
+    pending: Option<&mut Vec<u8>>,
+    buf: &[u8],
+) -> std::io::Result<()> {
+    let Some(pending) = pending else {
+        return writer.write_all(buf);
+    };
+    pending.extend_from_slice(buf);
+    if let Some(end) = pending.iter().rposition(|byte| *byte == b'\n') {
+        let lines = pending.drain(..=end).collect::<Vec<_>>();
+        write_stripped(writer, &lines)?;
+    }
+    Ok(())
+}
+
+fn write_stripped(writer: &mut impl Write, output: &[u8]) -> std::io::Result<()> {
+    let output = String::from_utf8_lossy(output);
+    writer.write_all(console::strip_ansi_codes(&output).as_bytes())
 }
 
 impl<W: Write> Write for LogWriter<W> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        match (&mut self.log_file, &mut self.writer) {
-            (Some(log_file), Some(prefixed_writer)) => {
-                let _ = prefixed_writer.write(buf)?;
-                log_file.write(buf)
-            }
-            (Some(log_file), None) => log_file.write(buf),
-            (None, Some(prefixed_writer)) => prefixed_writer.write(buf),
-            (None, None) => {
-                // Should this be an error or even a panic?
-                debug!("no log file or prefixed writer");
-                Ok(0)
-            }
+        if self.log_file.is_none() && self.writer.is_none() {
+            // Should this be an error or even a panic?
+            debug!("no log file or prefixed writer");
+            return Ok(0);
+        }
+
+        if let Some(prefixed_writer) = &mut self.writer {
+            write_all(prefixed_writer, self.writer_pending.as_mut(), buf)?;
+        }
+        if let Some(log_file) = &mut self.log_file {
+            write_all(log_file, self.log_file_pending.as_mut(), buf)?;
         }
+
+        Ok(buf.len())
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
         if let Some(log_file) = &mut self.log_file {
+            if let Some(pending) = &mut self.log_file_pending {
+                write_stripped(log_file, &std::mem::take(pending))?;
+            }
             log_file.flush()?;
         }
         if let Some(prefixed_writer) = &mut self.writer {
+            if let Some(pending) = &mut self.writer_pending {
+                write_stripped(prefixed_writer, &std::mem::take(pending))?;
+            }
             prefixed_writer.flush()?;
         }
 
@@ -164,6 +212,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_log_writer_strips_ansi_from_log_file() -> Result<()> {
+        let dir = tempdir()?;
+        let log_file_path = AbsoluteSystemPathBuf::try_from(dir.path().join("test.txt"))?;
+        let mut writer_output = Vec::new();
+        let mut log_writer = LogWriter::default();
+
+        log_writer.with_log_file(&log_file_path)?;
+        log_writer.with_writer(&mut writer_output);
+        log_writer.strip_ansi_from_log_file();
+
+        write!(
+            log_writer,
+            "{}",
+            CYAN.apply_to("one fish").force_styling(true)
+        )?;
+        writeln!(log_writer, " two fish")?;
+        write!(
+            log_writer,
+            "{}",
+            CYAN.apply_to("red fish").force_styling(true)
+        )?;
+
+        log_writer.flush()?;
+
+        assert_eq!(
+            String::from_utf8(writer_output)?,
+            "\u{1b}[36mone fish\u{1b}[0m two fish\n\u{1b}[36mred fish\u{1b}[0m"
+        );
+
+        let log_file_contents = log_file_path.read_to_string()?;
+
+        assert!(!log_file_contents.contains('\u{1b}'));
+        assert_eq!(log_file_contents, "one fish two fish\nred fish");
+
+        Ok(())
+    }
+
     #[test]
     fn test_replay_logs() -> Result<()> {
         let color_config = ColorConfig::new(false);
@@ -1,16 +1,19 @@
 use std::sync::{Arc, Mutex};
 
 use crate::{
+    compact::CompactSender,
     tui,
     tui::event::{CacheResult, OutputLogs, PaneSize, TaskResult},
     wui::sender,
 };
 
-/// Enum to abstract over sending events to either the Tui or the Web UI
+/// Enum to abstract over sending events to the Tui, the Web UI, or the
+/// compact view
 #[derive(Debug, Clone)]
 pub enum UISender {
     Tui(tui::TuiSender),
     Wui(sender::WebUISender),
+    Compact(CompactSender),
 }
 
 impl UISender {
@@ -18,6 +21,7 @@ impl UISender {
         match self {
             UISender::Tui(sender) => sender.start_task(task, output_logs),
             UISender::Wui(sender) => sender.start_task(task, output_logs),
+            UISender::Compact(sender) => sender.start_task(task, output_logs),
         }
     }
 
@@ -25,6 +29,7 @@ impl UISender {
         match self {
             UISender::Tui(sender) => sender.restart_tasks(tasks),
             UISender::Wui(sender) => sender.restart_tasks(tasks),
+            UISender::Compact(sender) => sender.restart_tasks(tasks),
         }
     }
 
@@ -32,6 +37,7 @@ impl UISender {
         match self {
             UISender::Tui(sender) => sender.end_task(task, result),
             UISender::Wui(sender) => sender.end_task(task, result),
+            UISender::Compact(sender) => sender.end_task(task, result),
         }
     }
 
@@ -39,12 +45,14 @@ impl UISender {
         match self {
             UISender::Tui(sender) => sender.status(task, status, result),
             UISender::Wui(sender) => sender.status(task, status, result),
+            UISender::Compact(sender) => sender.status(task, status, result),
         }
     }
     fn set_stdin(&self, task: String, stdin: Box<dyn std::io::Write + Send>) {
         match self {
             UISender::Tui(sender) => sender.set_stdin(task, stdin),
             UISender::Wui(sender) => sender.set_stdin(task, stdin),
+            UISender::Compact(sender) => sender.set_stdin(task, stdin),
         }
     }
 
@@ -52,6 +60,7 @@ impl UISender {
         match self {
             UISender::Tui(sender) => sender.output(task, output),
             UISender::Wui(sender) => sender.output(task, output),
+            UISender::Compact(sender) => sender.output(task, output),
         }
     }
 
@@ -60,26 +69,29 @@ impl UISender {
         match self {
             UISender::Tui(sender) => sender.task(task),
             UISender::Wui(sender) => sender.task(task),
+            UISender::Compact(sender) => sender.task(task),
         }
     }
     pub fn stop(&self) {
         match self {
             UISender::Tui(sender) => sender.stop(),
             UISender::Wui(sender) => sender.stop(),
+            UISender::Compact(sender) => sender.stop(),
         }
     }
     pub fn update_tasks(&self, tasks: Vec<String>) -> Result<(), crate::Error> {
         match self {
             UISender::Tui(sender) => sender.update_tasks(tasks),
             UISender::Wui(sender) => sender.update_tasks(tasks),
+            UISender::Compact(sender) => sender.update_tasks(tasks),
         }
     }
 
     pub fn pane_size(&self) -> Option<PaneSize> {
         match self {
             UISender::Tui(sender) => sender.pane_size(),
-            // Not applicable to the web UI
-            UISender::Wui(_) => None,
+            // Not applicable to the web UI or compact view
+            UISender::Wui(_) | UISender::Compact(_) => None,
         }
     }
 }
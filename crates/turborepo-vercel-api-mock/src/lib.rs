@@ -40,6 +40,8 @@ pub async fn start_test_server(port: u16) -> Result<()> {
     let get_durations_ref = Arc::new(Mutex::new(HashMap::new()));
     let head_durations_ref = get_durations_ref.clone();
     let put_durations_ref = get_durations_ref.clone();
+    let get_checksums_ref = Arc::new(Mutex::new(HashMap::new()));
+    let put_checksums_ref = get_checksums_ref.clone();
     let put_tempdir_ref = Arc::new(tempfile::tempdir()?);
     let get_tempdir_ref = put_tempdir_ref.clone();
 
@@ -170,6 +172,13 @@ pub async fn start_test_server(port: u16) -> Result<()> {
                     let mut durations_map = put_durations_ref.lock().await;
                     durations_map.insert(hash.clone(), duration);
 
+                    if let Some(checksum) = headers.get("x-artifact-checksum") {
+                        put_checksums_ref
+                            .lock()
+                            .await
+                            .insert(hash.clone(), checksum.clone());
+                    }
+
                     let mut body_stream = body.into_data_stream();
                     while let Some(item) = body_stream.next().await {
                         let chunk = item.unwrap();
@@ -200,6 +209,9 @@ pub async fn start_test_server(port: u16) -> Result<()> {
                     "x-artifact-duration",
                     HeaderValue::from_str(&duration.to_string()).unwrap(),
                 );
+                if let Some(checksum) = get_checksums_ref.lock().await.get(&hash) {
+                    headers.insert("x-artifact-checksum", checksum.clone());
+                }
 
                 (StatusCode::FOUND, headers, buffer)
             }),
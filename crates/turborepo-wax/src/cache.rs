@@ -0,0 +1,123 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{Mutex, PoisonError},
+};
+
+use lru::LruCache;
+
+use crate::{BuildError, Glob};
+
+/// Maximum number of compiled [`Glob`]s retained by [`cached_glob`].
+///
+/// [`cached_glob`]: crate::cached_glob
+/// [`Glob`]: crate::Glob
+const CACHE_CAPACITY: usize = 512;
+
+static CACHE: Mutex<Option<GlobCache>> = Mutex::new(None);
+
+/// Constructs a [`Glob`] from a glob expression, reusing a previously compiled
+/// [`Glob`] for the same expression if possible.
+///
+/// Compiled [`Glob`]s are kept in a process-wide least recently used cache
+/// keyed by the expression text. This avoids recompiling the same expression
+/// when it is used repeatedly, such as the same input patterns across many
+/// packages. The returned [`Glob`] is a clone of the cached [`Glob`].
+///
+/// Expressions that fail to build are not cached.
+///
+/// # Errors
+///
+/// Returns an error if the glob expression fails to build. See [`Glob::new`].
+///
+/// [`Glob`]: crate::Glob
+/// [`Glob::new`]: crate::Glob::new
+pub fn cached_glob(expression: &str) -> Result<Glob<'static>, BuildError> {
+    if let Some(glob) = with_cache(|cache| cache.get(expression)) {
+        return Ok(glob);
+    }
+    // Compile without holding the lock so that other threads are not blocked.
+    let glob = Glob::new(expression)?.into_owned();
+    with_cache(|cache| cache.insert(expression, glob.clone()));
+    Ok(glob)
+}
+
+fn with_cache<T>(f: impl FnOnce(&mut GlobCache) -> T) -> T {
+    let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+    f(cache.get_or_insert_with(|| GlobCache::new(CACHE_CAPACITY)))
+}
+
+struct GlobCache {
+    entries: LruCache<String, Glob<'static>>,
+    // Number of lookups that found a compiled `Glob`.
+    #[cfg(test)]
+    hits: usize,
+}
+
+impl GlobCache {
+    fn new(capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).expect("glob cache capacity must not be zero");
+        GlobCache {
+            entries: LruCache::new(capacity),
+            #[cfg(test)]
+            hits: 0,
+        }
+    }
+
+    fn get(&mut self, expression: &str) -> Option<Glob<'static>> {
+        let glob = self.entries.get(expression).cloned();
+        #[cfg(test)]
+        if glob.is_some() {
+            self.hits += 1;
+        }
+        glob
+    }
+
+    fn insert(&mut self, expression: &str, glob: Glob<'static>) {
+        self.entries.put(expression.to_owned(), glob);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cache::{with_cache, GlobCache},
+        cached_glob, Glob, Program,
+    };
+
+    #[test]
+    fn cached_glob_hits_cache() {
+        let expression = "cache/hit/**/*.rs";
+        assert!(cached_glob(expression)
+            .unwrap()
+            .is_match("cache/hit/src/lib.rs"));
+        // Other tests may use the cache concurrently, but hits only increase.
+        let hits = with_cache(|cache| cache.hits);
+        assert!(cached_glob(expression)
+            .unwrap()
+            .is_match("cache/hit/src/lib.rs"));
+        assert!(with_cache(|cache| cache.hits) > hits);
+    }
+
+    #[test]
+    fn cached_glob_does_not_cache_errors() {
+        assert!(cached_glob("a//b").is_err());
+        assert!(cached_glob("a//b").is_err());
+        assert!(!with_cache(|cache| cache.entries.contains("a//b")));
+    }
+
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        let mut cache = GlobCache::new(2);
+        for expression in ["a/**", "b/**"] {
+            cache.insert(expression, Glob::new(expression).unwrap().into_owned());
+        }
+        // Use `a/**` so that `b/**` is the least recently used.
+        assert!(cache.get("a/**").is_some());
+        cache.insert("c/**", Glob::new("c/**").unwrap().into_owned());
+
+        assert!(cache.get("a/**").is_some());
+        assert!(cache.get("b/**").is_none());
+        assert!(cache.get("c/**").is_some());
+        assert_eq!(cache.hits, 3);
+    }
+}
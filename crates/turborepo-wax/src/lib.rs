@@ -31,6 +31,7 @@
     clippy::unused_self
 )]
 
+mod cache;
 mod capture;
 mod diagnostics;
 mod encode;
@@ -79,6 +80,7 @@ use thiserror::Error;
 #[cfg(feature = "walk")]
 use crate::walk::WalkError;
 pub use crate::{
+    cache::cached_glob,
     capture::MatchedText,
     diagnostics::{LocatedError, Span},
 };
@@ -700,6 +702,21 @@ impl<'t> Glob<'t> {
         (prefix, Glob { tree, program })
     }
 
+    /// Gets the invariant prefix of the glob as a native path.
+    ///
+    /// This is the same prefix that [`Glob::partition`] splits from the glob,
+    /// but the glob is not consumed. The prefix can be used to narrow the
+    /// directories that must be read or watched to find matching paths. For
+    /// example, the glob expression `src/**/*.ts` has the prefix `src`.
+    ///
+    /// Returns `None` if the glob has no invariant prefix, such as `**/*.ts`.
+    ///
+    /// [`Glob::partition`]: crate::Glob::partition
+    pub fn invariant_prefix(&self) -> Option<PathBuf> {
+        let prefix = token::invariant_text_prefix(self.tree.as_ref().tokens());
+        (!prefix.is_empty()).then(|| prefix.into())
+    }
+
     /// Clones any borrowed data into an owning instance.
     ///
     /// # Examples
@@ -745,6 +762,33 @@ impl<'t> Glob<'t> {
             })
     }
 
+    /// Matches a path against the glob and gets its [matched
+    /// text][`MatchedText`].
+    ///
+    /// This is a convenience for [`Program::matched`] when matching a single
+    /// known path. No directory tree is walked and the file system is not
+    /// read. Returns `None` if the path does not match the glob.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use wax::Glob;
+    ///
+    /// let glob = Glob::new("packages/*/package.json").unwrap();
+    /// let matched = glob
+    ///     .is_match_with_captures(Path::new("packages/ui/package.json"))
+    ///     .unwrap();
+    /// assert_eq!("ui", matched.get(1).unwrap());
+    /// ```
+    ///
+    /// [`MatchedText`]: crate::MatchedText
+    /// [`Program::matched`]: crate::Program::matched
+    pub fn is_match_with_captures(&self, path: &Path) -> Option<MatchedText<'static>> {
+        let candidate = CandidatePath::from(path);
+        self.matched(&candidate).map(MatchedText::into_owned)
+    }
+
     /// Returns `true` if the glob has a root.
     ///
     /// As with Unix paths, a glob expression has a root if it begins with a
@@ -1056,7 +1100,7 @@ fn parse_and_check(expression: &str) -> Result<Checked<Tokenized>, BuildError> {
 // `/` as a separator.
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     use crate::{BuildError, BuildErrorKind, CandidatePath, Glob, Program};
 
@@ -1518,6 +1562,24 @@ mod tests {
         assert_eq!("ext", matched.get(3).unwrap());
     }
 
+    #[test]
+    fn match_glob_with_captures_from_path() {
+        let glob = Glob::new("src/{app,lib}/**/*.{ts,tsx}").unwrap();
+
+        let matched = glob
+            .is_match_with_captures(Path::new("src/app/pages/index.tsx"))
+            .unwrap();
+        assert_eq!("src/app/pages/index.tsx", matched.complete());
+        assert_eq!("app", matched.get(1).unwrap());
+        assert_eq!("pages/", matched.get(2).unwrap());
+        assert_eq!("index", matched.get(3).unwrap());
+        assert_eq!("tsx", matched.get(4).unwrap());
+
+        assert!(glob
+            .is_match_with_captures(Path::new("lib/app/index.ts"))
+            .is_none());
+    }
+
     #[test]
     fn match_glob_with_class_tokens() {
         let glob = Glob::new("a/[xyi-k]/**").unwrap();
@@ -1747,6 +1809,15 @@ mod tests {
         assert!(!any.is_match("pkg/lib.rs"));
     }
 
+    #[test]
+    fn invariant_prefix_of_glob() {
+        assert_eq!(
+            Glob::new("src/**/*.ts").unwrap().invariant_prefix(),
+            Some(PathBuf::from("src")),
+        );
+        assert_eq!(Glob::new("**/*.ts").unwrap().invariant_prefix(), None);
+    }
+
     #[test]
     fn partition_glob_with_variant_and_invariant_parts() {
         let (prefix, glob) = Glob::new("a/b/x?z/*.ext").unwrap().partition();
@@ -1,10 +1,18 @@
 use std::{
     fs::{FileType, Metadata},
     path::{Component, Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
+#[cfg(feature = "stream")]
+use futures::{stream, Stream};
 use itertools::Itertools;
 use regex::Regex;
+#[cfg(feature = "stream")]
+use tokio::sync::mpsc;
 
 use super::SplitAtDepth;
 use crate::{
@@ -19,6 +27,10 @@ use crate::{
     BuildError, CandidatePath, Glob, Pattern,
 };
 
+/// Number of entries that a stream reads ahead of its consumer.
+#[cfg(feature = "stream")]
+const STREAM_BUFFER_SIZE: usize = 64;
+
 /// APIs for matching globs against directory trees.
 impl<'t> Glob<'t> {
     /// Gets an iterator over matching file paths in a directory tree.
@@ -118,6 +130,9 @@ impl<'t> Glob<'t> {
     /// determined by joining the given path and any [invariant
     /// prefix][`Glob::partition`] of the `Glob`.
     ///
+    /// To collect statistics about the traversal, such as the number of
+    /// directories pruned, see [`GlobWalker::with_stats`].
+    ///
     /// See [`Glob::walk`] for more information.
     ///
     /// # Examples
@@ -150,6 +165,7 @@ impl<'t> Glob<'t> {
     ///
     /// [`Glob::partition`]: crate::Glob::partition
     /// [`Glob::walk`]: crate::Glob::walk
+    /// [`GlobWalker::with_stats`]: crate::walk::GlobWalker::with_stats
     /// [`LinkBehavior`]: crate::walk::LinkBehavior
     /// [`WalkBehavior`]: crate::walk::WalkBehavior
     pub fn walk_with_behavior(
@@ -160,6 +176,55 @@ impl<'t> Glob<'t> {
         self.walker(directory).walk_with_behavior(behavior)
     }
 
+    /// Gets a stream over matching files in a directory tree.
+    ///
+    /// This function is the asynchronous counterpart of [`Glob::walk`]. The
+    /// directory tree is read on Tokio's blocking thread pool and matching
+    /// entries are yielded as a [`Stream`] in the same order as [`Glob::walk`].
+    /// Dropping the stream stops the traversal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use wax::walk::Entry;
+    /// use wax::Glob;
+    ///
+    /// # async fn walk() {
+    /// let glob = Glob::new("**/*.(?i){jpg,jpeg}").unwrap();
+    /// let mut entries = glob.walk_stream("./Pictures");
+    /// while let Some(entry) = entries.next().await {
+    ///     let entry = entry.unwrap();
+    ///     println!("JPEG: {:?}", entry.path());
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Glob::walk`]: crate::Glob::walk
+    /// [`Stream`]: futures::Stream
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn walk_stream(
+        &self,
+        directory: impl Into<PathBuf>,
+    ) -> impl 'static + Stream<Item = Result<GlobEntry, WalkError>> + Unpin {
+        let entries = self.walk(directory);
+        let (sender, mut receiver) = mpsc::channel(STREAM_BUFFER_SIZE);
+        tokio::task::spawn_blocking(move || {
+            for entry in entries {
+                // The receiver has been dropped, so there is no reason to keep walking.
+                if sender.blocking_send(entry).is_err() {
+                    break;
+                }
+            }
+        });
+        stream::poll_fn(move |cx| receiver.poll_recv(cx))
+    }
+
     /// Gets an iterator builder over matching files in a directory tree.
     ///
     /// This function gets an intermediate walker that describes iteration over
@@ -190,6 +255,7 @@ impl<'t> Glob<'t> {
         GlobWalker {
             anchor: self.anchor(directory),
             program: WalkProgram::from_glob(self),
+            stats: None,
         }
     }
 
@@ -328,9 +394,37 @@ impl WalkProgram {
 pub struct GlobWalker {
     anchor: Anchor,
     program: WalkProgram,
+    stats: Option<WalkStats>,
 }
 
 impl GlobWalker {
+    /// Collects [`WalkStats`] while walking.
+    ///
+    /// The given handle shares its counters with any clones, so a clone kept
+    /// by the caller can be read during or after iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use wax::walk::WalkStats;
+    /// use wax::Glob;
+    ///
+    /// let glob = Glob::new("src/**/*.rs").unwrap();
+    /// let stats = WalkStats::default();
+    /// let count = glob.walker(".").with_stats(stats.clone()).walk().count();
+    /// println!(
+    ///     "matched {} files, pruned {} directories",
+    ///     count,
+    ///     stats.directories_pruned(),
+    /// );
+    /// ```
+    ///
+    /// [`WalkStats`]: crate::walk::WalkStats
+    pub fn with_stats(mut self, stats: WalkStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
     /// Gets the root and prefix paths.
     ///
     /// The root path is the path to the walked directory tree. **This path may
@@ -399,6 +493,11 @@ impl GlobWalker {
                 let entry = filtrate.as_ref();
                 let (_, path) = entry.root_relative_paths();
                 let depth = entry.depth().saturating_sub(1);
+                let is_dir = entry.file_type().is_dir();
+                let stats = self.stats.as_ref();
+                if let Some(stats) = stats.filter(|_| is_dir) {
+                    stats.inner.directories.fetch_add(1, Ordering::Relaxed);
+                }
                 for (position, candidate) in path
                     .components()
                     .filter_map(|component| match component {
@@ -415,6 +514,9 @@ impl GlobWalker {
                             if !program.is_match(candidate.as_ref()) {
                                 // Do not walk directories that do not match the corresponding
                                 // component program.
+                                if let Some(stats) = stats.filter(|_| is_dir) {
+                                    stats.inner.pruned.fetch_add(1, Ordering::Relaxed);
+                                }
                                 return filtrate.filter_tree(cancellation).into();
                             }
                         }
@@ -428,6 +530,9 @@ impl GlobWalker {
                                     .map(MatchedText::from)
                                     .map(MatchedText::into_owned)
                                 {
+                                    if let Some(stats) = stats.filter(|_| !is_dir) {
+                                        stats.inner.matched.fetch_add(1, Ordering::Relaxed);
+                                    }
                                     filtrate
                                         .map(|entry| Ok(GlobEntry { entry, matched }))
                                         .into()
@@ -437,6 +542,9 @@ impl GlobWalker {
                             } else {
                                 // Do not walk directories that do not match the corresponding
                                 // component program.
+                                if let Some(stats) = stats.filter(|_| is_dir) {
+                                    stats.inner.pruned.fetch_add(1, Ordering::Relaxed);
+                                }
                                 filtrate.filter_tree(cancellation).into()
                             };
                         }
@@ -449,6 +557,9 @@ impl GlobWalker {
                                 .map(MatchedText::from)
                                 .map(MatchedText::into_owned)
                             {
+                                if let Some(stats) = stats.filter(|_| !is_dir) {
+                                    stats.inner.matched.fetch_add(1, Ordering::Relaxed);
+                                }
                                 filtrate
                                     .map(|entry| Ok(GlobEntry { entry, matched }))
                                     .into()
@@ -472,6 +583,9 @@ impl GlobWalker {
                     .map(MatchedText::from)
                     .map(MatchedText::into_owned)
                 {
+                    if let Some(stats) = stats.filter(|_| !is_dir) {
+                        stats.inner.matched.fetch_add(1, Ordering::Relaxed);
+                    }
                     return filtrate
                         .map(|entry| Ok(GlobEntry { entry, matched }))
                         .into();
@@ -481,6 +595,51 @@ impl GlobWalker {
     }
 }
 
+/// Counts of the work done by a [`GlobWalker`].
+///
+/// `WalkStats` is a handle to shared counters: clones observe the same counts.
+/// Attach a handle to a walk with [`GlobWalker::with_stats`] and read it once
+/// iteration is done to diagnose slow walks. Directories are pruned when they
+/// cannot match the `Glob`, in which case none of their contents are read.
+///
+/// [`GlobWalker`]: crate::walk::GlobWalker
+/// [`GlobWalker::with_stats`]: crate::walk::GlobWalker::with_stats
+#[derive(Clone, Debug, Default)]
+pub struct WalkStats {
+    inner: Arc<WalkCounters>,
+}
+
+#[derive(Debug, Default)]
+struct WalkCounters {
+    // Every directory encountered, whether or not it was pruned
+    directories: AtomicUsize,
+    pruned: AtomicUsize,
+    matched: AtomicUsize,
+}
+
+impl WalkStats {
+    /// Gets the number of directories that were descended into. Pruned
+    /// directories are not included.
+    pub fn directories_visited(&self) -> usize {
+        self.inner
+            .directories
+            .load(Ordering::Relaxed)
+            .saturating_sub(self.directories_pruned())
+    }
+
+    /// Gets the number of directories that were not descended into because
+    /// nothing beneath them can match.
+    pub fn directories_pruned(&self) -> usize {
+        self.inner.pruned.load(Ordering::Relaxed)
+    }
+
+    /// Gets the number of files that matched the `Glob`. Matching directories
+    /// are not included.
+    pub fn files_matched(&self) -> usize {
+        self.inner.matched.load(Ordering::Relaxed)
+    }
+}
+
 #[derive(Clone, Debug)]
 enum FilterAnyProgram {
     Empty,
@@ -594,6 +753,21 @@ impl FilterAny {
         })
     }
 
+    /// Returns `true` if every pattern is
+    /// [exhaustive][`Program::is_exhaustive`], such as `node_modules/**`.
+    ///
+    /// When this is the case, any directory that matches has its entire tree
+    /// discarded, so a walk filtered by these patterns never reads an excluded
+    /// directory. Trivially returns `true` if there are no patterns.
+    ///
+    /// [`Program::is_exhaustive`]: crate::Program::is_exhaustive
+    pub fn is_fully_exhaustive(&self) -> bool {
+        matches!(
+            self.program,
+            FilterAnyProgram::Exhaustive(_) | FilterAnyProgram::Empty
+        )
+    }
+
     /// Gets the appropriate [`EntryResidue`] for the given [`Entry`].
     ///
     /// Notably, this function returns [`EntryResidue::Tree`] if the [`Entry`]
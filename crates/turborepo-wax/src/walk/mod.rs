@@ -76,20 +76,17 @@ use std::{
     io,
     io::ErrorKind,
     path::{Path, PathBuf},
-    rc::Rc,
+    sync::Arc,
 };
 
 use thiserror::Error;
 use walkdir::{DirEntry, Error, WalkDir};
 
-pub use crate::walk::glob::{GlobEntry, GlobWalker};
+pub use crate::walk::glob::{FilterAny, GlobEntry, GlobWalker, WalkStats};
 use crate::{
-    walk::{
-        filter::{
-            CancelWalk, HierarchicalIterator, Isomeric, SeparatingFilter, SeparatingFilterInput,
-            Separation, TreeResidue, WalkCancellation,
-        },
-        glob::FilterAny,
+    walk::filter::{
+        CancelWalk, HierarchicalIterator, Isomeric, SeparatingFilter, SeparatingFilterInput,
+        Separation, TreeResidue, WalkCancellation,
     },
     BuildError, Pattern,
 };
@@ -332,6 +329,17 @@ pub enum LinkBehavior {
     /// If a link is reentrant and forms a cycle, then an error will be emitted
     /// instead of an entry and traversal does not follow the link.
     ReadTarget,
+    /// Read the target of the symbolic link if it is within the root directory.
+    ///
+    /// This behavior is the same as [`LinkBehavior::ReadTarget`] for links
+    /// with a canonical target beneath the root directory of the walk. Any
+    /// other link is read like [`LinkBehavior::ReadFile`] and traversal does
+    /// **not** follow it. This prevents traversal from escaping the root
+    /// directory into unrelated parts of the file system.
+    ///
+    /// [`LinkBehavior::ReadFile`]: crate::walk::LinkBehavior::ReadFile
+    /// [`LinkBehavior::ReadTarget`]: crate::walk::LinkBehavior::ReadTarget
+    ReadTargetWithinRoot,
 }
 
 /// Configuration for walking directory trees.
@@ -523,7 +531,14 @@ pub enum WaxDirEntry {
         path: PathBuf,
         file_type: FileType,
         depth: usize,
-        error: Rc<Error>,
+        error: Arc<Error>,
+    },
+    /// Symlinks with targets outside of the root are read as files rather
+    /// than followed
+    OutOfRootSymlink {
+        path: PathBuf,
+        file_type: FileType,
+        depth: usize,
     },
 }
 
@@ -552,7 +567,7 @@ impl TryFrom<walkdir::Error> for WaxDirEntry {
                     path: path.to_path_buf(),
                     file_type: symlink_meta.file_type(),
                     depth: error.depth(),
-                    error: Rc::new(error),
+                    error: Arc::new(error),
                 });
             }
         }
@@ -565,28 +580,34 @@ impl WaxDirEntry {
     pub fn path(&self) -> &Path {
         match self {
             WaxDirEntry::DirEntry(inner) => inner.path(),
-            WaxDirEntry::DeadSymlink { path, .. } => path.as_path(),
+            WaxDirEntry::DeadSymlink { path, .. } | WaxDirEntry::OutOfRootSymlink { path, .. } => {
+                path.as_path()
+            }
         }
     }
 
     pub fn into_path(self) -> PathBuf {
         match self {
             WaxDirEntry::DirEntry(inner) => inner.into_path(),
-            WaxDirEntry::DeadSymlink { path, .. } => path,
+            WaxDirEntry::DeadSymlink { path, .. } | WaxDirEntry::OutOfRootSymlink { path, .. } => {
+                path
+            }
         }
     }
 
     pub fn file_type(&self) -> FileType {
         match self {
             WaxDirEntry::DirEntry(inner) => inner.file_type(),
-            WaxDirEntry::DeadSymlink { file_type, .. } => *file_type,
+            WaxDirEntry::DeadSymlink { file_type, .. }
+            | WaxDirEntry::OutOfRootSymlink { file_type, .. } => *file_type,
         }
     }
 
     pub fn depth(&self) -> usize {
         match self {
             WaxDirEntry::DirEntry(inner) => inner.depth(),
-            WaxDirEntry::DeadSymlink { depth, .. } => *depth,
+            WaxDirEntry::DeadSymlink { depth, .. }
+            | WaxDirEntry::OutOfRootSymlink { depth, .. } => *depth,
         }
     }
 
@@ -602,6 +623,14 @@ impl WaxDirEntry {
                     },
                 })
             }
+            WaxDirEntry::OutOfRootSymlink { path, depth, .. } => fs::symlink_metadata(path)
+                .map_err(|e| WalkError {
+                    depth: *depth,
+                    kind: WalkErrorKind::Io {
+                        path: Some(path.to_path_buf()),
+                        error: e,
+                    },
+                }),
         }
     }
 }
@@ -661,6 +690,8 @@ impl Entry for TreeEntry {
 pub struct WalkTree {
     prefix: usize,
     is_dir: bool,
+    // The canonical root directory when links are only followed within it
+    link_root: Option<PathBuf>,
     input: walkdir::IntoIter,
 }
 
@@ -676,19 +707,53 @@ impl WalkTree {
     ) -> Self {
         let root = root.into();
         let WalkBehavior { link, depth } = behavior.into();
+        let link_root = match link {
+            // The prefix is part of the glob, so the root of the walk is the directory that
+            // precedes it
+            LinkBehavior::ReadTargetWithinRoot => {
+                let (link_root, _) = root.split_at_depth(prefix);
+                Some(fs::canonicalize(link_root).unwrap_or_else(|_| link_root.to_path_buf()))
+            }
+            LinkBehavior::ReadFile | LinkBehavior::ReadTarget => None,
+        };
         let builder = WalkDir::new(root.as_path());
         WalkTree {
             prefix,
             is_dir: false,
+            link_root,
             input: builder
                 .follow_links(match link {
                     LinkBehavior::ReadFile => false,
-                    LinkBehavior::ReadTarget => true,
+                    LinkBehavior::ReadTarget | LinkBehavior::ReadTargetWithinRoot => true,
                 })
                 .max_depth(depth)
                 .into_iter(),
         }
     }
+
+    /// Reads a followed symlink with a target outside of the root as a file.
+    fn unfollow_out_of_root_link(&mut self, entry: DirEntry) -> WaxDirEntry {
+        let Some(link_root) = &self.link_root else {
+            return entry.into();
+        };
+        if !entry.path_is_symlink()
+            || fs::canonicalize(entry.path()).map_or(false, |target| target.starts_with(link_root))
+        {
+            return entry.into();
+        }
+
+        if entry.file_type().is_dir() {
+            self.input.skip_current_dir();
+        }
+        match fs::symlink_metadata(entry.path()) {
+            Ok(metadata) => WaxDirEntry::OutOfRootSymlink {
+                file_type: metadata.file_type(),
+                depth: entry.depth(),
+                path: entry.into_path(),
+            },
+            Err(_) => entry.into(),
+        }
+    }
 }
 
 impl CancelWalk for WalkTree {
@@ -708,13 +773,16 @@ impl Iterator for WalkTree {
     fn next(&mut self) -> Option<Self::Item> {
         let (is_dir, next) = match self.input.next() {
             Some(result) => match result {
-                Ok(entry) => (
-                    entry.file_type().is_dir(),
-                    Some(Ok(TreeEntry {
-                        entry: entry.into(),
-                        prefix: self.prefix,
-                    })),
-                ),
+                Ok(entry) => {
+                    let entry = self.unfollow_out_of_root_link(entry);
+                    (
+                        entry.file_type().is_dir(),
+                        Some(Ok(TreeEntry {
+                            entry,
+                            prefix: self.prefix,
+                        })),
+                    )
+                }
                 Err(error) => match WaxDirEntry::try_from(error) {
                     Ok(entry) => (
                         false,
@@ -1089,7 +1157,7 @@ mod tests {
     use crate::{
         walk::{
             filter::{HierarchicalIterator, Separation, TreeResidue},
-            Entry, FileIterator, LinkBehavior, PathExt, WalkBehavior,
+            Entry, FileIterator, FilterAny, LinkBehavior, PathExt, WalkBehavior, WalkStats,
         },
         Glob,
     };
@@ -1215,6 +1283,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filter_any_with_exhaustive_negations() {
+        let filter = FilterAny::any(["node_modules/**", "**/.git/**"]).unwrap();
+        assert!(filter.is_fully_exhaustive());
+
+        let filter = FilterAny::any(["node_modules/**", "**/*.log"]).unwrap();
+        assert!(!filter.is_fully_exhaustive());
+    }
+
     #[test]
     fn walk_tree_with_empty_not() {
         let (_root, path) = temptree();
@@ -1274,6 +1351,24 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn walk_glob_stream() {
+        use futures::StreamExt;
+
+        let (_root, path) = temptree();
+
+        let glob = Glob::new("**/*.{md,rs}").unwrap();
+        let expected: Vec<_> = glob.walk(&path).flatten().map(Entry::into_path).collect();
+        let paths: Vec<_> = glob
+            .walk_stream(&path)
+            .map(|entry| entry.unwrap().into_path())
+            .collect()
+            .await;
+        assert_eq!(paths.len(), 6);
+        assert_eq!(paths, expected);
+    }
+
     #[test]
     fn walk_glob_with_invariant_terminating_component() {
         let (_root, path) = temptree();
@@ -1288,6 +1383,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn walk_glob_with_stats() {
+        let (_root, path) = temptree();
+
+        let glob = Glob::new("{src,tests}/**/*.rs").unwrap();
+        let stats = WalkStats::default();
+        let paths: HashSet<_> = glob
+            .walker(&path)
+            .with_stats(stats.clone())
+            .walk()
+            .flatten()
+            .map(Entry::into_path)
+            .collect();
+        assert_eq!(paths.len(), 4);
+        assert_eq!(stats.files_matched(), 4);
+        // `doc` cannot match the first component of the glob.
+        assert_eq!(stats.directories_pruned(), 1);
+        // The root, `src`, `tests` and `tests/harness`.
+        assert_eq!(stats.directories_visited(), 4);
+    }
+
+    #[test]
+    fn walk_glob_with_stats_counts_only_files() {
+        let (_root, path) = temptree();
+
+        let glob = Glob::new("tests/**").unwrap();
+        let stats = WalkStats::default();
+        let paths: HashSet<_> = glob
+            .walker(&path)
+            .with_stats(stats.clone())
+            .walk()
+            .flatten()
+            .map(Entry::into_path)
+            .collect();
+        // `tests/harness` matches but is a directory.
+        assert!(paths.contains(&path.join("tests/harness")));
+        assert_eq!(stats.files_matched(), 2);
+    }
+
     #[test]
     fn walk_glob_with_invariant_intermediate_component() {
         let (_root, path) = temptree();
@@ -1485,6 +1619,48 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn walk_tree_with_links_within_root() {
+        use std::{fs, os::unix::fs::symlink};
+
+        let (root, path) = temptree();
+        let outside = root.path().join("outside");
+        fs::create_dir(&outside).unwrap();
+        fs::write(outside.join("secret.rs"), "").unwrap();
+        symlink(path.join("src"), path.join("doc/src")).unwrap();
+        symlink(&outside, path.join("doc/outside")).unwrap();
+
+        let entries: Vec<_> = path
+            .walk_with_behavior(LinkBehavior::ReadTargetWithinRoot)
+            .flatten()
+            .filter(|entry| entry.root_relative_paths().1.starts_with("doc"))
+            .collect();
+        let paths: HashSet<_> = entries
+            .iter()
+            .map(|entry| entry.root_relative_paths().1.to_path_buf())
+            .collect();
+        assert_set_eq!(
+            paths,
+            [
+                PathBuf::from("doc"),
+                PathBuf::from("doc/guide.md"),
+                PathBuf::from("doc/outside"),
+                PathBuf::from("doc/src"),
+                PathBuf::from("doc/src/glob.rs"),
+                PathBuf::from("doc/src/lib.rs"),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let outside_link = entries
+            .iter()
+            .find(|entry| entry.path() == path.join("doc/outside"))
+            .unwrap();
+        assert!(outside_link.file_type().is_symlink());
+    }
+
     #[test]
     #[cfg(any(unix, windows))]
     fn walk_glob_with_cyclic_link_target() {
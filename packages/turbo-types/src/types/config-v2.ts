@@ -4,8 +4,9 @@ export type OutputLogs =
   | "new-only"
   | "errors-only"
   | "none";
-export type EnvMode = "strict" | "loose";
-export type UI = "tui" | "stream";
+export type EnvMode = "strict" | "loose" | "loose-warn";
+export type HashAlgorithm = "xxh64" | "sha256";
+export type UI = "tui" | "stream" | "compact";
 
 /**
  * This is a relative Unix-style path (e.g. `./src/index.ts` or `src/index.ts`).  Absolute paths (e.g. `/tmp/foo`) are not valid.
@@ -35,6 +36,28 @@ export interface BaseSchema {
      */
     [script: string]: Pipeline;
   };
+
+  /**
+   * Task overrides keyed by environment name. When an environment is selected
+   * with `--environment` or `TURBO_ENV`, its tasks are merged on top of `tasks`.
+   *
+   * Documentation: https://turbo.build/repo/docs/reference/configuration#environments
+   *
+   * @defaultValue `{}`
+   */
+  // eslint-disable-next-line @typescript-eslint/consistent-indexed-object-style -- it's more readable to specify a name for the key
+  environments?: {
+    [environment: string]: {
+      /**
+       * Task configuration that is merged into the matching entry of `tasks`.
+       * Tasks that aren't in `tasks` are added.
+       */
+      // eslint-disable-next-line @typescript-eslint/consistent-indexed-object-style -- it's more readable to specify a name for the key
+      tasks?: {
+        [script: string]: Pipeline;
+      };
+    };
+  };
 }
 
 /** A `turbo.json` file in a package in the monorepo (not the root) */
@@ -135,6 +158,14 @@ export interface RootSchema extends BaseSchema {
    */
   cacheDir?: RelativeUnixPath;
 
+  /**
+   * Limit the size of the filesystem cache, in bytes. Once the limit is
+   * exceeded, the least recently used artifacts are evicted.
+   *
+   * Documentation: https://turbo.build/repo/docs/reference/configuration#cachemaxsize
+   */
+  cacheMaxSize?: number;
+
   /**
    * Turborepo runs a background process to pre-calculate some expensive operations. This standalone process (daemon) is a performance optimization, and not required for proper functioning of `turbo`.
    *
@@ -149,12 +180,27 @@ export interface RootSchema extends BaseSchema {
    *
    * - `"strict"`: Filter environment variables to only those that are specified in the `env` and `globalEnv` keys in `turbo.json`.
    * - `"loose"`: Allow all environment variables for the process to be available.
+   * - `"loose-warn"`: Like `"loose"`, but warn about environment variables that tasks use without declaring them.
    *
    * Documentation: https://turbo.build/repo/docs/reference/configuration#envmode
    *
    * @defaultValue `"strict"`
    */
   envMode?: EnvMode;
+
+  /**
+   * The algorithm used to calculate the global hash and the hash of each task:
+   *
+   * - `"xxh64"`: A fast, non-cryptographic hash.
+   * - `"sha256"`: A cryptographic hash. These hashes are prefixed with `v2-`.
+   *
+   * Changing the algorithm is a cache miss for every task.
+   *
+   * Documentation: https://turbo.build/repo/docs/reference/configuration#hashalgorithm
+   *
+   * @defaultValue `"xxh64"`
+   */
+  hashAlgorithm?: HashAlgorithm;
 }
 
 export interface Pipeline {
@@ -219,11 +265,14 @@ export interface Pipeline {
    *
    * Setting cache to false is useful for long-running "watch" or development mode tasks.
    *
+   * Setting cache to "readonly" restores outputs from the cache, but never writes
+   * outputs to the cache.
+   *
    * Documentation: https://turbo.build/repo/docs/reference/configuration#cache
    *
    * @defaultValue `true`
    */
-  cache?: boolean;
+  cache?: boolean | "readonly";
 
   /**
    * The set of glob patterns to consider as inputs to this task.
@@ -282,6 +331,36 @@ export interface Pipeline {
    * @defaultValue `false`
    */
   interactive?: boolean;
+
+  /**
+   * Text that a persistent task prints once it is ready, like a dev server
+   * printing the address it is listening on. Tasks that depend on the
+   * persistent task start once this text appears in its output. Colors are
+   * ignored when matching.
+   *
+   * Documentation: https://turbo.build/repo/docs/reference/configuration#readyoutput
+   */
+  readyOutput?: string;
+
+  /**
+   * Name of a group of tasks that must never run at the same time, like
+   * database migrations in different packages. Tasks in the same group run one
+   * at a time even if they don't depend on each other.
+   *
+   * Documentation: https://turbo.build/repo/docs/reference/configuration#concurrencygroup
+   */
+  concurrencyGroup?: string;
+
+  /**
+   * Exit code the task uses to report that it had nothing to do, like a code
+   * generator that found nothing to regenerate. The task is treated as
+   * successful and it's never cached, so it runs again on the next run.
+   * Dependents hash it as it was the last time it did something, so they
+   * stay cached. Cannot be 0.
+   *
+   * Documentation: https://turbo.build/repo/docs/reference/configuration#noopexitcode
+   */
+  noopExitCode?: number;
 }
 
 export interface RemoteCache {
@@ -336,6 +415,24 @@ export interface RemoteCache {
    * @defaultValue `30`
    */
   timeout?: number;
+  /**
+   * Set the base URL of an S3-compatible bucket's API to use it as the remote
+   * cache instead of Vercel, e.g. `http://localhost:9000`. Artifacts are stored
+   * at `<s3Endpoint>/<s3Bucket>/<hash>`. Credentials are read from the
+   * `TURBO_S3_ACCESS_KEY_ID` and `TURBO_S3_SECRET_ACCESS_KEY` environment
+   * variables.
+   */
+  s3Endpoint?: string;
+  /**
+   * The bucket to store artifacts in when `s3Endpoint` is set.
+   */
+  s3Bucket?: string;
+  /**
+   * The region used to sign requests to the S3-compatible bucket.
+   *
+   * @defaultValue `"us-east-1"`
+   */
+  s3Region?: string;
 }
 
 export const isRootSchemaV2 = (schema: Schema): schema is RootSchema =>
@@ -19,7 +19,7 @@ Validate that we only run `my-app#build` with change not committed
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache miss, executing 1b83c3b24476ec9c
+  my-app:build: cache miss, executing 63de9d9caa31ef0a
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -27,7 +27,7 @@ Validate that we only run `my-app#build` with change not committed
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -103,7 +103,7 @@ Validate that we only run `my-app#build` with change not committed
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache miss, executing c1189254892f813f
+  my-app:build: cache miss, executing 414a5b3835f65d81
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -111,7 +111,7 @@ Validate that we only run `my-app#build` with change not committed
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -151,7 +151,7 @@ Validate that we only run `my-app#build` with change committed
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache hit, replaying logs c1189254892f813f
+  my-app:build: cache hit, replaying logs 414a5b3835f65d81
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -159,7 +159,7 @@ Validate that we only run `my-app#build` with change committed
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
@@ -195,7 +195,7 @@ Override the SCM base to be HEAD, so nothing runs
   No tasks were executed as part of this run.
   
    Tasks:    0 successful, 0 total
-  Cached:    0 cached, 0 total
+  Cached:    0/0 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 
@@ -226,7 +226,7 @@ Override the SCM head to be main, so nothing runs
   No tasks were executed as part of this run.
   
    Tasks:    0 successful, 0 total
-  Cached:    0 cached, 0 total
+  Cached:    0/0 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 
@@ -261,7 +261,7 @@ Run the build and expect only `my-app` to be affected, since between
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache hit, replaying logs c1189254892f813f
+  my-app:build: cache hit, replaying logs 414a5b3835f65d81
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -269,7 +269,7 @@ Run the build and expect only `my-app` to be affected, since between
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
@@ -0,0 +1,14 @@
+Setup
+  $ . ${TESTDIR}/../../helpers/setup_integration_test.sh
+
+Task names aren't completed by default
+  $ ${TURBO} completion bash | grep -q maybefails
+  [1]
+
+Complete the task names from turbo.json
+  $ ${TURBO} completion bash --tasks | grep -q maybefails && echo "found"
+  found
+  $ ${TURBO} completion zsh --tasks | grep -q maybefails && echo "found"
+  found
+  $ ${TURBO} completion fish --tasks | grep -q maybefails && echo "found"
+  found
@@ -25,6 +25,63 @@ Query packages
     }
   }
 
+Query the first page of packages
+  $ ${TURBO} query "query { packages(first: 2) { items { name }, length, pageLength, endCursor, hasNextPage } }" | jq
+   WARNING  query command is experimental and may change in the future
+  {
+    "data": {
+      "packages": {
+        "items": [
+          {
+            "name": "//"
+          },
+          {
+            "name": "another"
+          }
+        ],
+        "length": 4,
+        "pageLength": 2,
+        "endCursor": "another",
+        "hasNextPage": true
+      }
+    }
+  }
+
+Query the next page of packages
+  $ ${TURBO} query "query { packages(first: 2, after: \"another\") { items { name }, length, pageLength, endCursor, hasNextPage } }" | jq
+   WARNING  query command is experimental and may change in the future
+  {
+    "data": {
+      "packages": {
+        "items": [
+          {
+            "name": "my-app"
+          },
+          {
+            "name": "util"
+          }
+        ],
+        "length": 4,
+        "pageLength": 2,
+        "endCursor": "util",
+        "hasNextPage": false
+      }
+    }
+  }
+
+Query packages after the last package
+  $ ${TURBO} query "query { packages(first: 2, after: \"util\") { items { name }, endCursor, hasNextPage } }" | jq
+   WARNING  query command is experimental and may change in the future
+  {
+    "data": {
+      "packages": {
+        "items": [],
+        "endCursor": null,
+        "hasNextPage": false
+      }
+    }
+  }
+
 Query packages with equals filter
   $ ${TURBO} query "query { packages(filter: { equal: { field: NAME, value: \"my-app\" } }) { items { name } } }" | jq
    WARNING  query command is experimental and may change in the future
@@ -40,6 +97,21 @@ Query packages with equals filter
     }
   }
 
+Query packages with the shorthand query syntax
+  $ ${TURBO} query "{ packages(filter: { equal: { field: NAME, value: \"util\" } }) { items { name } } }" | jq
+   WARNING  query command is experimental and may change in the future
+  {
+    "data": {
+      "packages": {
+        "items": [
+          {
+            "name": "util"
+          }
+        ]
+      }
+    }
+  }
+
 Query packages that have at least one dependent package
   $ ${TURBO} query "query { packages(filter: { greaterThan: { field: DIRECT_DEPENDENT_COUNT, value: 0 } }) { items { name } } }" | jq
    WARNING  query command is experimental and may change in the future
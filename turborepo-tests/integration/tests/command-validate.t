@@ -0,0 +1,43 @@
+Setup
+  $ . ${TESTDIR}/../../helpers/setup_integration_test.sh
+
+Validate a valid turbo.json
+  $ ${TURBO} validate
+  turbo.json is valid
+
+Depend on a task that isn't defined anywhere
+  $ sed -i.bak 's/"env": \["NODE_ENV"\],/"dependsOn": ["^nonsense"], "env": ["NODE_ENV"],/' turbo.json
+  $ ${TURBO} validate 2> error.txt
+  [1]
+  $ grep -o 'invalid turbo.json' error.txt
+  invalid turbo.json
+  $ grep -o 'Could not find task "nonsense" that "build" depends on' error.txt
+  Could not find task "nonsense" that "build" depends on
+
+Depend on a task that's only defined in a package turbo.json
+  $ mv turbo.json.bak turbo.json
+  $ cat > packages/util/turbo.json <<EOF
+  > {"extends": ["//"], "tasks": {"codegen": {}}}
+  > EOF
+  $ sed -i.bak 's/"env": \["NODE_ENV"\],/"dependsOn": ["^codegen"], "env": ["NODE_ENV"],/' turbo.json
+  $ ${TURBO} validate
+  turbo.json is valid
+
+Depend on a missing task from a package turbo.json
+  $ mv turbo.json.bak turbo.json
+  $ cat > packages/util/turbo.json <<EOF
+  > {"extends": ["//"], "tasks": {"build": {"dependsOn": ["^missing-in-package"]}}}
+  > EOF
+  $ ${TURBO} validate 2> error.txt
+  [1]
+  $ grep -o 'Could not find task "missing-in-package" that "build" depends on' error.txt
+  Could not find task "missing-in-package" that "build" depends on
+
+Extend from another package in a package turbo.json
+  $ cat > packages/util/turbo.json <<EOF
+  > {"extends": ["another"], "tasks": {}}
+  > EOF
+  $ ${TURBO} validate 2> error.txt
+  [1]
+  $ grep -o 'You can only extend from the root workspace' error.txt
+  You can only extend from the root workspace
@@ -35,9 +35,11 @@ Setup
     "envMode",
     "frameworkInference",
     "globalCacheInputs",
+    "hashVersion",
     "id",
     "monorepo",
     "packages",
+    "schemaVersion",
     "scm",
     "tasks",
     "turboVersion",
@@ -51,7 +53,7 @@ Setup
     "taskId": "my-app#build",
     "task": "build",
     "package": "my-app",
-    "hash": "0555ce94ca234049",
+    "hash": "d61cd49c85806c74",
     "inputs": {
       ".env.local": "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391",
       "package.json": "1746e0db2361085b5953a6a3beab08c24af5bc08"
@@ -80,6 +82,7 @@ Setup
         "banana.txt"
       ],
       "cache": true,
+      "cacheReadonly": false,
       "dependsOn": [],
       "inputs": [
         "$TURBO_DEFAULT$",
@@ -111,7 +114,7 @@ Setup
     "taskId": "util#build",
     "task": "build",
     "package": "util",
-    "hash": "bf1798d3e46e1b48",
+    "hash": "3e9a693bce63c0d0",
     "inputs": {
       "package.json": "e755064fd7893809d10fc067bb409c7ae516327f"
     },
@@ -133,6 +136,7 @@ Setup
     "resolvedTaskDefinition": {
       "outputs": [],
       "cache": true,
+      "cacheReadonly": false,
       "dependsOn": [],
       "inputs": [],
       "outputLogs": "full",
@@ -5,6 +5,7 @@ Setup
 
   $ ${TURBO} run build --dry=json
   {
+    "schemaVersion": "1",
     "id": "[a-zA-Z0-9]+", (re)
     "version": "1",
     "turboVersion": "[a-z0-9\.-]+", (re)
@@ -30,11 +31,12 @@ Setup
     },
     "envMode": "strict",
     "frameworkInference": true,
+    "hashVersion": 1,
     "tasks": [
       {
         "taskId": "build",
         "task": "build",
-        "hash": "e2b99dad85a4ff66",
+        "hash": "1b472efbe24399cb",
         "inputs": {
           ".gitignore": "03b541460c1b836f96f9c0a941ceb48e91a9fd83",
           "package-lock.json": "1c117cce37347befafe3a9cba1b8a609b3600021",
@@ -58,6 +60,7 @@ Setup
         "resolvedTaskDefinition": {
           "outputs": [],
           "cache": false,
+          "cacheReadonly": false,
           "dependsOn": [],
           "inputs": [],
           "outputLogs": "full",
@@ -3,6 +3,7 @@ Setup
 
   $ ${TURBO} run build --dry=json
   {
+    "schemaVersion": "1",
     "id": "[a-zA-Z0-9]+", (re)
     "version": "1",
     "turboVersion": "[a-z0-9\.-]+", (re)
@@ -29,11 +30,12 @@ Setup
     },
     "envMode": "strict",
     "frameworkInference": true,
+    "hashVersion": 1,
     "tasks": [
       {
         "taskId": "build",
         "task": "build",
-        "hash": "7ece7b62aad25615",
+        "hash": "557df624384d551d",
         "inputs": {
           ".gitignore": "03b541460c1b836f96f9c0a941ceb48e91a9fd83",
           "package-lock.json": "1c117cce37347befafe3a9cba1b8a609b3600021",
@@ -62,6 +64,7 @@ Setup
             "foo.txt"
           ],
           "cache": true,
+          "cacheReadonly": false,
           "dependsOn": [],
           "inputs": [],
           "outputLogs": "full",
@@ -10,11 +10,11 @@ Baseline task hashes
   }
   {
     "taskId": "my-app#build",
-    "hash": "0555ce94ca234049"
+    "hash": "d61cd49c85806c74"
   }
   {
     "taskId": "util#build",
-    "hash": "bf1798d3e46e1b48"
+    "hash": "3e9a693bce63c0d0"
   }
 
 Change only my-app#build
@@ -26,11 +26,11 @@ Change only my-app#build
   }
   {
     "taskId": "my-app#build",
-    "hash": "6eea03fab6f9a8c8"
+    "hash": "f227044040eb38f6"
   }
   {
     "taskId": "util#build",
-    "hash": "bf1798d3e46e1b48"
+    "hash": "3e9a693bce63c0d0"
   }
 
 Change my-app#build dependsOn
@@ -42,11 +42,11 @@ Change my-app#build dependsOn
   }
   {
     "taskId": "my-app#build",
-    "hash": "8637a0f5db686164"
+    "hash": "8081582b6b06190b"
   }
   {
     "taskId": "util#build",
-    "hash": "bf1798d3e46e1b48"
+    "hash": "3e9a693bce63c0d0"
   }
 
 Non-materially modifying the dep graph does nothing.
@@ -58,11 +58,11 @@ Non-materially modifying the dep graph does nothing.
   }
   {
     "taskId": "my-app#build",
-    "hash": "8637a0f5db686164"
+    "hash": "8081582b6b06190b"
   }
   {
     "taskId": "util#build",
-    "hash": "bf1798d3e46e1b48"
+    "hash": "3e9a693bce63c0d0"
   }
 
 
@@ -75,9 +75,9 @@ Change util#build impacts itself and my-app
   }
   {
     "taskId": "my-app#build",
-    "hash": "2721f01b53b758d0"
+    "hash": "ebf6c089e90208e3"
   }
   {
     "taskId": "util#build",
-    "hash": "74c8eb9bab702b4b"
+    "hash": "8ff6332108f4696d"
   }
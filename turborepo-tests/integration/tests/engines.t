@@ -5,14 +5,14 @@ Setup
 
 Check a hash
   $ ${TURBO} build --dry=json --filter=my-app | jq '.tasks | last | .hash'
-  "56d7eb9a31d82ee0"
+  "aa9e82c982a08e4c"
 Change engines
   $ jq '.engines = {"node": ">=16"}' package.json > package.json.new
   $ mv package.json.new package.json
 
 Verify hash has changed
   $ ${TURBO} build --dry=json --filter=my-app | jq ".tasks | last | .hash"
-  "1d8b8596ae37a40c"
+  "fc4e9f61170ebc86"
 
 Verify engines are part of global cache inputs
   $ ${TURBO} build --dry=json | jq '.globalCacheInputs.engines'
@@ -10,7 +10,7 @@ Setup
   No tasks were executed as part of this run.
   
    Tasks:    0 successful, 0 total
-  Cached:    0 cached, 0 total
+  Cached:    0/0 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 
@@ -24,7 +24,7 @@ Setup
   No tasks were executed as part of this run.
   
    Tasks:    0 successful, 0 total
-  Cached:    0 cached, 0 total
+  Cached:    0/0 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 
@@ -37,7 +37,7 @@ Setup
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -8,10 +8,10 @@ Setup
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache miss, executing bf1798d3e46e1b48
+  util:build: cache miss, executing 3e9a693bce63c0d0
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
@@ -20,10 +20,10 @@ Setup
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache hit, suppressing logs bf1798d3e46e1b48
+  util:build: cache hit, suppressing logs 3e9a693bce63c0d0
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 # set global env var and ensure cache miss
@@ -31,10 +31,10 @@ Setup
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache miss, executing 265e40ee03ae83ec
+  util:build: cache miss, executing fe9a9d25a39404ce
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
@@ -43,10 +43,10 @@ Setup
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache hit, suppressing logs bf1798d3e46e1b48
+  util:build: cache hit, suppressing logs 3e9a693bce63c0d0
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 # set vercel analytics env var and ensure cache miss
@@ -54,10 +54,10 @@ Setup
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache miss, executing b8f403756f67074c
+  util:build: cache miss, executing cfc4a253389a7536
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
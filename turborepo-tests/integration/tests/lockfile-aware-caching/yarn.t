@@ -15,7 +15,7 @@ Populate cache
   a:build: Done in [\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task a#build. Please check your `outputs` key in `turbo.json`
@@ -31,7 +31,7 @@ Populate cache
   b:build: Done in [\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task b#build. Please check your `outputs` key in `turbo.json`
@@ -52,7 +52,7 @@ Only b should have a cache miss
   a:build: Done in [\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
@@ -68,7 +68,7 @@ Only b should have a cache miss
   b:build: Done in [\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task b#build. Please check your `outputs` key in `turbo.json`
@@ -121,7 +121,7 @@ Bump of root workspace invalidates all packages
   a:build: Done in [\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task a#build. Please check your `outputs` key in `turbo.json`
@@ -137,7 +137,7 @@ Bump of root workspace invalidates all packages
   b:build: Done in [\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task b#build. Please check your `outputs` key in `turbo.json`
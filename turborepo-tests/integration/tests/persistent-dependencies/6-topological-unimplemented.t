@@ -17,7 +17,7 @@
   \xe2\x80\xa2 Packages in scope: app-a, pkg-a (esc)
   \xe2\x80\xa2 Running dev in 2 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  app-a:dev: cache miss, executing 7def3a3e7f1235de
+  app-a:dev: cache miss, executing 6a4deaecdca54de8
   app-a:dev: 
   app-a:dev: > dev
   app-a:dev: > echo dev-app-a
@@ -25,7 +25,7 @@
   app-a:dev: dev-app-a
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s+[.0-9]+m?s  (re)
   
    WARNING  no output files found for task app-a#dev. Please check your `outputs` key in `turbo.json`
@@ -8,7 +8,7 @@ Run a build to get a local cache.
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s+[.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
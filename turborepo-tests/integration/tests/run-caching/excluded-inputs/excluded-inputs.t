@@ -9,7 +9,7 @@ Running build for my-app succeeds
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache miss, executing e228bd94fd46352c
+  my-app:build: cache miss, executing 9212f501b4b19bfd
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -17,7 +17,7 @@ Running build for my-app succeeds
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -27,7 +27,7 @@ Update exluded file and try again
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache hit, replaying logs e228bd94fd46352c
+  my-app:build: cache hit, replaying logs 9212f501b4b19bfd
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -35,6 +35,6 @@ Update exluded file and try again
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
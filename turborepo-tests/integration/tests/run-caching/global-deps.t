@@ -8,7 +8,7 @@ Run a build to get a local cache.
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s+[.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -20,7 +20,7 @@ Run again to get cache hit
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
@@ -31,7 +31,7 @@ Run again without env var to get cache miss
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s+[.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -10,10 +10,10 @@ Warm the cache
   \xe2\x80\xa2 Packages in scope: another (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  another:build: cache miss, executing 6a4c300cb14847b0
+  another:build: cache miss, executing ecd3613715770bc0
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s+[.0-9]+m?s  (re)
   
    WARNING  no output files found for task another#build. Please check your `outputs` key in `turbo.json`
@@ -23,10 +23,10 @@ Confirm cache hit
   \xe2\x80\xa2 Packages in scope: another (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  another:build: cache hit, suppressing logs 6a4c300cb14847b0
+  another:build: cache hit, suppressing logs ecd3613715770bc0
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s+[.0-9]+m?s >>> FULL TURBO (re)
   
 
@@ -38,10 +38,10 @@ All tasks should be a cache miss, even ones that don't depend on changed package
   \xe2\x80\xa2 Packages in scope: another (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  another:build: cache miss, executing 34787620f332fb95
+  another:build: cache miss, executing 876aaa681a9d764a
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s+[.0-9]+m?s  (re)
   
    WARNING  no output files found for task another#build. Please check your `outputs` key in `turbo.json`
@@ -64,10 +64,10 @@ Cache hit since only tracked files contribute to root dep hash
   \xe2\x80\xa2 Packages in scope: another (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  another:build: cache hit, suppressing logs 34787620f332fb95
+  another:build: cache hit, suppressing logs 876aaa681a9d764a
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
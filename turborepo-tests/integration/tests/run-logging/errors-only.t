@@ -10,7 +10,7 @@ Setup
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task app-a#build. Please check your `outputs` key in `turbo.json`
@@ -26,7 +26,7 @@ Setup
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task app-a#buildsuccess. Please check your `outputs` key in `turbo.json`
@@ -39,7 +39,7 @@ Setup
   \xe2\x80\xa2 Packages in scope: app-a (esc)
   \xe2\x80\xa2 Running builderror in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  app-a:builderror: cache miss, executing 7e337a3261100818
+  app-a:builderror: cache miss, executing 12bc251e8e79e8b6
   app-a:builderror: 
   app-a:builderror: > builderror
   app-a:builderror: > echo error-builderror-app-a && exit 1
@@ -53,7 +53,7 @@ Setup
   app-a#builderror: command .*npm(?:\.cmd)? run builderror exited \(1\) (re)
   
    Tasks:    0 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    app-a#builderror
   
@@ -69,7 +69,7 @@ Setup
   \xe2\x80\xa2 Packages in scope: app-a (esc)
   \xe2\x80\xa2 Running builderror2 in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  app-a:builderror2: cache miss, executing 3731518fa339b920
+  app-a:builderror2: cache miss, executing 7a4646b45ba106e8
   app-a:builderror2: 
   app-a:builderror2: > builderror2
   app-a:builderror2: > echo error-builderror2-app-a && exit 1
@@ -83,7 +83,7 @@ Setup
   app-a#builderror2: command .*npm(?:\.cmd)? run builderror2 exited \(1\) (re)
   
    Tasks:    0 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    app-a#builderror2
   
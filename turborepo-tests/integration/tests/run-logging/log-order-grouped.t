@@ -6,14 +6,14 @@
   \xe2\x80\xa2 Packages in scope: my-app, util (esc)
   \xe2\x80\xa2 Running build in 2 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache bypass, force executing 0af90ec6a57471be
+  my-app:build: cache bypass, force executing 8a02b7e529200ca5
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building && sleep 1 && echo done
   my-app:build: 
   my-app:build: building
   my-app:build: done
-  util:build: cache bypass, force executing 2da422600daca8be
+  util:build: cache bypass, force executing fbb8a03e3d64a09e
   util:build: 
   util:build: > build
   util:build: > sleep 0.5 && echo building && sleep 1 && echo completed
@@ -22,7 +22,7 @@
   util:build: completed
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -34,14 +34,14 @@
   \xe2\x80\xa2 Packages in scope: my-app, util (esc)
   \xe2\x80\xa2 Running build in 2 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache bypass, force executing 0af90ec6a57471be
+  my-app:build: cache bypass, force executing 8a02b7e529200ca5
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building && sleep 1 && echo done
   my-app:build: 
   my-app:build: building
   my-app:build: done
-  util:build: cache bypass, force executing 2da422600daca8be
+  util:build: cache bypass, force executing fbb8a03e3d64a09e
   util:build: 
   util:build: > build
   util:build: > sleep 0.5 && echo building && sleep 1 && echo completed
@@ -50,7 +50,7 @@
   util:build: completed
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -61,14 +61,14 @@
   \xe2\x80\xa2 Packages in scope: my-app, util (esc)
   \xe2\x80\xa2 Running build in 2 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache bypass, force executing 0af90ec6a57471be
+  my-app:build: cache bypass, force executing 8a02b7e529200ca5
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building && sleep 1 && echo done
   my-app:build: 
   my-app:build: building
   my-app:build: done
-  util:build: cache bypass, force executing 2da422600daca8be
+  util:build: cache bypass, force executing fbb8a03e3d64a09e
   util:build: 
   util:build: > build
   util:build: > sleep 0.5 && echo building && sleep 1 && echo completed
@@ -77,7 +77,7 @@
   util:build: completed
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -6,7 +6,7 @@ Setup
   \xe2\x80\xa2 Packages in scope: app-a (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  cache miss, executing 612027951a2848ce
+  cache miss, executing 4364de9d48495939
   
   \> build (re)
   \> echo build-app-a (re)
@@ -14,7 +14,7 @@ Setup
   build-app-a
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task app-a#build. Please check your `outputs` key in `turbo.json`
@@ -31,7 +31,7 @@ Setup
   \xe2\x80\xa2 Packages in scope: app-a (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  cache hit, replaying logs 612027951a2848ce
+  cache hit, replaying logs 4364de9d48495939
   
   \> build (re)
   \> echo build-app-a (re)
@@ -39,7 +39,7 @@ Setup
   build-app-a
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 # Running again withuot `--log-prefix` should get a cache hit, but should print prefixes this time
@@ -47,7 +47,7 @@ Setup
   \xe2\x80\xa2 Packages in scope: app-a (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  app-a:build: cache hit, replaying logs 612027951a2848ce
+  app-a:build: cache hit, replaying logs 4364de9d48495939
   app-a:build: 
   app-a:build: > build
   app-a:build: > echo build-app-a
@@ -55,7 +55,7 @@ Setup
   app-a:build: build-app-a
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
@@ -6,7 +6,7 @@ Verbosity level 1
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache bypass, force executing bf1798d3e46e1b48
+  util:build: cache bypass, force executing 3e9a693bce63c0d0
   util:build: 
   util:build: > build
   util:build: > echo building
@@ -14,7 +14,7 @@ Verbosity level 1
   util:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
@@ -22,7 +22,7 @@ Verbosity level 1
   \xe2\x80\xa2 Packages in scope: util (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache bypass, force executing bf1798d3e46e1b48
+  util:build: cache bypass, force executing 3e9a693bce63c0d0
   util:build: 
   util:build: > build
   util:build: > echo building
@@ -30,7 +30,7 @@ Verbosity level 1
   util:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
@@ -21,6 +21,7 @@ Validate that there was a failed task and exitCode is 1 (which is what we get fr
     "failed": 1,
     "cached": 0,
     "attempted": 1,
+    "cacheHitRate": 0,
     "startTime": [0-9]+, (re)
     "endTime": [0-9]+, (re)
     "exitCode": 1
@@ -32,7 +33,7 @@ Validate that we got a full task summary for the failed task with an error in .e
     "taskId": "my-app#maybefails",
     "task": "maybefails",
     "package": "my-app",
-    "hash": "9f05a7188fdf4e93",
+    "hash": "53945bf5fa834eca",
     "inputs": {
       ".env.local": "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391",
       "package.json": "1746e0db2361085b5953a6a3beab08c24af5bc08"
@@ -55,6 +56,7 @@ Validate that we got a full task summary for the failed task with an error in .e
     "resolvedTaskDefinition": {
       "outputs": [],
       "cache": true,
+      "cacheReadonly": false,
       "dependsOn": [],
       "inputs": [],
       "outputLogs": "full",
@@ -79,7 +81,8 @@ Validate that we got a full task summary for the failed task with an error in .e
       "startTime": [0-9]+, (re)
       "endTime": [0-9]+, (re)
       "error": "command .*npm(?:\.cmd)? run maybefails exited \(1\)", (re)
-      "exitCode": 1
+      "exitCode": 1,
+      "peakMemory": [0-9]+ (re)
     }
   }
 
@@ -102,6 +105,7 @@ success should be 1, and attempted should be 2
     "failed": 1,
     "cached": 0,
     "attempted": 2,
+    "cacheHitRate": 0,
     "startTime": [0-9]+, (re)
     "endTime": [0-9]+, (re)
     "exitCode": 1
@@ -116,5 +120,6 @@ success should be 1, and attempted should be 2
     "startTime": [0-9]+, (re)
     "endTime": [0-9]+, (re)
     "error": "command .*npm(?:\.cmd)? run maybefails exited \(1\)", (re)
-    "exitCode": 1
+    "exitCode": 1,
+    "peakMemory": [0-9]+ (re)
   }
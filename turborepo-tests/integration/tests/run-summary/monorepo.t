@@ -36,6 +36,7 @@ Setup
     "execution",
     "frameworkInference",
     "globalCacheInputs",
+    "hashVersion",
     "id",
     "monorepo",
     "packages",
@@ -58,6 +59,8 @@ Setup
   2
   $ cat $FIRST | jq '.version'
   "1"
+  $ cat $FIRST | jq '.hashVersion'
+  1
   $ cat $FIRST | jq '.execution.exitCode'
   0
   $ cat $FIRST | jq '.execution.attempted'
@@ -83,7 +86,8 @@ Setup
   {
     "startTime": [0-9]+, (re)
     "endTime": [0-9]+, (re)
-    "exitCode": 0
+    "exitCode": 0,
+    "peakMemory": [0-9]+ (re)
   }
   $ echo $FIRST_APP_BUILD | jq '.cliArguments'
   [
@@ -117,7 +121,8 @@ Setup
   {
     "startTime": [0-9]+, (re)
     "endTime": [0-9]+, (re)
-    "exitCode": 0
+    "exitCode": 0,
+    "peakMemory": [0-9]+ (re)
   }
 
 # another#build is not in tasks, because it didn't execute (script was not implemented)
@@ -157,6 +162,7 @@ Setup
     "execution",
     "frameworkInference",
     "globalCacheInputs",
+    "hashVersion",
     "id",
     "monorepo",
     "packages",
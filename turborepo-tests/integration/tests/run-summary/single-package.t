@@ -18,6 +18,7 @@ Check
   $ cat $SUMMARY | jq '.execution | keys'
   [
     "attempted",
+    "cacheHitRate",
     "cached",
     "command",
     "endTime",
@@ -34,6 +35,7 @@ Check
     "execution",
     "frameworkInference",
     "globalCacheInputs",
+    "hashVersion",
     "id",
     "monorepo",
     "scm",
@@ -92,7 +94,8 @@ Check
   {
     "startTime": [0-9]+, (re)
     "endTime": [0-9]+, (re)
-    "exitCode": 0
+    "exitCode": 0,
+    "peakMemory": [0-9]+ (re)
   }
   $ echo $TASK_SUMMARY | jq '.cliArguments'
   []
@@ -128,6 +131,7 @@ Check Rust implementation
   $ cat $SUMMARY | jq '.execution | keys'
   [
     "attempted",
+    "cacheHitRate",
     "cached",
     "command",
     "endTime",
@@ -144,6 +148,7 @@ Check Rust implementation
     "execution",
     "frameworkInference",
     "globalCacheInputs",
+    "hashVersion",
     "id",
     "monorepo",
     "scm",
@@ -12,7 +12,7 @@ Runs test tasks
   \xe2\x80\xa2 Packages in scope: another, my-app, util (esc)
   \xe2\x80\xa2 Running test in 3 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:test: cache bypass, force executing d80016a1a60c4c0a
+  my-app:test: cache bypass, force executing b4958e21e58b7861
   my-app:test: 
   my-app:test: > test
   my-app:test: > echo $MY_VAR
@@ -20,7 +20,7 @@ Runs test tasks
   my-app:test: foo
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 
@@ -30,7 +30,7 @@ Ensure caching is disabled
   \xe2\x80\xa2 Packages in scope: another, my-app, util (esc)
   \xe2\x80\xa2 Running test in 3 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:test: cache bypass, force executing d80016a1a60c4c0a
+  my-app:test: cache bypass, force executing b4958e21e58b7861
   my-app:test: 
   my-app:test: > test
   my-app:test: > echo $MY_VAR
@@ -38,7 +38,7 @@ Ensure caching is disabled
   my-app:test: foo
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 Finds all tasks based on scripts
@@ -0,0 +1,21 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+  $ cat > apps/my-app/package.json <<EOF
+  > {"name": "my-app", "scripts": {"build": "seq 1 100000"}}
+  > EOF
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"build": {"cache": false}}}
+  > EOF
+
+Closing the reader early stops the run without an error
+  $ { ${TURBO} run build --filter=my-app 2> /dev/null; echo $? > exit-code; } | head -n 10 > /dev/null
+  $ cat exit-code
+  0
+
+The run still finishes normally, so the run summary is written
+  $ { ${TURBO} run build --filter=my-app --summarize 2> /dev/null; echo $? > exit-code; } | head -n 10 > /dev/null
+  $ cat exit-code
+  0
+  $ SUMMARY=$(/bin/ls .turbo/runs/*.json | head -n1)
+  $ cat $SUMMARY | jq '.tasks | length'
+  1
@@ -0,0 +1,20 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+Each migrate task holds a lock for a second and fails if another task already holds it
+  $ for pkg in packages/util packages/another; do
+  >   jq '.scripts.migrate = "mkdir ../../db.lock && sleep 1 && rmdir ../../db.lock"' $pkg/package.json > package.json.tmp
+  >   mv package.json.tmp $pkg/package.json
+  > done
+
+Tasks in the same concurrency group run one at a time
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"migrate": {"cache": false, "concurrencyGroup": "db"}}}
+  > EOF
+  $ ${TURBO} run migrate --filter=util --filter=another > /dev/null
+
+Without a concurrency group the tasks overlap and one of them can't take the lock
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"migrate": {"cache": false}}}
+  > EOF
+  $ ${TURBO} run migrate --filter=util --filter=another > /dev/null 2>&1
+  [1]
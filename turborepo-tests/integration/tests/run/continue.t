@@ -5,7 +5,7 @@ Run without --continue
   \xe2\x80\xa2 Packages in scope: my-app, other-app, some-lib (esc)
   \xe2\x80\xa2 Running build in 3 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  some-lib:build: cache miss, executing ab8c4a02e3facf55
+  some-lib:build: cache miss, executing a36aed21bd0d456e
   some-lib:build: 
   some-lib:build: > build
   some-lib:build: > exit 2
@@ -18,7 +18,7 @@ Run without --continue
   some-lib#build: command \(.*(\/|\\)apps(\/|\\)some-lib\) .*npm(?:\.cmd)? run build exited \(1\) (re)
   
    Tasks:    0 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    some-lib#build
   
@@ -31,7 +31,7 @@ Run without --continue, and with only errors.
   \xe2\x80\xa2 Packages in scope: my-app, other-app, some-lib (esc)
   \xe2\x80\xa2 Running build in 3 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  some-lib:build: cache miss, executing ab8c4a02e3facf55
+  some-lib:build: cache miss, executing a36aed21bd0d456e
   some-lib:build: 
   some-lib:build: > build
   some-lib:build: > exit 2
@@ -44,7 +44,7 @@ Run without --continue, and with only errors.
   some-lib#build: command \(.*\) .*npm(?:\.cmd)? run build exited \(1\) (re)
   
    Tasks:    0 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    some-lib#build
   
@@ -56,7 +56,7 @@ Run with --continue
   \xe2\x80\xa2 Packages in scope: my-app, other-app, some-lib (esc)
   \xe2\x80\xa2 Running build in 3 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  some-lib:build: cache miss, executing ab8c4a02e3facf55
+  some-lib:build: cache miss, executing a36aed21bd0d456e
   some-lib:build: 
   some-lib:build: > build
   some-lib:build: > exit 2
@@ -66,7 +66,7 @@ Run with --continue
   some-lib:build: npm ERR!   in workspace: some-lib 
   some-lib:build: npm ERR!   at location: (.*)(\/|\\)apps(\/|\\)some-lib  (re)
   some-lib:build: command finished with error, but continuing...
-  other-app:build: cache miss, executing babf2f6b1d6ace47
+  other-app:build: cache miss, executing a64f13cf99d48af8
   other-app:build: 
   other-app:build: > build
   other-app:build: > exit 3
@@ -80,7 +80,7 @@ Run with --continue
   other-app#build: command \((.*)(\/|\\)apps(\/|\\)other-app\) .*npm(?:\.cmd)? run build exited \(1\) (re)
   
    Tasks:    1 successful, 3 total
-  Cached:    0 cached, 3 total
+  Cached:    0/3 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    other-app#build, some-lib#build
   
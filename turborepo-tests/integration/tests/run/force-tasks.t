@@ -0,0 +1,33 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"build": {"outputs": []}, "lint": {"outputs": []}}}
+  > EOF
+  $ cat > apps/my-app/package.json <<EOF
+  > {"name": "my-app", "scripts": {"build": "echo building", "lint": "echo linting"}}
+  > EOF
+
+baseline to generate cache
+  $ ${TURBO} run build lint --output-logs=hash-only --filter=my-app | grep "my-app:"
+   WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
+   WARNING  no output files found for task my-app#lint. Please check your `outputs` key in `turbo.json`
+  my-app:build: cache miss, executing [0-9a-f]+ (re)
+  my-app:lint: cache miss, executing [0-9a-f]+ (re)
+
+Forcing build only bypasses the cache for build
+  $ ${TURBO} run build lint --output-logs=hash-only --filter=my-app --force=build | grep "my-app:"
+   WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
+  my-app:build: cache bypass, force executing [0-9a-f]+ (re)
+  my-app:lint: cache hit, suppressing logs [0-9a-f]+ (re)
+
+Tasks can be forced by id or glob
+  $ ${TURBO} run build lint --output-logs=hash-only --filter=my-app --force="my-app#l*" | grep "my-app:"
+   WARNING  no output files found for task my-app#lint. Please check your `outputs` key in `turbo.json`
+  my-app:build: cache hit, suppressing logs [0-9a-f]+ (re)
+  my-app:lint: cache bypass, force executing [0-9a-f]+ (re)
+
+Forcing a task overrides TURBO_FORCE for the other tasks
+  $ TURBO_FORCE=true ${TURBO} run build lint --output-logs=hash-only --filter=my-app --force=build | grep "my-app:"
+   WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
+  my-app:build: cache bypass, force executing [0-9a-f]+ (re)
+  my-app:lint: cache hit, suppressing logs [0-9a-f]+ (re)
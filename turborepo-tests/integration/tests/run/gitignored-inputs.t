@@ -17,7 +17,7 @@ Some helper functions to parse the summary file
 Just run the util package, it's simpler
   $ ${TURBO} run build --filter=util --output-logs=hash-only --summarize | grep "util:build: cache"
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
-  util:build: cache miss, executing 11cff3dd389fdfed
+  util:build: cache miss, executing fba4dde9ece1f46f
 
   $ FIRST=$(/bin/ls .turbo/runs/*.json | head -n1)
   $ echo $(getSummaryTaskId $FIRST "util#build") | jq -r '.inputs."internal.txt"'
@@ -32,7 +32,7 @@ Change the content of internal.txt
 Hash does not change, because it is gitignored
   $ ${TURBO} run build --filter=util --output-logs=hash-only --summarize | grep "util:build: cache"
    WARNING  no output files found for task util#build. Please check your `outputs` key in `turbo.json`
-  util:build: cache miss, executing a489883a3c7cd307
+  util:build: cache miss, executing 5fe59f295232f9bf
 
 The internal.txt hash should be different from the one before
   $ SECOND=$(/bin/ls .turbo/runs/*.json | head -n1)
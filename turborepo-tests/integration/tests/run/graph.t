@@ -24,6 +24,9 @@ Graph to stdout
   
   .*Generated task graph in .*graph\.html.* (re)
   $ cat graph.html | grep --quiet "DOCTYPE"
+  $ cat graph.html | grep -o "\"[^\"]*\" -> \"[^\"]*\""
+  "[root] my-app#build" -> "[root] util#build"
+  "[root] util#build" -> "[root] ___ROOT___"
 
   $ ${TURBO} build -F my-app --graph=graph.mermaid
   
@@ -0,0 +1,16 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh monorepo_dependency_error
+  $ for i in 1 2 3 4 5; do
+  >   mkdir -p apps/broken-$i
+  >   echo "{\"name\": \"broken-$i\", \"scripts\": {\"build\": \"exit 1\"}}" > apps/broken-$i/package.json
+  > done
+
+The run stops once two tasks have failed
+  $ ${TURBO} build --filter="broken-*" --concurrency=1 --max-failures=2 > out.log 2>&1
+  [1]
+  $ grep "Tasks:" out.log
+   Tasks:    0 successful, 5 total
+  $ grep "Failed:" out.log
+  Failed:    broken-\d#build, broken-\d#build (re)
+  $ grep -c "maximum number of failures" out.log
+  1
@@ -0,0 +1,23 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+  $ cat > apps/my-app/package.json <<EOF
+  > {"name": "my-app", "scripts": {"build": "echo depth \$TURBO_INVOCATION_DEPTH"}, "dependencies": {"util": "*"}}
+  > EOF
+
+Tasks are told how deeply they are nested
+  $ ${TURBO} run build --filter=my-app --log-prefix=none 2>&1 | grep "^depth"
+  depth 1
+
+A nested turbo tells its tasks they're nested one level further
+  $ TURBO_INVOCATION_DEPTH=1 ${TURBO} run build --filter=my-app --log-prefix=none --force 2>&1 | grep "^depth"
+  depth 2
+
+Nesting more than one level deep warns
+  $ TURBO_INVOCATION_DEPTH=2 ${TURBO} run build --filter=my-app 2>&1 | grep "WARNING"
+   WARNING  turbo is running inside of 2 other turbo runs, a task might be invoking turbo recursively
+
+--no-nested fails on any nesting
+  $ TURBO_INVOCATION_DEPTH=1 ${TURBO} run build --filter=my-app --no-nested
+    x turbo was run from inside a task and --no-nested was passed
+  
+  [1]
@@ -0,0 +1,18 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+
+A filter that matches nothing is an error
+  $ ${TURBO} run build --filter="nothing-*"
+    x No packages found matching nothing-*
+  
+  [1]
+
+Filtering by changes can select nothing
+  $ ${TURBO} run build --filter="[HEAD]" --output-logs none > /dev/null
+
+A workspace without packages is a different error
+  $ rm -rf apps packages
+  $ ${TURBO} run build > out.txt 2>&1
+  [1]
+  $ grep "x No packages" out.txt
+    x No packages found in the workspace
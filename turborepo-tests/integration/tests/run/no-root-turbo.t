@@ -14,7 +14,7 @@ Run with --root-turbo-json should use specified config
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache miss, executing 0555ce94ca234049
+  my-app:build: cache miss, executing d61cd49c85806c74
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -22,7 +22,7 @@ Run with --root-turbo-json should use specified config
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
@@ -32,7 +32,7 @@ Run with TURBO_ROOT_TURBO_JSON should use specified config
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running build in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:build: cache hit, replaying logs 0555ce94ca234049
+  my-app:build: cache hit, replaying logs d61cd49c85806c74
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -40,7 +40,7 @@ Run with TURBO_ROOT_TURBO_JSON should use specified config
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 
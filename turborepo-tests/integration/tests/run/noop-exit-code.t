@@ -0,0 +1,64 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh monorepo_with_root_dep pnpm@7.25.1
+  $ cat > packages/shared/package.json <<EOF
+  > {"name": "shared", "scripts": {"gen": "exit \$(cat exit-code)"}}
+  > EOF
+  $ echo 0 > packages/shared/exit-code
+  $ echo ".turbo" > .gitignore
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"shared#gen": {"noopExitCode": 78}, "web#build": {"dependsOn": ["shared#gen"]}}}
+  > EOF
+
+The first run does something
+  $ ${TURBO} run build --filter=web --output-logs=hash-only | grep -E "(shared|web):"
+   WARNING  no output files found for task shared#gen. Please check your `outputs` key in `turbo.json`
+   WARNING  no output files found for task web#build. Please check your `outputs` key in `turbo.json`
+  shared:gen: cache miss, executing [0-9a-f]+ (re)
+  web:build: cache miss, executing [0-9a-f]+ (re)
+
+A task exiting with its noop exit code succeeds
+  $ echo 78 > packages/shared/exit-code
+  $ ${TURBO} run build --filter=web --output-logs=hash-only --summarize > out.txt
+  $ grep -E "(shared|web):" out.txt
+  shared:gen: cache miss, executing [0-9a-f]+ (re)
+  web:build: cache hit, suppressing logs [0-9a-f]+ (re)
+  $ grep "Failed:" out.txt
+  [1]
+  $ source "$TESTDIR/../../../helpers/run_summary.sh"
+  $ SUMMARY=$(/bin/ls .turbo/runs/*.json | head -n1)
+  $ echo $(getSummaryTaskId $SUMMARY "shared#gen") | jq -c '.execution | {exitCode, noop}'
+  {"exitCode":78,"noop":true}
+  $ echo $(getSummaryTaskId $SUMMARY "shared#gen") | jq '.resolvedTaskDefinition.noopExitCode'
+  78
+  $ echo $(getSummaryTaskId $SUMMARY "web#build") | jq '.resolvedTaskDefinition | has("noopExitCode")'
+  false
+  $ rm -r .turbo/runs
+
+Dependents treat the inputs of a task that had nothing to do as unchanged
+  $ echo "change" > packages/shared/new.txt
+  $ ${TURBO} run build --filter=web --output-logs=hash-only | grep -E "(shared|web):"
+  shared:gen: cache miss, executing [0-9a-f]+ (re)
+  web:build: cache hit, suppressing logs [0-9a-f]+ (re)
+
+Dependents run again once the task does something
+  $ echo 0 > packages/shared/exit-code
+  $ ${TURBO} run build --filter=web --output-logs=hash-only | grep -E "(shared|web):"
+   WARNING  no output files found for task shared#gen. Please check your `outputs` key in `turbo.json`
+   WARNING  no output files found for task web#build. Please check your `outputs` key in `turbo.json`
+  shared:gen: cache miss, executing [0-9a-f]+ (re)
+  web:build: cache miss, executing [0-9a-f]+ (re)
+
+Without a noop exit code the task fails
+  $ echo 78 > packages/shared/exit-code
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"shared#gen": {}, "web#build": {"dependsOn": ["shared#gen"]}}}
+  > EOF
+  $ ${TURBO} run build --filter=web > /dev/null 2>&1
+  [78]
+
+A noop exit code of 0 is rejected
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"shared#gen": {"noopExitCode": 0}}}
+  > EOF
+  $ ${TURBO} run build --filter=web > /dev/null 2>&1
+  [1]
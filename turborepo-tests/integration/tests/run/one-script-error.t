@@ -7,13 +7,13 @@ Note that npm reports any failed script as exit code 1, even though we "exit 2"
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running error in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:okay: cache miss, executing 61545c7738a28273
+  my-app:okay: cache miss, executing 8bc634c58c529b46
   my-app:okay: 
   my-app:okay: > okay
   my-app:okay: > echo working
   my-app:okay: 
   my-app:okay: working
-  my-app:error: cache miss, executing fe9f1118e6072fce
+  my-app:error: cache miss, executing b6bca991cf965f55
   my-app:error: 
   my-app:error: > error
   my-app:error: > exit 2
@@ -26,7 +26,7 @@ Note that npm reports any failed script as exit code 1, even though we "exit 2"
   my-app#error: command \(.*apps(\/|\\)my-app\) (.*)npm(?:\.cmd)? run error exited \(1\) (re)
   
    Tasks:    1 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    my-app#error
   
@@ -39,13 +39,13 @@ Make sure error isn't cached
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running error in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:okay: cache hit, replaying logs 61545c7738a28273
+  my-app:okay: cache hit, replaying logs 8bc634c58c529b46
   my-app:okay: 
   my-app:okay: > okay
   my-app:okay: > echo working
   my-app:okay: 
   my-app:okay: working
-  my-app:error: cache miss, executing fe9f1118e6072fce
+  my-app:error: cache miss, executing b6bca991cf965f55
   my-app:error: 
   my-app:error: > error
   my-app:error: > exit 2
@@ -58,7 +58,7 @@ Make sure error isn't cached
   my-app#error: command \(.*apps(\/|\\)my-app\) (.*)npm(?:\.cmd)? run error exited \(1\) (re)
   
    Tasks:    1 successful, 2 total
-  Cached:    1 cached, 2 total
+  Cached:    1/2 tasks cached (50% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    my-app#error
   
@@ -70,13 +70,13 @@ Make sure error code isn't swallowed with continue
   \xe2\x80\xa2 Packages in scope: my-app (esc)
   \xe2\x80\xa2 Running okay2 in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  my-app:okay: cache hit, replaying logs 61545c7738a28273
+  my-app:okay: cache hit, replaying logs 8bc634c58c529b46
   my-app:okay: 
   my-app:okay: > okay
   my-app:okay: > echo working
   my-app:okay: 
   my-app:okay: working
-  my-app:error: cache miss, executing fe9f1118e6072fce
+  my-app:error: cache miss, executing b6bca991cf965f55
   my-app:error: 
   my-app:error: > error
   my-app:error: > exit 2
@@ -86,7 +86,7 @@ Make sure error code isn't swallowed with continue
   my-app:error: npm ERR!   in workspace: my-app 
   my-app:error: npm ERR!   at location: .*apps(\/|\\)my-app  (re)
   my-app:error: command finished with error, but continuing...
-  my-app:okay2: cache miss, executing a24768ab20e24dd3
+  my-app:okay2: cache miss, executing 536695e0d2830b6f
   my-app:okay2: 
   my-app:okay2: > okay2
   my-app:okay2: > echo working
@@ -95,7 +95,7 @@ Make sure error code isn't swallowed with continue
   my-app#error: command \((.*)(\/|\\)apps(\/|\\)my-app\) (.*)npm(?:\.cmd)? run error exited \(1\) (re)
   
    Tasks:    2 successful, 3 total
-  Cached:    1 cached, 3 total
+  Cached:    1/3 tasks cached (33% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   Failed:    my-app#error
   
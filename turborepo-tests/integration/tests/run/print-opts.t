@@ -0,0 +1,30 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+
+Print the resolved options without running tasks
+  $ ${TURBO} run build --print-opts | jq '.runOpts.tasks'
+  [
+    "build"
+  ]
+  $ test -d .turbo/cache
+  [1]
+
+Cache options use the same casing as the rest of the options
+  $ ${TURBO} run build --print-opts | jq -c '.cacheOpts.remoteCacheOpts'
+  {"unusedTeamId":null,"signature":false}
+
+The default env mode is strict
+  $ ${TURBO} run build --print-opts | jq -r '.runOpts.envMode'
+  strict
+
+Flags override the default
+  $ ${TURBO} run build --print-opts --env-mode=loose --concurrency=3 | jq -c '.runOpts | [.envMode, .concurrency]'
+  ["loose",3]
+
+As do environment variables
+  $ TURBO_ENV_MODE=loose ${TURBO} run build --print-opts | jq -r '.runOpts.envMode'
+  loose
+
+Flags take precedence over environment variables
+  $ TURBO_ENV_MODE=loose ${TURBO} run build --print-opts --env-mode=strict | jq -r '.runOpts.envMode'
+  strict
@@ -0,0 +1,36 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+  $ cat > apps/my-app/package.json <<EOF
+  > {"name": "my-app", "scripts": {"build": "echo building"}}
+  > EOF
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"build": {"outputs": [], "cache": "readonly"}}}
+  > EOF
+
+A readonly task does not write to the cache on a miss
+  $ ${TURBO} run build --output-logs=hash-only --filter=my-app | grep "my-app:build: cache"
+  my-app:build: cache miss, executing [0-9a-f]+ (re)
+  $ ${TURBO} run build --output-logs=hash-only --filter=my-app | grep "my-app:build: cache"
+  my-app:build: cache miss, executing [0-9a-f]+ (re)
+
+Populate the cache with a cacheable run
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"build": {"outputs": []}}}
+  > EOF
+  $ ${TURBO} run build --output-logs=hash-only --filter=my-app | grep "my-app:build: cache"
+   WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
+  my-app:build: cache miss, executing [0-9a-f]+ (re)
+
+A readonly task restores from the cache on a hit
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"build": {"outputs": [], "cache": "readonly"}}}
+  > EOF
+  $ ${TURBO} run build --output-logs=hash-only --filter=my-app | grep "my-app:build: cache"
+  my-app:build: cache hit, suppressing logs [0-9a-f]+ (re)
+
+Other values are rejected
+  $ cat > turbo.json <<EOF
+  > {"tasks": {"build": {"cache": "sometimes"}}}
+  > EOF
+  $ ${TURBO} run build --filter=my-app > /dev/null 2>&1
+  [1]
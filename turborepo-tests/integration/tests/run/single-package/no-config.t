@@ -20,7 +20,7 @@ Check
   Tasks to Run
   build
     Task                           = build\s* (re)
-    Hash                           = e2b99dad85a4ff66
+    Hash                           = 1b472efbe24399cb
     Cached \(Local\)                 = false\s* (re)
     Cached \(Remote\)                = false\s* (re)
     Command                        = echo building > foo.txt\s* (re)
@@ -34,7 +34,7 @@ Check
     Inferred Env Vars Values       = 
     Passed Through Env Vars        = 
     Passed Through Env Vars Values = 
-    Resolved Task Definition       = {"outputs":[],"cache":false,"dependsOn":[],"inputs":[],"outputLogs":"full","persistent":false,"env":[],"passThroughEnv":null,"interactive":false}
+    Resolved Task Definition       = {"outputs":[],"cache":false,"cacheReadonly":false,"dependsOn":[],"inputs":[],"outputLogs":"full","persistent":false,"env":[],"passThroughEnv":null,"interactive":false}
     Framework                      = 
 
   $ ${TURBO} run build --graph
@@ -51,27 +51,27 @@ Run real once
   $ ${TURBO} run build
   \xe2\x80\xa2 Running build (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache bypass, force executing e2b99dad85a4ff66
+  build: cache bypass, force executing 1b472efbe24399cb
   build: 
   build: > build
   build: > echo building > foo.txt
   build: 
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
 Run a second time, verify no caching because there is no config
   $ ${TURBO} run build
   \xe2\x80\xa2 Running build (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache bypass, force executing e2b99dad85a4ff66
+  build: cache bypass, force executing 1b472efbe24399cb
   build: 
   build: > build
   build: > echo building > foo.txt
   build: 
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
\ No newline at end of file
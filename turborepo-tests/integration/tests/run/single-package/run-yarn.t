@@ -5,26 +5,26 @@ Check
   $ ${TURBO} run build
   \xe2\x80\xa2 Running build (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache miss, executing a6fef17cc2efc81c
+  build: cache miss, executing 945e59f27fc6fd88
   build: yarn run v1.22.17
   build: warning package.json: No license field
   build: $ echo building > foo.txt
   build: Done in \s*[\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
   $ ${TURBO} run build
   \xe2\x80\xa2 Running build (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache hit, replaying logs a6fef17cc2efc81c
+  build: cache hit, replaying logs 945e59f27fc6fd88
   build: yarn run v1.22.17
   build: warning package.json: No license field
   build: $ echo building > foo.txt
   build: Done in \s*[\.0-9]+m?s\. (re)
   
    Tasks:    1 successful, 1 total
-  Cached:    1 cached, 1 total
+  Cached:    1/1 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
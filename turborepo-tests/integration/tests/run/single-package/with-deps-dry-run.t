@@ -18,7 +18,7 @@ Check
   Tasks to Run
   build
     Task                           = build\s* (re)
-    Hash                           = 7ece7b62aad25615
+    Hash                           = 557df624384d551d
     Cached \(Local\)                 = false\s* (re)
     Cached \(Remote\)                = false\s* (re)
     Command                        = echo building > foo.txt\s* (re)
@@ -32,11 +32,11 @@ Check
     Inferred Env Vars Values       = 
     Passed Through Env Vars        = 
     Passed Through Env Vars Values = 
-    Resolved Task Definition       = {"outputs":["foo.txt"],"cache":true,"dependsOn":[],"inputs":[],"outputLogs":"full","persistent":false,"env":[],"passThroughEnv":null,"interactive":false}
+    Resolved Task Definition       = {"outputs":["foo.txt"],"cache":true,"cacheReadonly":false,"dependsOn":[],"inputs":[],"outputLogs":"full","persistent":false,"env":[],"passThroughEnv":null,"interactive":false}
     Framework                      = 
   test
     Task                           = test\s* (re)
-    Hash                           = cb5839f7284aa5f3
+    Hash                           = e29067605819d7c5
     Cached \(Local\)                 = false\s* (re)
     Cached \(Remote\)                = false\s* (re)
     Command                        = cat foo.txt\s* (re)
@@ -50,5 +50,5 @@ Check
     Inferred Env Vars Values       = 
     Passed Through Env Vars        = 
     Passed Through Env Vars Values = 
-    Resolved Task Definition       = {"outputs":[],"cache":true,"dependsOn":["build"],"inputs":[],"outputLogs":"full","persistent":false,"env":[],"passThroughEnv":null,"interactive":false}
+    Resolved Task Definition       = {"outputs":[],"cache":true,"cacheReadonly":false,"dependsOn":["build"],"inputs":[],"outputLogs":"full","persistent":false,"env":[],"passThroughEnv":null,"interactive":false}
     Framework                      = 
@@ -5,12 +5,12 @@ Check
   $ ${TURBO} run test
   \xe2\x80\xa2 Running test (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache miss, executing 7ece7b62aad25615
+  build: cache miss, executing 557df624384d551d
   build: 
   build: > build
   build: > echo building > foo.txt
   build: 
-  test: cache miss, executing cb5839f7284aa5f3
+  test: cache miss, executing e29067605819d7c5
   test: 
   test: > test
   test: > cat foo.txt
@@ -18,7 +18,7 @@ Check
   test: building
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task //#test. Please check your `outputs` key in `turbo.json`
@@ -26,12 +26,12 @@ Run a second time, verify caching works because there is a config
   $ ${TURBO} run test
   \xe2\x80\xa2 Running test (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache hit, replaying logs 7ece7b62aad25615
+  build: cache hit, replaying logs 557df624384d551d
   build: 
   build: > build
   build: > echo building > foo.txt
   build: 
-  test: cache hit, replaying logs cb5839f7284aa5f3
+  test: cache hit, replaying logs e29067605819d7c5
   test: 
   test: > test
   test: > cat foo.txt
@@ -39,18 +39,18 @@ Run a second time, verify caching works because there is a config
   test: building
   
    Tasks:    2 successful, 2 total
-  Cached:    2 cached, 2 total
+  Cached:    2/2 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 Run with --output-logs=hash-only
   $ ${TURBO} run test --output-logs=hash-only
   \xe2\x80\xa2 Running test (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  build: cache hit, suppressing logs 7ece7b62aad25615
-  test: cache hit, suppressing logs cb5839f7284aa5f3
+  build: cache hit, suppressing logs 557df624384d551d
+  test: cache hit, suppressing logs e29067605819d7c5
   
    Tasks:    2 successful, 2 total
-  Cached:    2 cached, 2 total
+  Cached:    2/2 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 Run with --output-logs=errors-only
@@ -59,7 +59,7 @@ Run with --output-logs=errors-only
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    2 successful, 2 total
-  Cached:    2 cached, 2 total
+  Cached:    2/2 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
 Run with --output-logs=none
@@ -68,6 +68,6 @@ Run with --output-logs=none
   \xe2\x80\xa2 Remote caching disabled (esc)
   
    Tasks:    2 successful, 2 total
-  Cached:    2 cached, 2 total
+  Cached:    2/2 tasks cached (100% hit rate)
     Time:\s*[\.0-9]+m?s >>> FULL TURBO (re)
   
@@ -0,0 +1,31 @@
+Setup
+  $ . ${TESTDIR}/../../../helpers/setup_integration_test.sh
+
+Hashes are printed as JSON without running any tasks
+  $ ${TURBO} run build --filter=my-app --summarize-only > first.json
+  $ jq -r 'keys[]' first.json
+  globalHash
+  tasks
+  $ jq -r '.globalHash' first.json
+  [0-9a-f]+ (re)
+  $ jq -r '.tasks | keys[]' first.json
+  my-app#build
+  $ jq -r '.tasks."my-app#build"' first.json
+  [0-9a-f]+ (re)
+  $ test -e apps/my-app/.turbo/turbo-build.log
+  [1]
+
+Hashes are the same for an unchanged tree
+  $ ${TURBO} run build --filter=my-app --summarize-only > second.json
+  $ diff first.json second.json
+
+Changing an input changes the task hash
+  $ echo "changed" > apps/my-app/new.txt
+  $ ${TURBO} run build --filter=my-app --summarize-only > third.json
+  $ test "$(jq -r '.globalHash' first.json)" = "$(jq -r '.globalHash' third.json)"
+  $ test "$(jq -r '.tasks."my-app#build"' first.json)" = "$(jq -r '.tasks."my-app#build"' third.json)"
+  [1]
+
+Summarizing can't be combined with a dry run
+  $ ${TURBO} run build --summarize-only --dry > /dev/null 2>&1
+  [1]
@@ -15,7 +15,7 @@ which does not have a name should be ignored. We should process it but filter.
   my-app:build: building
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:    (.+)  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
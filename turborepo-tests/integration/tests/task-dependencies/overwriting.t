@@ -14,7 +14,7 @@ Test
 
 # workspace-a#generate ran
   $ cat tmp.log | grep "workspace-a:generate"
-  workspace-a:generate: cache miss, executing 9177da2f682aeeb7
+  workspace-a:generate: cache miss, executing df1b8c389d5b661c
   workspace-a:generate: 
   workspace-a:generate: > generate
   workspace-a:generate: > echo generate-workspace-a
@@ -22,7 +22,7 @@ Test
   workspace-a:generate: generate-workspace-a
 workspace-a#build ran
   $ cat tmp.log | grep "workspace-a:build"
-  workspace-a:build: cache miss, executing f6fe0ec4a3ecb04b
+  workspace-a:build: cache miss, executing e8bb394ab70edac4
   workspace-a:build: 
   workspace-a:build: > build
   workspace-a:build: > echo build-workspace-a
@@ -35,7 +35,7 @@ workspace-b#generate DID NOT run
 
 workspace-b#build ran
   $ cat tmp.log | grep "workspace-b:build"
-  workspace-b:build: cache miss, executing b0cbf81d4cce38a4
+  workspace-b:build: cache miss, executing 819166603e665384
   workspace-b:build: 
   workspace-b:build: > build
   workspace-b:build: > echo build-workspace-b
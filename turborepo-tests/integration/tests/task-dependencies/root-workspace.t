@@ -5,13 +5,13 @@ This tests asserts that root tasks can depend on workspace#task
   \xe2\x80\xa2 Packages in scope: //, lib-a (esc)
   \xe2\x80\xa2 Running mytask in 2 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  lib-a:build: cache miss, executing eddc59f63124ca26
+  lib-a:build: cache miss, executing dc278d272ab2839f
   lib-a:build: 
   lib-a:build: > build
   lib-a:build: > echo build-lib-a
   lib-a:build: 
   lib-a:build: build-lib-a
-  //:mytask: cache miss, executing 82474628cac2e34c
+  //:mytask: cache miss, executing ba46d80841b713d6
   //:mytask: 
   //:mytask: > mytask
   //:mytask: > echo root-mytask
@@ -19,7 +19,7 @@ This tests asserts that root tasks can depend on workspace#task
   //:mytask: root-mytask
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9ms]+  (re)
   
    WARNING  no output files found for task //#mytask. Please check your `outputs` key in `turbo.json`
@@ -6,13 +6,13 @@ Check my-app#build output
   \xe2\x80\xa2 Packages in scope: //, my-app, util (esc)
   \xe2\x80\xa2 Running build in 3 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  util:build: cache miss, executing 3d1f83b683510099
+  util:build: cache miss, executing 49cbfa8c7757e9f5
   util:build: 
   util:build: > build
   util:build: > echo building
   util:build: 
   util:build: building
-  my-app:build: cache miss, executing a24fb33a97cce572
+  my-app:build: cache miss, executing 6ae851b48be624b8
   my-app:build: 
   my-app:build: > build
   my-app:build: > echo building
@@ -20,7 +20,7 @@ Check my-app#build output
   my-app:build: building
   
    Tasks:    2 successful, 2 total
-  Cached:    0 cached, 2 total
+  Cached:    0/2 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task my-app#build. Please check your `outputs` key in `turbo.json`
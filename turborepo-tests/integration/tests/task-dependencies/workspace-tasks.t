@@ -4,6 +4,8 @@ Setup
 
 Test that root tasks are included in the graph. In this case, "//#build" task should be there
   $ ${TURBO} run build1 --graph
+   WARNING  task `build1` is configured in turbo.json but there is no `build1` script for it to run
+   WARNING  task `//#build1` is configured in turbo.json but there is no `build1` script for it to run
   
   digraph {
   \tcompound = "true" (esc)
@@ -20,6 +22,7 @@ Test that root tasks are included in the graph. In this case, "//#build" task sh
 
 Can depend on root tasks
   $ ${TURBO} run build2 --graph
+   WARNING  task `build1` is configured in turbo.json but there is no `build1` script for it to run
   
   digraph {
   \tcompound = "true" (esc)
@@ -42,6 +45,7 @@ Can't depend on a missing root task
 
 Package tasks can depend on things
   $ ${TURBO} run special --graph
+   WARNING  task `build1` is configured in turbo.json but there is no `build1` script for it to run
   
   digraph {
   \tcompound = "true" (esc)
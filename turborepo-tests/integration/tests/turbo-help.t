@@ -9,6 +9,7 @@ Test help flag
   
   Commands:
     bin         Get the path to the Turbo binary
+    cache       Inspect and maintain the local cache
     completion  Generate the autocompletion script for the specified shell
     daemon      Runs the Turborepo background daemon
     generate    Generate a new app / package
@@ -22,6 +23,7 @@ Test help flag
     run         Run tasks across projects in your monorepo
     watch       Arguments used in run and watch
     unlink      Unlink the current directory from your Vercel organization and disable Remote Caching
+    validate    Check turbo.json for mistakes without running any tasks
   
   Options:
         --version
@@ -39,7 +41,7 @@ Test help flag
         --heap <HEAP>
             Specify a file to save a pprof heap profile
         --ui <UI>
-            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web]
+            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web, compact]
         --login <LOGIN>
             Override the login endpoint
         --no-color
@@ -52,10 +54,16 @@ Test help flag
             Set the team slug for API calls
         --token <TOKEN>
             Set the auth token for API calls
+        --token-file <TOKEN_FILE>
+            Read the auth token for API calls from a file
         --trace <TRACE>
             Specify a file to save a pprof trace
         --verbosity <COUNT>
             Verbosity level
+        --log-format <LOG_FORMAT>
+            Set the format of turbo's own log messages. Use "json" to write each message as a JSON object on its own line [possible values: text, json]
+        --strict-package-manager
+            Fail instead of warning when the package manager running turbo doesn't match the `packageManager` field of package.json
         --dangerously-disable-package-manager-check
             Allow for missing `packageManager` in `package.json`
         --root-turbo-json <ROOT_TURBO_JSON>
@@ -66,6 +74,8 @@ Test help flag
   Run Arguments:
         --cache-workers <CACHE_WORKERS>
             Set the number of concurrent cache operations (default 10) [default: 10]
+        --hash-workers <HASH_WORKERS>
+            Set the number of threads used to hash files (defaults to the number of CPUs)
         --dry-run [<DRY_RUN>]
             [possible values: text, json]
         --graph [<GRAPH>]
@@ -84,28 +94,44 @@ Test help flag
             Treat remote cache as read only [possible values: true, false]
         --summarize [<SUMMARIZE>]
             Generate a summary of the turbo run [possible values: true, false]
+        --summarize-only
+            Print the global hash and the hash of each task as JSON without running tasks or using the cache
+        --print-opts
+            Print the options resolved from the command line, configuration and environment as JSON without running tasks
+        --csv-output <CSV_OUTPUT>
+            Write the duration and cache status of each task to a CSV file
         --parallel
             Execute all tasks in parallel
+        --restart-on-change
+            Restart persistent tasks when their inputs change
+        --no-nested
+            Fail instead of continuing when run from inside a task of another turbo run
         --cache-dir <CACHE_DIR>
-            Override the filesystem cache directory
+            Override the filesystem cache directory. Pass it again to add read-only cache directories that are checked before the remote cache, these can't be set with TURBO_CACHE_DIR
         --concurrency <CONCURRENCY>
             Limit the concurrency of task execution. Use 1 for serial (i.e. one-at-a-time) execution
         --continue
             Continue execution even if a task exits with an error or non-zero exit code. The default behavior is to bail
+        --max-failures <MAX_FAILURES>
+            Stop running tasks once this many have failed. Implies --continue
         --single-package
             Run turbo in single-package mode
         --force [<FORCE>]
-            Ignore the existing cache (to force execution) [possible values: true, false]
+            Ignore the existing cache (to force execution). Pass a task name or glob to only force matching tasks
         --framework-inference [<BOOL>]
             Specify whether or not to do framework inference for tasks [default: true] [possible values: true, false]
         --global-deps <GLOBAL_DEPS>
             Specify glob of global filesystem dependencies to be hashed. Useful for .env and files
         --env-mode [<ENV_MODE>]
-            Environment variable mode. Use "loose" to pass the entire existing environment. Use "strict" to use an allowlist specified in turbo.json [possible values: loose, strict]
+            Environment variable mode. Use "loose" to pass the entire existing environment. Use "strict" to use an allowlist specified in turbo.json. Use "loose-warn" to behave like "loose" while warning about environment variables that aren't in the allowlist [possible values: loose, strict, loose-warn]
+        --environment <ENVIRONMENT>
+            Apply the task overrides of the given entry in the `environments` section of turbo.json
     -F, --filter <FILTER>
             Use the given selector to specify package(s) to act as entry points. The syntax mirrors pnpm's syntax, and additional documentation and examples can be found in turbo's documentation https://turbo.build/repo/docs/reference/command-line-reference/run#--filter
         --affected
             Run only tasks that are affected by changes between the current branch and `main`
+        --changed-files-from <PATH>
+            Run only tasks that are affected by the files listed in the given file, one path per line relative to the repository root. Use "-" to read the list from stdin. Changes are not computed with git
         --output-logs <OUTPUT_LOGS>
             Set type of process output logging. Use "full" to show all output. Use "hash-only" to show only turbo-computed task hashes. Use "new-only" to show only new output with only hashes for cached tasks. Use "none" to hide process output. (default full) [possible values: full, none, hash-only, new-only, errors-only]
         --log-order <LOG_ORDER>
@@ -116,6 +142,8 @@ Test help flag
             Ignore the local filesystem cache for all tasks. Only allow reading and caching artifacts using the remote cache [possible values: true, false]
         --log-prefix <LOG_PREFIX>
             Use "none" to remove prefixes from task logs. Use "task" to get task id prefixing. Use "auto" to let turbo decide how to prefix the logs based on the execution environment. In most cases this will be the same as "task". Note that tasks running in parallel interleave their logs, so removing prefixes can make it difficult to associate logs with tasks. Use --log-order=grouped to prevent interleaving. (default auto) [default: auto] [possible values: auto, none, task]
+        --no-color-logs
+            Remove color codes from task output written to log files, and from task output written to stdout when it isn't a terminal
 
 
 
@@ -129,6 +157,7 @@ Test help flag
   
   Commands:
     bin         Get the path to the Turbo binary
+    cache       Inspect and maintain the local cache
     completion  Generate the autocompletion script for the specified shell
     daemon      Runs the Turborepo background daemon
     generate    Generate a new app / package
@@ -142,6 +171,7 @@ Test help flag
     run         Run tasks across projects in your monorepo
     watch       Arguments used in run and watch
     unlink      Unlink the current directory from your Vercel organization and disable Remote Caching
+    validate    Check turbo.json for mistakes without running any tasks
   
   Options:
         --version
@@ -169,9 +199,10 @@ Test help flag
             Specify whether to use the streaming UI or TUI
   
             Possible values:
-            - tui:    Use the terminal user interface
-            - stream: Use the standard output stream
-            - web:    Use the web user interface (experimental)
+            - tui:     Use the terminal user interface
+            - stream:  Use the standard output stream
+            - web:     Use the web user interface (experimental)
+            - compact: Use a compact view with one line per running task
   
         --login <LOGIN>
             Override the login endpoint
@@ -191,12 +222,23 @@ Test help flag
         --token <TOKEN>
             Set the auth token for API calls
   
+        --token-file <TOKEN_FILE>
+            Read the auth token for API calls from a file
+  
         --trace <TRACE>
             Specify a file to save a pprof trace
   
         --verbosity <COUNT>
             Verbosity level
   
+        --log-format <LOG_FORMAT>
+            Set the format of turbo's own log messages. Use "json" to write each message as a JSON object on its own line
+            
+            [possible values: text, json]
+  
+        --strict-package-manager
+            Fail instead of warning when the package manager running turbo doesn't match the `packageManager` field of package.json
+  
         --dangerously-disable-package-manager-check
             Allow for missing `packageManager` in `package.json`.
             
@@ -214,6 +256,9 @@ Test help flag
             
             [default: 10]
   
+        --hash-workers <HASH_WORKERS>
+            Set the number of threads used to hash files (defaults to the number of CPUs)
+  
         --dry-run [<DRY_RUN>]
             [possible values: text, json]
   
@@ -245,11 +290,26 @@ Test help flag
             
             [possible values: true, false]
   
+        --summarize-only
+            Print the global hash and the hash of each task as JSON without running tasks or using the cache
+  
+        --print-opts
+            Print the options resolved from the command line, configuration and environment as JSON without running tasks
+  
+        --csv-output <CSV_OUTPUT>
+            Write the duration and cache status of each task to a CSV file
+  
         --parallel
             Execute all tasks in parallel
   
+        --restart-on-change
+            Restart persistent tasks when their inputs change
+  
+        --no-nested
+            Fail instead of continuing when run from inside a task of another turbo run
+  
         --cache-dir <CACHE_DIR>
-            Override the filesystem cache directory
+            Override the filesystem cache directory. Pass it again to add read-only cache directories that are checked before the remote cache, these can't be set with TURBO_CACHE_DIR
   
         --concurrency <CONCURRENCY>
             Limit the concurrency of task execution. Use 1 for serial (i.e. one-at-a-time) execution
@@ -257,13 +317,14 @@ Test help flag
         --continue
             Continue execution even if a task exits with an error or non-zero exit code. The default behavior is to bail
   
+        --max-failures <MAX_FAILURES>
+            Stop running tasks once this many have failed. Implies --continue
+  
         --single-package
             Run turbo in single-package mode
   
         --force [<FORCE>]
-            Ignore the existing cache (to force execution)
-            
-            [possible values: true, false]
+            Ignore the existing cache (to force execution). Pass a task name or glob to only force matching tasks
   
         --framework-inference [<BOOL>]
             Specify whether or not to do framework inference for tasks
@@ -275,9 +336,12 @@ Test help flag
             Specify glob of global filesystem dependencies to be hashed. Useful for .env and files
   
         --env-mode [<ENV_MODE>]
-            Environment variable mode. Use "loose" to pass the entire existing environment. Use "strict" to use an allowlist specified in turbo.json
+            Environment variable mode. Use "loose" to pass the entire existing environment. Use "strict" to use an allowlist specified in turbo.json. Use "loose-warn" to behave like "loose" while warning about environment variables that aren't in the allowlist
             
-            [possible values: loose, strict]
+            [possible values: loose, strict, loose-warn]
+  
+        --environment <ENVIRONMENT>
+            Apply the task overrides of the given entry in the `environments` section of turbo.json
   
     -F, --filter <FILTER>
             Use the given selector to specify package(s) to act as entry points. The syntax mirrors pnpm's syntax, and additional documentation and examples can be found in turbo's documentation https://turbo.build/repo/docs/reference/command-line-reference/run#--filter
@@ -285,6 +349,9 @@ Test help flag
         --affected
             Run only tasks that are affected by changes between the current branch and `main`
   
+        --changed-files-from <PATH>
+            Run only tasks that are affected by the files listed in the given file, one path per line relative to the repository root. Use "-" to read the list from stdin. Changes are not computed with git
+  
         --output-logs <OUTPUT_LOGS>
             Set type of process output logging. Use "full" to show all output. Use "hash-only" to show only turbo-computed task hashes. Use "new-only" to show only new output with only hashes for cached tasks. Use "none" to hide process output. (default full)
             
@@ -308,6 +375,9 @@ Test help flag
             
             [default: auto]
             [possible values: auto, none, task]
+  
+        --no-color-logs
+            Remove color codes from task output written to log files, and from task output written to stdout when it isn't a terminal
 
 Test help flag for link command
   $ ${TURBO} link -h
@@ -326,8 +396,12 @@ Test help flag for link command
             Specify what should be linked (default "remote cache") [default: remote-cache] [possible values: remote-cache, spaces]
         --no-update-notifier
             Disable the turbo update notification
+        --scope <SCOPE>
+            The slug of the team to link to. Skips the team selection prompt
         --api <API>
             Override the endpoint for API calls
+        --yes
+            Answer yes to all confirmation prompts. Requires --scope
         --color
             Force color usage in the terminal
         --cwd <CWD>
@@ -335,7 +409,7 @@ Test help flag for link command
         --heap <HEAP>
             Specify a file to save a pprof heap profile
         --ui <UI>
-            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web]
+            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web, compact]
         --login <LOGIN>
             Override the login endpoint
         --no-color
@@ -348,10 +422,16 @@ Test help flag for link command
             Set the team slug for API calls
         --token <TOKEN>
             Set the auth token for API calls
+        --token-file <TOKEN_FILE>
+            Read the auth token for API calls from a file
         --trace <TRACE>
             Specify a file to save a pprof trace
         --verbosity <COUNT>
             Verbosity level
+        --log-format <LOG_FORMAT>
+            Set the format of turbo's own log messages. Use "json" to write each message as a JSON object on its own line [possible values: text, json]
+        --strict-package-manager
+            Fail instead of warning when the package manager running turbo doesn't match the `packageManager` field of package.json
         --dangerously-disable-package-manager-check
             Allow for missing `packageManager` in `package.json`
         --root-turbo-json <ROOT_TURBO_JSON>
@@ -383,7 +463,7 @@ Test help flag for unlink command
         --heap <HEAP>
             Specify a file to save a pprof heap profile
         --ui <UI>
-            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web]
+            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web, compact]
         --login <LOGIN>
             Override the login endpoint
         --no-color
@@ -396,10 +476,16 @@ Test help flag for unlink command
             Set the team slug for API calls
         --token <TOKEN>
             Set the auth token for API calls
+        --token-file <TOKEN_FILE>
+            Read the auth token for API calls from a file
         --trace <TRACE>
             Specify a file to save a pprof trace
         --verbosity <COUNT>
             Verbosity level
+        --log-format <LOG_FORMAT>
+            Set the format of turbo's own log messages. Use "json" to write each message as a JSON object on its own line [possible values: text, json]
+        --strict-package-manager
+            Fail instead of warning when the package manager running turbo doesn't match the `packageManager` field of package.json
         --dangerously-disable-package-manager-check
             Allow for missing `packageManager` in `package.json`
         --root-turbo-json <ROOT_TURBO_JSON>
@@ -433,7 +519,7 @@ Test help flag for login command
         --heap <HEAP>
             Specify a file to save a pprof heap profile
         --ui <UI>
-            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web]
+            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web, compact]
         --login <LOGIN>
             Override the login endpoint
         --no-color
@@ -446,10 +532,16 @@ Test help flag for login command
             Set the team slug for API calls
         --token <TOKEN>
             Set the auth token for API calls
+        --token-file <TOKEN_FILE>
+            Read the auth token for API calls from a file
         --trace <TRACE>
             Specify a file to save a pprof trace
         --verbosity <COUNT>
             Verbosity level
+        --log-format <LOG_FORMAT>
+            Set the format of turbo's own log messages. Use "json" to write each message as a JSON object on its own line [possible values: text, json]
+        --strict-package-manager
+            Fail instead of warning when the package manager running turbo doesn't match the `packageManager` field of package.json
         --dangerously-disable-package-manager-check
             Allow for missing `packageManager` in `package.json`
         --root-turbo-json <ROOT_TURBO_JSON>
@@ -481,7 +573,7 @@ Test help flag for logout command
         --heap <HEAP>
             Specify a file to save a pprof heap profile
         --ui <UI>
-            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web]
+            Specify whether to use the streaming UI or TUI [possible values: tui, stream, web, compact]
         --login <LOGIN>
             Override the login endpoint
         --no-color
@@ -494,10 +586,16 @@ Test help flag for logout command
             Set the team slug for API calls
         --token <TOKEN>
             Set the auth token for API calls
+        --token-file <TOKEN_FILE>
+            Read the auth token for API calls from a file
         --trace <TRACE>
             Specify a file to save a pprof trace
         --verbosity <COUNT>
             Verbosity level
+        --log-format <LOG_FORMAT>
+            Set the format of turbo's own log messages. Use "json" to write each message as a JSON object on its own line [possible values: text, json]
+        --strict-package-manager
+            Fail instead of warning when the package manager running turbo doesn't match the `packageManager` field of package.json
         --dangerously-disable-package-manager-check
             Allow for missing `packageManager` in `package.json`
         --root-turbo-json <ROOT_TURBO_JSON>
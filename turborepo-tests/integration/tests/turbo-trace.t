@@ -58,3 +58,22 @@ Setup
     }
   }
 
+
+  $ echo 'import { Button } from "./button";' > other.ts
+  $ ${TURBO} query "query { file(path: \"button.tsx\") { path, dependents { path } } }"
+   WARNING  query command is experimental and may change in the future
+  {
+    "data": {
+      "file": {
+        "path": "button.tsx",
+        "dependents": [
+          {
+            "path": "main.ts"
+          },
+          {
+            "path": "other.ts"
+          }
+        ]
+      }
+    }
+  }
@@ -17,14 +17,14 @@ Setup
   \xe2\x80\xa2 Remote caching disabled (esc)
 
   $ cat tmp.log | grep "missing-workspace-config:missing-workspace-config-task-with-deps"
-  missing-workspace-config:missing-workspace-config-task-with-deps: cache miss, executing 27111702ff2c516b
+  missing-workspace-config:missing-workspace-config-task-with-deps: cache miss, executing 172ed15cc5c32a8c
   missing-workspace-config:missing-workspace-config-task-with-deps: 
   missing-workspace-config:missing-workspace-config-task-with-deps: > missing-workspace-config-task-with-deps
   missing-workspace-config:missing-workspace-config-task-with-deps: > echo running-missing-workspace-config-task-with-deps > out/foo.min.txt
   missing-workspace-config:missing-workspace-config-task-with-deps: 
 
   $ cat tmp.log | grep "missing-workspace-config:missing-workspace-config-underlying-task"
-  missing-workspace-config:missing-workspace-config-underlying-task: cache miss, executing abd612282d80ada6
+  missing-workspace-config:missing-workspace-config-underlying-task: cache miss, executing ea80f8f73cb3cf9c
   missing-workspace-config:missing-workspace-config-underlying-task: 
   missing-workspace-config:missing-workspace-config-underlying-task: > missing-workspace-config-underlying-task
   missing-workspace-config:missing-workspace-config-underlying-task: > echo running-missing-workspace-config-underlying-task
@@ -32,7 +32,7 @@ Setup
   missing-workspace-config:missing-workspace-config-underlying-task: running-missing-workspace-config-underlying-task
 
   $ cat tmp.log | grep "blank-pkg:missing-workspace-config-underlying-topo-task"
-  blank-pkg:missing-workspace-config-underlying-topo-task: cache miss, executing 9cc1b75893929720
+  blank-pkg:missing-workspace-config-underlying-topo-task: cache miss, executing 10dcf7eeb44739a4
   blank-pkg:missing-workspace-config-underlying-topo-task: 
   blank-pkg:missing-workspace-config-underlying-topo-task: > missing-workspace-config-underlying-topo-task
   blank-pkg:missing-workspace-config-underlying-topo-task: > echo missing-workspace-config-underlying-topo-task from blank-pkg
@@ -41,5 +41,5 @@ Setup
 
   $ cat tmp.log | grep "Tasks:" -A 2
    Tasks:    3 successful, 3 total
-  Cached:    0 cached, 3 total
+  Cached:    0/3 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
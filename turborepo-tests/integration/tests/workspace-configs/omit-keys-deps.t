@@ -17,14 +17,14 @@ Setup
   \xe2\x80\xa2 Running omit-keys-task-with-deps in 1 packages (esc)
 
   $ cat tmp.log | grep "omit-keys:omit-keys-task-with-deps"
-  omit-keys:omit-keys-task-with-deps: cache miss, executing bbb54c4130d16663
+  omit-keys:omit-keys-task-with-deps: cache miss, executing 09e54883341217fb
   omit-keys:omit-keys-task-with-deps: 
   omit-keys:omit-keys-task-with-deps: > omit-keys-task-with-deps
   omit-keys:omit-keys-task-with-deps: > echo running-omit-keys-task-with-deps > out/foo.min.txt
   omit-keys:omit-keys-task-with-deps: 
 
   $ cat tmp.log | grep "omit-keys:omit-keys-underlying-task"
-  omit-keys:omit-keys-underlying-task: cache miss, executing 1aa42011f41a10f1
+  omit-keys:omit-keys-underlying-task: cache miss, executing e4f839791c3ea8b3
   omit-keys:omit-keys-underlying-task: 
   omit-keys:omit-keys-underlying-task: > omit-keys-underlying-task
   omit-keys:omit-keys-underlying-task: > echo running-omit-keys-underlying-task
@@ -32,7 +32,7 @@ Setup
   omit-keys:omit-keys-underlying-task: running-omit-keys-underlying-task
 
   $ cat tmp.log | grep "blank-pkg:omit-keys-underlying-topo-task"
-  blank-pkg:omit-keys-underlying-topo-task: cache miss, executing 4510d84de8b1d9b7
+  blank-pkg:omit-keys-underlying-topo-task: cache miss, executing 7819b0be291d7553
   blank-pkg:omit-keys-underlying-topo-task: 
   blank-pkg:omit-keys-underlying-topo-task: > omit-keys-underlying-topo-task
   blank-pkg:omit-keys-underlying-topo-task: > echo omit-keys-underlying-topo-task from blank-pkg
@@ -41,7 +41,7 @@ Setup
 
   $ cat tmp.log | grep "Tasks:" -A 2
    Tasks:    3 successful, 3 total
-  Cached:    0 cached, 3 total
+  Cached:    0/3 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
 
   $ HASH=$(cat tmp.log | grep -E "omit-keys:omit-keys-task-with-deps.* executing .*" | awk '{print $5}')
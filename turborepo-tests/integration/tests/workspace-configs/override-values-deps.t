@@ -12,14 +12,14 @@ Setup
   \xe2\x80\xa2 Packages in scope: override-values (esc)
   \xe2\x80\xa2 Running override-values-task-with-deps in 1 packages (esc)
   \xe2\x80\xa2 Remote caching disabled (esc)
-  override-values:override-values-task-with-deps: cache miss, executing 9b51bda96ea87896
+  override-values:override-values-task-with-deps: cache miss, executing 8332f10e2a8bd4e8
   override-values:override-values-task-with-deps: 
   override-values:override-values-task-with-deps: > override-values-task-with-deps
   override-values:override-values-task-with-deps: > echo running-override-values-task-with-deps > out/foo.min.txt
   override-values:override-values-task-with-deps: 
   
    Tasks:    1 successful, 1 total
-  Cached:    0 cached, 1 total
+  Cached:    0/1 tasks cached (0% hit rate)
     Time:\s*[\.0-9]+m?s  (re)
   
    WARNING  no output files found for task override-values#override-values-task-with-deps. Please check your `outputs` key in `turbo.json`
@@ -29,6 +29,7 @@ Setup
   {
     "outputs": [],
     "cache": true,
+    "cacheReadonly": false,
     "dependsOn": [],
     "inputs": [],
     "outputLogs": "full",
@@ -44,6 +45,7 @@ Setup
   {
     "outputs": [],
     "cache": true,
+    "cacheReadonly": false,
     "dependsOn": [],
     "inputs": [],
     "outputLogs": "full",